@@ -6,55 +6,959 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_rt`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `announce_quarantine`, `build_client`, `clear_logged_out_store`, `client_state`, `current_session`, `delete_marker`, `delete_own_device`, `get_or_join_room`, `get_rt`, `import_pending_room_keys`, `invited_room`, `join_room_for_send`, `known_room`, `media_attachment`, `media_source_json`, `message_to_timeline_item`, `oversized_media`, `raw_to_timeline_item`, `recover_mismatched_store`, `store_recovery_event`, `timeline_events_to_items`, `uint_to_u32`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
-/// Subscribe a Dart StreamSink to receive Matrix events.
-Stream<MatrixEvent> subscribeEvents() =>
-    RustLib.instance.api.crateBridgeSubscribeEvents();
+/// Subscribe a Dart StreamSink to receive live timeline items of a client.
+Stream<TimelineItem> subscribeEvents({required ClientHandle client}) =>
+    RustLib.instance.api.crateBridgeSubscribeEvents(client: client);
 
-Future<void> init({required String homeserver, required String dataDir}) =>
-    RustLib.instance.api
-        .crateBridgeInit(homeserver: homeserver, dataDir: dataDir);
+/// Subscribe a Dart StreamSink to changes of the client's room list (see `list_rooms`), sent as sync
+/// updates rooms.
+Stream<RoomListUpdate> subscribeRoomList({required ClientHandle client}) =>
+    RustLib.instance.api.crateBridgeSubscribeRoomList(client: client);
+
+/// Subscribe a Dart StreamSink to the invites of a client that its invite policy didn't accept,
+/// as they come in. `list_invites` has the ones pending from before.
+Stream<InviteInfo> subscribeInvites({required ClientHandle client}) =>
+    RustLib.instance.api.crateBridgeSubscribeInvites(client: client);
+
+/// Subscribe a Dart StreamSink to the sync status of a client. The current status is sent right away.
+Stream<SyncStatus> subscribeSyncStatus({required ClientHandle client}) =>
+    RustLib.instance.api.crateBridgeSubscribeSyncStatus(client: client);
+
+Future<SyncStatus> getSyncStatus({required ClientHandle client}) =>
+    RustLib.instance.api.crateBridgeGetSyncStatus(client: client);
+
+/// Subscribe a Dart StreamSink to the live timeline items of every client, tagged with their account.
+/// This is what a unified inbox listens to; it works alongside the per-client `subscribe_events`.
+Stream<AccountTimelineItem> subscribeAllEvents() =>
+    RustLib.instance.api.crateBridgeSubscribeAllEvents();
+
+/// Subscribe a Dart StreamSink to store recovery events of all clients.
+Stream<StoreRecoveryEvent> subscribeStoreRecovery() =>
+    RustLib.instance.api.crateBridgeSubscribeStoreRecovery();
+
+CancelToken newCancelToken() =>
+    RustLib.instance.api.crateBridgeNewCancelToken();
+
+/// Cancel every call started with `token`; they fail with `Cancelled`, and so do calls passed it
+/// later, until it is released. Does nothing to a released token.
+void cancelCalls({required CancelToken token}) =>
+    RustLib.instance.api.crateBridgeCancelCalls(token: token);
+
+/// Drop a token that is no longer needed, leaving its running calls alone. Calls passed it later
+/// run as if they had no token.
+void releaseCancelToken({required CancelToken token}) =>
+    RustLib.instance.api.crateBridgeReleaseCancelToken(token: token);
+
+/// Timeout for calls whose `CallOptions` don't set one. None (the default) means no timeout
+/// beyond the SDK's own per-request HTTP timeout.
+void setDefaultTimeout({BigInt? timeoutMs}) =>
+    RustLib.instance.api.crateBridgeSetDefaultTimeout(timeoutMs: timeoutMs);
+
+/// Delete the store in `data_dir`, which must not be open. Only the SDK's database files go; the
+/// media cache, other accounts' stores and recovery files stay.
+Future<void> clearStore({required String dataDir}) =>
+    RustLib.instance.api.crateBridgeClearStore(dataDir: dataDir);
+
+/// Build a client on the store in `data_dir` and return its handle.
+/// Calling it again for a store that already has a live client returns that client's handle;
+/// to switch homeserver on the same store, `dispose_client` the old one first. A call for a store
+/// another `init` is still opening fails.
+/// With a `passphrase` the store is encrypted (an existing plaintext store is encrypted in place);
+/// it has to be the same on every start, see `rekey_store` to change it.
+/// With `sliding_sync` the client syncs with sliding sync, see `SlidingSyncConfig`. Calling `init`
+/// again on a live client replaces that setting, effective from the next `start_sync`.
+/// `invite_policy` decides which invites are joined without asking; without one, all are.
+/// A repeated `init` replaces it right away.
+Future<ClientHandle> init(
+        {required String homeserver,
+        required String dataDir,
+        String? passphrase,
+        SlidingSyncConfig? slidingSync,
+        InvitePolicy? invitePolicy,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeInit(
+        homeserver: homeserver,
+        dataDir: dataDir,
+        passphrase: passphrase,
+        slidingSync: slidingSync,
+        invitePolicy: invitePolicy,
+        options: options);
 
-Future<LoginResult> login({required String user, required String password}) =>
-    RustLib.instance.api.crateBridgeLogin(user: user, password: password);
+/// Build a client for one of several accounts that can be logged in at the same time.
+/// Each account gets its own store under `<base_dir>/accounts/<account>` (e.g. the provisioned
+/// Matrix username), and is otherwise used like a client from `init`.
+Future<ClientHandle> initAccount(
+        {required String homeserver,
+        required String baseDir,
+        required String account,
+        String? passphrase,
+        SlidingSyncConfig? slidingSync,
+        InvitePolicy? invitePolicy,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeInitAccount(
+        homeserver: homeserver,
+        baseDir: baseDir,
+        account: account,
+        passphrase: passphrase,
+        slidingSync: slidingSync,
+        invitePolicy: invitePolicy,
+        options: options);
+
+/// Change the passphrase of the store in `data_dir`, which must not be open.
+/// With `old_passphrase` = None the store is a plaintext one and gets encrypted in place: message
+/// keys and the device identity are kept, cached room state is re-fetched by the next sync.
+Future<void> rekeyStore(
+        {required String dataDir,
+        String? oldPassphrase,
+        required String newPassphrase}) =>
+    RustLib.instance.api.crateBridgeRekeyStore(
+        dataDir: dataDir,
+        oldPassphrase: oldPassphrase,
+        newPassphrase: newPassphrase);
+
+/// List every live client with the account it's logged in as.
+Future<List<ClientInfo>> listClients() =>
+    RustLib.instance.api.crateBridgeListClients();
+
+/// Tear down a client: stop its sync loop, drop its event sink and release the store,
+/// so `init` can build a new client (e.g. for another homeserver or user).
+Future<void> disposeClient({required ClientHandle client}) =>
+    RustLib.instance.api.crateBridgeDisposeClient(client: client);
+
+Future<LoginResult> login(
+        {required ClientHandle client,
+        required String user,
+        required String password,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeLogin(
+        client: client, user: user, password: password, options: options);
+
+/// Restore a session saved from `login` (or provisioned by the backend) into the client
+/// created by `init`, without a password round-trip.
+/// If the store was wiped the device's encryption keys are gone with it, so messages sent to the
+/// old keys can't be decrypted; the SDK creates fresh keys for the same device ID.
+Future<LoginResult> restoreSession(
+        {required ClientHandle client,
+        required LoginResult session,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeRestoreSession(
+        client: client, session: session, options: options);
+
+/// Log out for good: stop sync, invalidate the access token on the homeserver and wipe the local
+/// state and crypto store. With `delete_device` the device is removed from the account as well
+/// (which also revokes its token); servers usually want the account `password` for that.
+/// Only the store's database files are deleted; anything else in its data_dir (other accounts'
+/// stores included) stays. If they can't be removed right away (they're still open on Windows), a
+/// delete marker is left next to the store and the next `init` on it clears them.
+Future<void> logout(
+        {required ClientHandle client,
+        required bool deleteDevice,
+        String? password,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeLogout(
+        client: client,
+        deleteDevice: deleteDevice,
+        password: password,
+        options: options);
 
 /// Create a direct message room and invite another user
 /// The creator is automatically added to the room when it's created
 /// If creator_mxid is provided, it will also be invited (for multi-device support)
-Future<String> createRoom({required String otherMxid, String? creatorMxid}) =>
+/// This always creates a new room; `get_or_create_dm` reuses an existing DM instead.
+/// `property` ties the room to a property, as `set_property_metadata` does.
+Future<String> createRoom(
+        {required ClientHandle client,
+        required String otherMxid,
+        String? creatorMxid,
+        PropertyMetadata? property,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeCreateRoom(
+        client: client,
+        otherMxid: otherMxid,
+        creatorMxid: creatorMxid,
+        property: property,
+        options: options);
+
+/// Create an encrypted group room (e.g. one per property) and invite the participants, with power
+/// levels following their roles: landlords and managers can rename the room and invite or remove
+/// people, everyone can post. `config.property` is stored as the room's property metadata. Returns
+/// the room ID.
+Future<String> createGroupRoom(
+        {required ClientHandle client,
+        required GroupRoomConfig config,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeCreateGroupRoom(
+        client: client, config: config, options: options);
+
+/// Get the direct message room with `user_id`, creating an encrypted one only if there is none yet.
+/// Existing DMs are looked up in the `m.direct` account data and by room membership (a DM the user
+/// invited us to is accepted); new and newly found rooms are recorded in `m.direct`, so other
+/// clients list them as DMs too.
+/// `also_invite` are further users to have in the room, e.g. the creator's dashboard account. They
+/// are invited if they aren't in the room yet, but the room isn't recorded as a DM with them.
+/// `property` is stored with a new room, and added to a reused one that has no property yet.
+Future<DirectRoom> getOrCreateDm(
+        {required ClientHandle client,
+        required String userId,
+        List<String>? alsoInvite,
+        PropertyMetadata? property,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeGetOrCreateDm(
+        client: client,
+        userId: userId,
+        alsoInvite: alsoInvite,
+        property: property,
+        options: options);
+
+/// Summaries of every joined and invited room, most recently active first. With `space_id`, only
+/// the rooms in that space and its subspaces. Spaces themselves aren't listed.
+/// Rooms without a message since the client started get their latest one fetched once.
+Future<List<RoomSummary>> listRooms(
+        {required ClientHandle client,
+        String? spaceId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeListRooms(
+        client: client, spaceId: spaceId, options: options);
+
+/// Create a space, e.g. for a property or a portfolio of properties, and return its room ID.
+/// With `parent_space_id` it's added to that space as a subspace.
+Future<String> createSpace(
+        {required ClientHandle client,
+        required String name,
+        String? topic,
+        String? parentSpaceId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeCreateSpace(
+        client: client,
+        name: name,
+        topic: topic,
+        parentSpaceId: parentSpaceId,
+        options: options);
+
+/// Add a room (or subspace) to a space. Needs the power level to change the space's state.
+Future<void> addSpaceChild(
+        {required ClientHandle client,
+        required String spaceId,
+        required String childId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeAddSpaceChild(
+        client: client, spaceId: spaceId, childId: childId, options: options);
+
+/// Take a room (or subspace) out of a space. The room itself stays as it is.
+Future<void> removeSpaceChild(
+        {required ClientHandle client,
+        required String spaceId,
+        required String childId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeRemoveSpaceChild(
+        client: client, spaceId: spaceId, childId: childId, options: options);
+
+/// Everything below a space, including rooms we aren't in: the space itself first, then its rooms
+/// and subspaces with the ID of the space listing them.
+Future<List<SpaceHierarchyEntry>> getSpaceHierarchy(
+        {required ClientHandle client,
+        required String spaceId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeGetSpaceHierarchy(
+        client: client, spaceId: spaceId, options: options);
+
+/// Tie a joined room to a property, replacing any metadata it had. Needs the power level for state
+/// events (managers and landlords in group rooms).
+Future<void> setPropertyMetadata(
+        {required ClientHandle client,
+        required String roomId,
+        required PropertyMetadata metadata,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeSetPropertyMetadata(
+        client: client, roomId: roomId, metadata: metadata, options: options);
+
+/// The property a room is about, or None if it isn't tied to one.
+Future<PropertyMetadata?> getPropertyMetadata(
+        {required ClientHandle client,
+        required String roomId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeGetPropertyMetadata(
+        client: client, roomId: roomId, options: options);
+
+/// Every pending invite of a client.
+Future<List<InviteInfo>> listInvites(
+        {required ClientHandle client, CallOptions? options}) =>
+    RustLib.instance.api
+        .crateBridgeListInvites(client: client, options: options);
+
+/// Join a room we're invited to.
+Future<void> acceptInvite(
+        {required ClientHandle client,
+        required String roomId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeAcceptInvite(
+        client: client, roomId: roomId, options: options);
+
+/// Reject an invite. The inviter can invite us again later.
+Future<void> declineInvite(
+        {required ClientHandle client,
+        required String roomId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeDeclineInvite(
+        client: client, roomId: roomId, options: options);
+
+/// Every member of a room with their membership and power level, including those who left or
+/// were banned. Loads the full member list from the homeserver the first time.
+Future<List<RoomMemberInfo>> getRoomMembers(
+        {required ClientHandle client,
+        required String roomId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeGetRoomMembers(
+        client: client, roomId: roomId, options: options);
+
+/// Invite a user to a room we're in.
+Future<void> inviteUser(
+        {required ClientHandle client,
+        required String roomId,
+        required String userId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeInviteUser(
+        client: client, roomId: roomId, userId: userId, options: options);
+
+/// Remove a member from a room; they can join again if invited.
+Future<void> kickUser(
+        {required ClientHandle client,
+        required String roomId,
+        required String userId,
+        String? reason,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeKickUser(
+        client: client,
+        roomId: roomId,
+        userId: userId,
+        reason: reason,
+        options: options);
+
+/// Remove a member (or anyone else) from a room and keep them out until `unban_user`.
+Future<void> banUser(
+        {required ClientHandle client,
+        required String roomId,
+        required String userId,
+        String? reason,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeBanUser(
+        client: client,
+        roomId: roomId,
+        userId: userId,
+        reason: reason,
+        options: options);
+
+/// Lift a ban. The user isn't re-invited.
+Future<void> unbanUser(
+        {required ClientHandle client,
+        required String roomId,
+        required String userId,
+        String? reason,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeUnbanUser(
+        client: client,
+        roomId: roomId,
+        userId: userId,
+        reason: reason,
+        options: options);
+
+/// Leave a room. It drops out of the room list with the next sync; `forget_room` also removes it
+/// from the account's history.
+Future<void> leaveRoom(
+        {required ClientHandle client,
+        required String roomId,
+        CallOptions? options}) =>
+    RustLib.instance.api
+        .crateBridgeLeaveRoom(client: client, roomId: roomId, options: options);
+
+/// Forget a room we left, so the server drops it from our account and the local store drops it too.
+Future<void> forgetRoom(
+        {required ClientHandle client,
+        required String roomId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeForgetRoom(
+        client: client, roomId: roomId, options: options);
+
+Future<String> sendMessage(
+        {required ClientHandle client,
+        required String roomId,
+        required String body,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeSendMessage(
+        client: client, roomId: roomId, body: body, options: options);
+
+/// Upload a file and post it to a room as m.image, m.video, m.audio or m.file depending on `mime_type`.
+/// Pass either `file_path` or the raw `data`; `filename` defaults to the path's file name.
+/// In encrypted rooms the file (and thumbnail) is encrypted before upload.
+/// Images get their dimensions and a JPEG thumbnail attached. Returns the event ID.
+Future<String> sendAttachment(
+        {required ClientHandle client,
+        required String roomId,
+        String? filePath,
+        Uint8List? data,
+        String? filename,
+        required String mimeType,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeSendAttachment(
+        client: client,
+        roomId: roomId,
+        filePath: filePath,
+        data: data,
+        filename: filename,
+        mimeType: mimeType,
+        options: options);
+
+/// Change the media cache limits (defaults: 512 MiB total, 100 MiB per file).
+Future<void> configureMediaCache(
+        {required ClientHandle client, required MediaCacheConfig config}) =>
     RustLib.instance.api
-        .crateBridgeCreateRoom(otherMxid: otherMxid, creatorMxid: creatorMxid);
+        .crateBridgeConfigureMediaCache(client: client, config: config);
 
-Future<String> sendMessage({required String roomId, required String body}) =>
-    RustLib.instance.api.crateBridgeSendMessage(roomId: roomId, body: body);
+/// Resolve media to a local file path, downloading (and decrypting) it into the cache on first use.
+/// `source` is an `Attachment.source`/`thumbnail_source` JSON string or a bare mxc:// URI.
+/// `thumbnail` asks the server for a scaled copy; that only works for unencrypted media, for encrypted
+/// attachments pass the event's `thumbnail_source` instead. `filename` only picks the file extension.
+/// `size` is the attachment's `size`; media over the cache's per-file limit is then turned down
+/// without downloading it.
+/// Note that encrypted media is stored decrypted so it can be shown offline.
+Future<String> fetchMedia(
+        {required ClientHandle client,
+        required String source,
+        String? filename,
+        BigInt? size,
+        ThumbnailRequest? thumbnail,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeFetchMedia(
+        client: client,
+        source: source,
+        filename: filename,
+        size: size,
+        thumbnail: thumbnail,
+        options: options);
+
+/// Delete every cached media file.
+Future<void> clearMediaCache({required ClientHandle client}) =>
+    RustLib.instance.api.crateBridgeClearMediaCache(client: client);
 
 /// Send a read receipt for a specific event in a room.
-Future<void> markRead({required String roomId, required String eventId}) =>
-    RustLib.instance.api.crateBridgeMarkRead(roomId: roomId, eventId: eventId);
+Future<void> markRead(
+        {required ClientHandle client,
+        required String roomId,
+        required String eventId,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeMarkRead(
+        client: client, roomId: roomId, eventId: eventId, options: options);
+
+/// Get the latest timeline messages from a room, in chronological order
+/// Encrypted events are decrypted with the client's Olm machine. If the room key is missing,
+/// the item is still returned as `MessageKind::UnableToDecrypt` with the reason in `decryption_error`.
+Future<List<TimelineItem>> getRoomMessages(
+        {required ClientHandle client,
+        required String roomId,
+        required int limit,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeGetRoomMessages(
+        client: client, roomId: roomId, limit: limit, options: options);
+
+/// Get one page of timeline messages from a room
+/// Pass `from = None` to start at the newest events (backward) or the oldest visible ones (forward),
+/// then pass the returned `next_token` to continue in the same direction.
+/// `messages` is in chronological order, same as `get_room_messages`.
+Future<TimelinePage> getRoomMessagesPage(
+        {required ClientHandle client,
+        required String roomId,
+        String? from,
+        required TimelineDirection direction,
+        required int limit,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeGetRoomMessagesPage(
+        client: client,
+        roomId: roomId,
+        from: from,
+        direction: direction,
+        limit: limit,
+        options: options);
 
-/// Get timeline messages from a room
-/// Returns a JSON array of messages: [{"sender":"@user:server","body":"text","timestamp":1234567890,"eventId":"$xyz"}]
-Future<String> getRoomMessages({required String roomId, required int limit}) =>
+/// Start the background sync loop of a client. Live timeline items go to its `subscribe_events` sink,
+/// its status to `subscribe_sync_status`. The loop retries failed syncs until `stop_sync`, except
+/// when the session has expired: then it stops by itself. Fails while a `stop_sync` is still
+/// waiting for the previous loop to finish.
+/// Uses sliding sync if `init` configured it and the server supports it, classic sync otherwise.
+/// Event handlers are registered once per client, so stopping and starting again doesn't deliver
+/// events twice.
+Future<void> startSync({required ClientHandle client}) =>
+    RustLib.instance.api.crateBridgeStartSync(client: client);
+
+/// Tell the bridge the device is online again (e.g. from a connectivity listener), so sync loops
+/// waiting to retry after a network error retry right away. Healthy loops aren't affected, and
+/// waits for a server rate limit still run their course.
+Future<void> notifyNetworkAvailable() =>
+    RustLib.instance.api.crateBridgeNotifyNetworkAvailable();
+
+/// Filter what classic sync fetches from the next sync request on, or stop filtering with None.
+/// The filter is uploaded once per set of options and reused from then on, across app starts too.
+/// Sliding sync has its own limits, see `SlidingSyncConfig`.
+Future<void> setSyncFilter(
+        {required ClientHandle client, SyncFilterConfig? filter}) =>
     RustLib.instance.api
-        .crateBridgeGetRoomMessages(roomId: roomId, limit: limit);
+        .crateBridgeSetSyncFilter(client: client, filter: filter);
+
+/// Move the sliding sync window to the `count` rooms of the room list starting at `start`, e.g. as
+/// the user scrolls. Does nothing under classic sync, which syncs every room anyway.
+Future<void> setVisibleRooms(
+        {required ClientHandle client,
+        required int start,
+        required int count,
+        CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeSetVisibleRooms(
+        client: client, start: start, count: count, options: options);
+
+/// Stop the sync loop of a client. The sync request in flight (a long poll of up to 30 s) is
+/// finished and its response stored first, so the returned future completes once sync has
+/// stopped. A timeout from `options` only ends the wait; the loop still stops.
+Future<void> stopSync({required ClientHandle client, CallOptions? options}) =>
+    RustLib.instance.api.crateBridgeStopSync(client: client, options: options);
+
+/// A live timeline item tagged with the account it arrived on.
+class AccountTimelineItem {
+  final ClientHandle client;
+  /// Matrix user ID of the account, empty if it isn't logged in
+  final String userId;
+  final TimelineItem item;
+
+  const AccountTimelineItem({
+    required this.client,
+    required this.userId,
+    required this.item,
+  });
+
+  @override
+  int get hashCode => client.hashCode ^ userId.hashCode ^ item.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccountTimelineItem &&
+          runtimeType == other.runtimeType &&
+          client == other.client &&
+          userId == other.userId &&
+          item == other.item;
+}
+
+/// Media attached to an image/file/video/audio message.
+class Attachment {
+  /// mxc:// URI of the (possibly encrypted) media
+  final String url;
+  /// JSON of the event's media source (`{"url":..}` or `{"file":{..}}`), needed to fetch and decrypt it
+  final String source;
+  final bool isEncrypted;
+  final String filename;
+  final String? mimetype;
+  final BigInt? size;
+  final int? width;
+  final int? height;
+  final BigInt? durationMs;
+  /// JSON media source of the thumbnail, same shape as `source`
+  final String? thumbnailSource;
+
+  const Attachment({
+    required this.url,
+    required this.source,
+    required this.isEncrypted,
+    required this.filename,
+    this.mimetype,
+    this.size,
+    this.width,
+    this.height,
+    this.durationMs,
+    this.thumbnailSource,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      source.hashCode ^
+      isEncrypted.hashCode ^
+      filename.hashCode ^
+      mimetype.hashCode ^
+      size.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      durationMs.hashCode ^
+      thumbnailSource.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Attachment &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          source == other.source &&
+          isEncrypted == other.isEncrypted &&
+          filename == other.filename &&
+          mimetype == other.mimetype &&
+          size == other.size &&
+          width == other.width &&
+          height == other.height &&
+          durationMs == other.durationMs &&
+          thumbnailSource == other.thumbnailSource;
+}
+
+/// Error returned by every bridge function. A struct with a plain kind enum rather than a
+/// data-carrying enum, so it maps onto a simple Dart exception class.
+class BridgeError implements FrbException {
+  final BridgeErrorKind kind;
+  /// Developer-facing description, not meant for display
+  final String message;
+  /// Only set for `RateLimited`, when the server says how long to back off
+  final BigInt? retryAfterMs;
+
+  const BridgeError({
+    required this.kind,
+    required this.message,
+    this.retryAfterMs,
+  });
+
+  @override
+  String toString() => 'BridgeError(${kind.name}): $message';
+
+  @override
+  int get hashCode => kind.hashCode ^ message.hashCode ^ retryAfterMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BridgeError &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          message == other.message &&
+          retryAfterMs == other.retryAfterMs;
+}
+
+/// What went wrong, for the app to branch on (and localise) instead of parsing messages.
+enum BridgeErrorKind {
+  /// The client handle is unknown: never initialized, or already disposed
+  notInitialized,
+  /// The client has no session, or the server no longer accepts its access token
+  notLoggedIn,
+  /// The room isn't known locally, or the server doesn't know it (M_NOT_FOUND)
+  roomNotFound,
+  /// The server refused the request (M_FORBIDDEN), e.g. for lack of power level
+  forbidden,
+  /// Too many requests; wait `retry_after_ms` (if the server said) before trying again
+  rateLimited,
+  /// The homeserver couldn't be reached
+  networkUnavailable,
+  /// The store belongs to another account or device and couldn't be recovered
+  cryptoStoreMismatch,
+  /// A Matrix ID, URL, MIME type or media source didn't parse
+  invalidId,
+  /// The store passphrase is missing or doesn't open the store
+  wrongPassphrase,
+  /// A live client (or an `init` still opening it) has the store open
+  storeInUse,
+  /// The call was cancelled through its `CancelToken`
+  cancelled,
+  /// The call ran past its `CallOptions.timeout_ms`
+  timedOut,
+  /// Anything else; see `message`
+  other,
+  ;
+}
+
+/// Per-call limits for the functions that talk to the homeserver.
+class CallOptions {
+  /// Fail with `TimedOut` after this long; None uses `set_default_timeout`
+  final BigInt? timeoutMs;
+  final CancelToken? cancel;
+
+  const CallOptions({
+    this.timeoutMs,
+    this.cancel,
+  });
+
+  static Future<CallOptions> default_() =>
+      RustLib.instance.api.crateBridgeCallOptionsDefault();
 
-Future<void> startSync() => RustLib.instance.api.crateBridgeStartSync();
+  @override
+  int get hashCode => timeoutMs.hashCode ^ cancel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CallOptions &&
+          runtimeType == other.runtimeType &&
+          timeoutMs == other.timeoutMs &&
+          cancel == other.cancel;
+}
+
+/// Cancels the calls it is passed to (in `CallOptions`). Create one per screen or operation with
+/// `new_cancel_token`, and `release_cancel_token` it once done, whether `cancel_calls` was used or not.
+class CancelToken {
+  final BigInt id;
+
+  const CancelToken({
+    required this.id,
+  });
+
+  @override
+  int get hashCode => id.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CancelToken &&
+          runtimeType == other.runtimeType &&
+          id == other.id;
+}
+
+/// Refers to a client created by `init`. Valid until `dispose_client` or `logout`.
+class ClientHandle {
+  final int id;
+
+  const ClientHandle({
+    required this.id,
+  });
+
+  @override
+  int get hashCode => id.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClientHandle &&
+          runtimeType == other.runtimeType &&
+          id == other.id;
+}
+
+/// A live client, as listed by `list_clients`.
+class ClientInfo {
+  final ClientHandle handle;
+  final String? userId;
+  final String? deviceId;
+  final String homeserver;
+  final String dataDir;
+  final bool isSyncing;
+
+  const ClientInfo({
+    required this.handle,
+    this.userId,
+    this.deviceId,
+    required this.homeserver,
+    required this.dataDir,
+    required this.isSyncing,
+  });
+
+  @override
+  int get hashCode =>
+      handle.hashCode ^
+      userId.hashCode ^
+      deviceId.hashCode ^
+      homeserver.hashCode ^
+      dataDir.hashCode ^
+      isSyncing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClientInfo &&
+          runtimeType == other.runtimeType &&
+          handle == other.handle &&
+          userId == other.userId &&
+          deviceId == other.deviceId &&
+          homeserver == other.homeserver &&
+          dataDir == other.dataDir &&
+          isSyncing == other.isSyncing;
+}
+
+/// A direct message room from `get_or_create_dm`.
+class DirectRoom {
+  final String roomId;
+  /// False if an existing DM was reused
+  final bool created;
+
+  const DirectRoom({
+    required this.roomId,
+    required this.created,
+  });
+
+  @override
+  int get hashCode => roomId.hashCode ^ created.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DirectRoom &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          created == other.created;
+}
+
+/// A group room for `create_group_room`.
+class GroupRoomConfig {
+  final String name;
+  final String? topic;
+  /// Image for the room avatar, uploaded with the room
+  final Uint8List? avatar;
+  /// MIME type of `avatar`, e.g. `image/png`
+  final String? avatarMimeType;
+  /// Everyone to invite, with their role. The creator gets the landlord's level whatever their role
+  final List<Participant> participants;
+  /// Property the room is about, stored with the room
+  final PropertyMetadata? property;
+
+  const GroupRoomConfig({
+    required this.name,
+    this.topic,
+    this.avatar,
+    this.avatarMimeType,
+    required this.participants,
+    this.property,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      topic.hashCode ^
+      avatar.hashCode ^
+      avatarMimeType.hashCode ^
+      participants.hashCode ^
+      property.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupRoomConfig &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          topic == other.topic &&
+          avatar == other.avatar &&
+          avatarMimeType == other.avatarMimeType &&
+          participants == other.participants &&
+          property == other.property;
+}
+
+/// A pending invite with what the invite tells about the room.
+class InviteInfo {
+  final String roomId;
+  /// Room name, or one computed from the members the invite shows
+  final String displayName;
+  /// mxc:// URI, fetch it with `fetch_media`
+  final String? avatarUrl;
+  final String? topic;
+  final bool isDirect;
+  /// Only known before joining if the server shares `ch.immosync.property` in invites
+  final PropertyMetadata? property;
+  /// User ID of whoever sent the invite
+  final String? inviter;
+  final String? inviterDisplayName;
+  final String? inviterAvatarUrl;
+
+  const InviteInfo({
+    required this.roomId,
+    required this.displayName,
+    this.avatarUrl,
+    this.topic,
+    required this.isDirect,
+    this.property,
+    this.inviter,
+    this.inviterDisplayName,
+    this.inviterAvatarUrl,
+  });
+
+  @override
+  int get hashCode =>
+      roomId.hashCode ^
+      displayName.hashCode ^
+      avatarUrl.hashCode ^
+      topic.hashCode ^
+      isDirect.hashCode ^
+      property.hashCode ^
+      inviter.hashCode ^
+      inviterDisplayName.hashCode ^
+      inviterAvatarUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InviteInfo &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          displayName == other.displayName &&
+          avatarUrl == other.avatarUrl &&
+          topic == other.topic &&
+          isDirect == other.isDirect &&
+          property == other.property &&
+          inviter == other.inviter &&
+          inviterDisplayName == other.inviterDisplayName &&
+          inviterAvatarUrl == other.inviterAvatarUrl;
+}
 
-Future<void> stopSync() => RustLib.instance.api.crateBridgeStopSync();
+/// Invite policy for `init`. Invites it doesn't accept stay pending and go to `subscribe_invites`.
+class InvitePolicy {
+  final InvitePolicyKind kind;
+  /// Full Matrix user IDs, for `AcceptAllowlisted`
+  final List<String> allowedUsers;
+  /// Server names (e.g. `matrix.immosync.ch`), for `AcceptAllowlisted`
+  final List<String> allowedServers;
 
+  const InvitePolicy({
+    required this.kind,
+    required this.allowedUsers,
+    required this.allowedServers,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ allowedUsers.hashCode ^ allowedServers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InvitePolicy &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          allowedUsers == other.allowedUsers &&
+          allowedServers == other.allowedServers;
+}
+
+/// Which invites a client joins without asking; see `InvitePolicy`.
+enum InvitePolicyKind {
+  /// Join every invite
+  acceptAll,
+  /// Join invites from `allowed_users`, or from any user on `allowed_servers`
+  acceptAllowlisted,
+  /// Join invites to rooms carrying property metadata (`ch.immosync.property` state)
+  acceptPropertyRooms,
+  /// Join nothing; every invite goes to `subscribe_invites`
+  manual,
+  ;
+}
+
+/// The logged-in session. Keep it somewhere safe to pass back to `restore_session` later.
 class LoginResult {
   final String userId;
+  final String deviceId;
   final String accessToken;
+  /// Only set if the homeserver issued a refresh token
+  final String? refreshToken;
 
   const LoginResult({
     required this.userId,
+    required this.deviceId,
     required this.accessToken,
+    this.refreshToken,
   });
 
   @override
-  int get hashCode => userId.hashCode ^ accessToken.hashCode;
+  int get hashCode =>
+      userId.hashCode ^
+      deviceId.hashCode ^
+      accessToken.hashCode ^
+      refreshToken.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -62,24 +966,605 @@ class LoginResult {
       other is LoginResult &&
           runtimeType == other.runtimeType &&
           userId == other.userId &&
-          accessToken == other.accessToken;
+          deviceId == other.deviceId &&
+          accessToken == other.accessToken &&
+          refreshToken == other.refreshToken;
+}
+
+/// Limits for the on-disk media cache under the client's data_dir.
+class MediaCacheConfig {
+  /// Least recently used files are evicted once the cache grows past this
+  final BigInt maxTotalBytes;
+  /// Media larger than this is not downloaded through the cache. Media whose size is known up
+  /// front isn't downloaded at all, other media only once
+  final BigInt maxFileBytes;
+
+  const MediaCacheConfig({
+    required this.maxTotalBytes,
+    required this.maxFileBytes,
+  });
+
+  @override
+  int get hashCode => maxTotalBytes.hashCode ^ maxFileBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaCacheConfig &&
+          runtimeType == other.runtimeType &&
+          maxTotalBytes == other.maxTotalBytes &&
+          maxFileBytes == other.maxFileBytes;
+}
+
+/// A member's membership of a room, for `get_room_members`.
+enum MemberState {
+  joined,
+  invited,
+  /// Left, or was kicked
+  left,
+  banned,
+  /// Asked to join
+  knocked,
+  ;
+}
+
+/// What kind of message a timeline item carries (the `msgtype` of `m.room.message`).
+enum MessageKind {
+  text,
+  notice,
+  emote,
+  image,
+  file,
+  video,
+  audio,
+  location,
+  /// A msgtype the bridge doesn't model; `body` still holds the fallback text
+  other,
+  /// An encrypted event we don't have the keys for (see `decryption_error`)
+  unableToDecrypt,
+  ;
+}
+
+class Participant {
+  final String userId;
+  final ParticipantRole role;
+
+  const Participant({
+    required this.userId,
+    required this.role,
+  });
+
+  @override
+  int get hashCode => userId.hashCode ^ role.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Participant &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          role == other.role;
+}
+
+/// What someone does in a property, deciding their power level in its group room.
+enum ParticipantRole {
+  /// Manages the room and everyone's roles (power level 100)
+  landlord,
+  /// Manages the room: name, topic, avatar and members (50)
+  manager,
+  /// Posts, like a tenant (10)
+  caretaker,
+  /// Posts (0)
+  tenant,
+  ;
+}
+
+/// The property and tenancy a room is about, kept in its `ch.immosync.property` state event.
+class PropertyMetadata {
+  final String propertyId;
+  final String? address;
+  final String? tenancyId;
+  /// Everyone's role in the property. Roles only inform the app; power levels aren't changed
+  final List<Participant> participants;
+
+  const PropertyMetadata({
+    required this.propertyId,
+    this.address,
+    this.tenancyId,
+    required this.participants,
+  });
+
+  @override
+  int get hashCode =>
+      propertyId.hashCode ^
+      address.hashCode ^
+      tenancyId.hashCode ^
+      participants.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PropertyMetadata &&
+          runtimeType == other.runtimeType &&
+          propertyId == other.propertyId &&
+          address == other.address &&
+          tenancyId == other.tenancyId &&
+          participants == other.participants;
+}
+
+enum RoomListChange {
+  /// The room is new to the list or something about it changed
+  updated,
+  /// The user left the room or was kicked or banned from it
+  removed,
+  ;
+}
+
+/// A change to the room list, pushed after each sync.
+class RoomListUpdate {
+  final String roomId;
+  final RoomListChange change;
+  /// The new summary; None for `Removed`
+  final RoomSummary? room;
+
+  const RoomListUpdate({
+    required this.roomId,
+    required this.change,
+    this.room,
+  });
+
+  @override
+  int get hashCode => roomId.hashCode ^ change.hashCode ^ room.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomListUpdate &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          change == other.change &&
+          room == other.room;
+}
+
+/// One member of a room, from `get_room_members`.
+class RoomMemberInfo {
+  final String userId;
+  final String? displayName;
+  /// mxc:// URI, fetch it with `fetch_media`
+  final String? avatarUrl;
+  final MemberState membership;
+  /// 100 for admins, 50 for moderators, 0 for everyone else by default
+  final PlatformInt64 powerLevel;
+
+  const RoomMemberInfo({
+    required this.userId,
+    this.displayName,
+    this.avatarUrl,
+    required this.membership,
+    required this.powerLevel,
+  });
+
+  @override
+  int get hashCode =>
+      userId.hashCode ^
+      displayName.hashCode ^
+      avatarUrl.hashCode ^
+      membership.hashCode ^
+      powerLevel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomMemberInfo &&
+          runtimeType == other.runtimeType &&
+          userId == other.userId &&
+          displayName == other.displayName &&
+          avatarUrl == other.avatarUrl &&
+          membership == other.membership &&
+          powerLevel == other.powerLevel;
+}
+
+/// The user's membership of a room in the room list.
+enum RoomMembership {
+  joined,
+  invited,
+  left,
+  ;
 }
 
-class MatrixEvent {
+/// One entry of the room list.
+class RoomSummary {
+  final String roomId;
+  /// Room name, or one computed from the members as the spec describes
+  final String displayName;
+  /// mxc:// URI, fetch it with `fetch_media`
+  final String? avatarUrl;
+  final RoomMembership membership;
+  final bool isEncrypted;
+  final bool isDirect;
+  /// User IDs of joined and invited members known locally; may be incomplete for large rooms
+  final List<String> members;
+  final BigInt joinedMembers;
+  final BigInt invitedMembers;
+  /// None for invites, and for rooms with no message in their recent history
+  final TimelineItem? latestMessage;
+  /// None for rooms not tied to a property
+  final PropertyMetadata? property;
+  /// Unread notifications, as counted by the server
+  final BigInt unreadCount;
+  /// Unread notifications that mention the user or match a highlight rule
+  final BigInt highlightCount;
+
+  const RoomSummary({
+    required this.roomId,
+    required this.displayName,
+    this.avatarUrl,
+    required this.membership,
+    required this.isEncrypted,
+    required this.isDirect,
+    required this.members,
+    required this.joinedMembers,
+    required this.invitedMembers,
+    this.latestMessage,
+    this.property,
+    required this.unreadCount,
+    required this.highlightCount,
+  });
+
+  @override
+  int get hashCode =>
+      roomId.hashCode ^
+      displayName.hashCode ^
+      avatarUrl.hashCode ^
+      membership.hashCode ^
+      isEncrypted.hashCode ^
+      isDirect.hashCode ^
+      members.hashCode ^
+      joinedMembers.hashCode ^
+      invitedMembers.hashCode ^
+      latestMessage.hashCode ^
+      property.hashCode ^
+      unreadCount.hashCode ^
+      highlightCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RoomSummary &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          displayName == other.displayName &&
+          avatarUrl == other.avatarUrl &&
+          membership == other.membership &&
+          isEncrypted == other.isEncrypted &&
+          isDirect == other.isDirect &&
+          members == other.members &&
+          joinedMembers == other.joinedMembers &&
+          invitedMembers == other.invitedMembers &&
+          latestMessage == other.latestMessage &&
+          property == other.property &&
+          unreadCount == other.unreadCount &&
+          highlightCount == other.highlightCount;
+}
+
+/// Delivery state of a timeline item.
+/// Events coming from the server are always `Sent`; `Sending` and `Failed` are for local echoes.
+enum SendState {
+  sending,
+  sent,
+  failed,
+  ;
+}
+
+/// Sliding sync (MSC3575) setup for `init`. Instead of every room, only a window of the room list
+/// (most recently active first) is synced, with a few timeline events each, so a cold start with
+/// hundreds of rooms is quick. If the server doesn't support it, sync falls back to classic sync.
+class SlidingSyncConfig {
+  /// Sliding sync proxy to use; None for a homeserver that supports sliding sync natively
+  final String? proxyUrl;
+  /// Rooms synced at first, from the top of the room list; `set_visible_rooms` moves the window
+  final int windowSize;
+  /// Timeline events synced per room in the window
+  final int timelineLimit;
+
+  const SlidingSyncConfig({
+    this.proxyUrl,
+    required this.windowSize,
+    required this.timelineLimit,
+  });
+
+  @override
+  int get hashCode =>
+      proxyUrl.hashCode ^ windowSize.hashCode ^ timelineLimit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SlidingSyncConfig &&
+          runtimeType == other.runtimeType &&
+          proxyUrl == other.proxyUrl &&
+          windowSize == other.windowSize &&
+          timelineLimit == other.timelineLimit;
+}
+
+/// A room or subspace in a space, from `get_space_hierarchy`.
+class SpaceHierarchyEntry {
+  final String roomId;
+  /// The space listing this room; None for the space the hierarchy was asked for
+  final String? parentId;
+  final String? name;
+  final String? topic;
+  /// mxc:// URI, fetch it with `fetch_media`
+  final String? avatarUrl;
+  final bool isSpace;
+  final BigInt joinedMembers;
+  /// Whether we're in the room
+  final bool joined;
+
+  const SpaceHierarchyEntry({
+    required this.roomId,
+    this.parentId,
+    this.name,
+    this.topic,
+    this.avatarUrl,
+    required this.isSpace,
+    required this.joinedMembers,
+    required this.joined,
+  });
+
+  @override
+  int get hashCode =>
+      roomId.hashCode ^
+      parentId.hashCode ^
+      name.hashCode ^
+      topic.hashCode ^
+      avatarUrl.hashCode ^
+      isSpace.hashCode ^
+      joinedMembers.hashCode ^
+      joined.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SpaceHierarchyEntry &&
+          runtimeType == other.runtimeType &&
+          roomId == other.roomId &&
+          parentId == other.parentId &&
+          name == other.name &&
+          topic == other.topic &&
+          avatarUrl == other.avatarUrl &&
+          isSpace == other.isSpace &&
+          joinedMembers == other.joinedMembers &&
+          joined == other.joined;
+}
+
+/// Reported on `subscribe_store_recovery` whenever a mismatched store is recovered.
+class StoreRecoveryEvent {
+  final ClientHandle client;
+  final StoreRecoveryStage stage;
+  /// The SDK error that triggered the recovery
+  final String reason;
+  /// Where the old store's database files now live; nothing in it is deleted
+  final String quarantinedDir;
+  /// Room key export of the old store, encrypted with the store passphrase (or a one-time one
+  /// kept until the import if the store has none). None if the export failed. Deleted once the
+  /// keys are imported
+  final String? keyBackupFile;
+  final BigInt exportedKeys;
+  final BigInt importedKeys;
+  final String? error;
+
+  const StoreRecoveryEvent({
+    required this.client,
+    required this.stage,
+    required this.reason,
+    required this.quarantinedDir,
+    this.keyBackupFile,
+    required this.exportedKeys,
+    required this.importedKeys,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      client.hashCode ^
+      stage.hashCode ^
+      reason.hashCode ^
+      quarantinedDir.hashCode ^
+      keyBackupFile.hashCode ^
+      exportedKeys.hashCode ^
+      importedKeys.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StoreRecoveryEvent &&
+          runtimeType == other.runtimeType &&
+          client == other.client &&
+          stage == other.stage &&
+          reason == other.reason &&
+          quarantinedDir == other.quarantinedDir &&
+          keyBackupFile == other.keyBackupFile &&
+          exportedKeys == other.exportedKeys &&
+          importedKeys == other.importedKeys &&
+          error == other.error;
+}
+
+/// Progress of recovering from a store that belongs to another account or device.
+enum StoreRecoveryStage {
+  /// The old store was moved aside and a fresh one built; its keys wait for the next login
+  quarantined,
+  /// The room keys of the old store were imported into the new one
+  keysImported,
+  /// The backup couldn't be imported; the keys are still in the backup file and the old store
+  keyImportFailed,
+  ;
+}
+
+/// What classic sync should leave out, for `set_sync_filter`. Everything left out saves mobile data.
+class SyncFilterConfig {
+  /// Only send the members who sent the synced events, not every member of every room
+  final bool lazyLoadMembers;
+  /// Timeline events per room and sync; None for the server's default
+  final int? timelineLimit;
+  /// Online status of other users
+  final bool includePresence;
+  /// Global and per-room account data. Needed to tell DMs apart (`m.direct`)
+  final bool includeAccountData;
+  /// Typing notifications and read receipts
+  final bool includeEphemeral;
+
+  const SyncFilterConfig({
+    required this.lazyLoadMembers,
+    this.timelineLimit,
+    required this.includePresence,
+    required this.includeAccountData,
+    required this.includeEphemeral,
+  });
+
+  @override
+  int get hashCode =>
+      lazyLoadMembers.hashCode ^
+      timelineLimit.hashCode ^
+      includePresence.hashCode ^
+      includeAccountData.hashCode ^
+      includeEphemeral.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncFilterConfig &&
+          runtimeType == other.runtimeType &&
+          lazyLoadMembers == other.lazyLoadMembers &&
+          timelineLimit == other.timelineLimit &&
+          includePresence == other.includePresence &&
+          includeAccountData == other.includeAccountData &&
+          includeEphemeral == other.includeEphemeral;
+}
+
+/// What the sync loop of a client is doing.
+enum SyncState {
+  /// Not syncing: never started, or stopped with `stop_sync`
+  idle,
+  /// Started and catching up on what happened while the client was away
+  initialSync,
+  /// Up to date and waiting for new events
+  running,
+  /// The last sync failed (`error` has a `RateLimited` kind if the server is throttling us);
+  /// the next attempt is at `next_retry_ms`
+  backoff,
+  /// The homeserver can't be reached; the next attempt is at `next_retry_ms`
+  offline,
+  /// The server no longer accepts the access token. Sync has stopped; log in again
+  /// (or restore a session) and start it again
+  sessionExpired,
+  ;
+}
+
+class SyncStatus {
+  final SyncState state;
+  /// Failed syncs in a row, for `Backoff` and `Offline`
+  final int attempt;
+  /// Unix time in ms of the next attempt, for `Backoff` and `Offline`
+  final BigInt? nextRetryMs;
+  /// Why the last sync failed
+  final BridgeError? error;
+
+  const SyncStatus({
+    required this.state,
+    required this.attempt,
+    this.nextRetryMs,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      state.hashCode ^ attempt.hashCode ^ nextRetryMs.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncStatus &&
+          runtimeType == other.runtimeType &&
+          state == other.state &&
+          attempt == other.attempt &&
+          nextRetryMs == other.nextRetryMs &&
+          error == other.error;
+}
+
+/// Size of a server-generated thumbnail to fetch instead of the full media.
+class ThumbnailRequest {
+  final int width;
+  final int height;
+  /// Crop to exactly width x height instead of scaling to fit
+  final bool crop;
+
+  const ThumbnailRequest({
+    required this.width,
+    required this.height,
+    required this.crop,
+  });
+
+  @override
+  int get hashCode => width.hashCode ^ height.hashCode ^ crop.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThumbnailRequest &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          crop == other.crop;
+}
+
+/// Which way to paginate through a room's history.
+enum TimelineDirection {
+  /// From newer to older events (scrolling up)
+  backward,
+  /// From older to newer events
+  forward,
+  ;
+}
+
+/// A room message as shown in the timeline, shared by history and the live event stream.
+class TimelineItem {
   final String roomId;
   final String eventId;
   final String sender;
-  final PlatformInt64 ts;
-  final String? content;
+  /// origin_server_ts in milliseconds since the Unix epoch
+  final PlatformInt64 timestampMs;
+  final MessageKind kind;
+  /// Plain text body with any reply fallback stripped
+  final String body;
+  /// org.matrix.custom.html body, if the sender provided one
+  final String? formattedBody;
+  /// True if the event was sent encrypted (whether or not we could decrypt it)
   final bool isEncrypted;
+  /// Why decryption failed, only set for `MessageKind::UnableToDecrypt`
+  final String? decryptionError;
+  /// Set when this event is an edit; body/kind then hold the new content for that event
+  final String? replacesEventId;
+  /// Event this message replies to
+  final String? inReplyTo;
+  final Attachment? attachment;
+  final SendState sendState;
 
-  const MatrixEvent({
+  const TimelineItem({
     required this.roomId,
     required this.eventId,
     required this.sender,
-    required this.ts,
-    this.content,
+    required this.timestampMs,
+    required this.kind,
+    required this.body,
+    this.formattedBody,
     required this.isEncrypted,
+    this.decryptionError,
+    this.replacesEventId,
+    this.inReplyTo,
+    this.attachment,
+    required this.sendState,
   });
 
   @override
@@ -87,19 +1572,61 @@ class MatrixEvent {
       roomId.hashCode ^
       eventId.hashCode ^
       sender.hashCode ^
-      ts.hashCode ^
-      content.hashCode ^
-      isEncrypted.hashCode;
+      timestampMs.hashCode ^
+      kind.hashCode ^
+      body.hashCode ^
+      formattedBody.hashCode ^
+      isEncrypted.hashCode ^
+      decryptionError.hashCode ^
+      replacesEventId.hashCode ^
+      inReplyTo.hashCode ^
+      attachment.hashCode ^
+      sendState.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is MatrixEvent &&
+      other is TimelineItem &&
           runtimeType == other.runtimeType &&
           roomId == other.roomId &&
           eventId == other.eventId &&
           sender == other.sender &&
-          ts == other.ts &&
-          content == other.content &&
-          isEncrypted == other.isEncrypted;
+          timestampMs == other.timestampMs &&
+          kind == other.kind &&
+          body == other.body &&
+          formattedBody == other.formattedBody &&
+          isEncrypted == other.isEncrypted &&
+          decryptionError == other.decryptionError &&
+          replacesEventId == other.replacesEventId &&
+          inReplyTo == other.inReplyTo &&
+          attachment == other.attachment &&
+          sendState == other.sendState;
+}
+
+class TimelinePage {
+  /// Messages in chronological order
+  final List<TimelineItem> messages;
+  /// Continuation token for the next page in the same direction, None when there is nothing further
+  final String? nextToken;
+  /// True once backward pagination has reached the beginning of the room
+  final bool reachedStart;
+
+  const TimelinePage({
+    required this.messages,
+    this.nextToken,
+    required this.reachedStart,
+  });
+
+  @override
+  int get hashCode =>
+      messages.hashCode ^ nextToken.hashCode ^ reachedStart.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimelinePage &&
+          runtimeType == other.runtimeType &&
+          messages == other.messages &&
+          nextToken == other.nextToken &&
+          reachedStart == other.reachedStart;
 }
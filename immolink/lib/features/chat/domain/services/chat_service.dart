@@ -310,7 +310,9 @@ class ChatService {
 
           // If backend stored a stale password, Matrix will return M_FORBIDDEN.
           // In that case, re-provision credentials and retry once.
-          final isForbidden = msg.contains('M_FORBIDDEN') ||
+          final isForbidden = (e is frb.BridgeError &&
+                  e.kind == frb.BridgeErrorKind.forbidden) ||
+              msg.contains('M_FORBIDDEN') ||
               msg.contains('Invalid username or password');
           if (!required && isForbidden) {
             try {
@@ -449,7 +451,8 @@ class ChatService {
           defaultTargetPlatform == TargetPlatform.linux ||
           defaultTargetPlatform == TargetPlatform.macOS) {
         // Desktop: Use Rust bridge
-        matrixEventId = await frb.sendMessage(roomId: roomId, body: content);
+        matrixEventId = await MatrixChatService.instance
+            .sendMessage(roomId: roomId, body: content);
       } else {
         // Mobile: Use Dart Matrix SDK
         final mobileClient = MobileMatrixClient.instance;
@@ -481,8 +484,8 @@ class ChatService {
               defaultTargetPlatform == TargetPlatform.linux ||
               defaultTargetPlatform == TargetPlatform.macOS) {
             // Desktop: Use Rust bridge
-            matrixEventId =
-                await frb.sendMessage(roomId: roomId, body: content);
+            matrixEventId = await MatrixChatService.instance
+                .sendMessage(roomId: roomId, body: content);
           } else {
             // Mobile: Use Dart Matrix SDK
             final mobileClient = MobileMatrixClient.instance;
@@ -505,6 +508,7 @@ class ChatService {
     required String conversationId,
     required String creatorUserId,
     required String otherUserId,
+    frb.PropertyMetadata? property,
     Ref? ref,
   }) async {
     // Use JWT authentication
@@ -539,6 +543,7 @@ class ChatService {
         conversationId: conversationId,
         creatorUserId: creatorUserId,
         otherUserId: otherUserId,
+        property: property,
         ref: ref,
       );
     }
@@ -546,10 +551,12 @@ class ChatService {
   }
 
   /// Ensure/create the Matrix room mapping via SDK and return the roomId if successful.
+  /// On desktop, [property] is stored with the room (see `getOrCreateDm`).
   Future<String?> _ensureRoom({
     required String conversationId,
     required String creatorUserId,
     required String otherUserId,
+    frb.PropertyMetadata? property,
     Ref? ref,
   }) async {
     // Use JWT authentication
@@ -617,15 +624,21 @@ class ChatService {
       if (defaultTargetPlatform == TargetPlatform.windows ||
           defaultTargetPlatform == TargetPlatform.linux ||
           defaultTargetPlatform == TargetPlatform.macOS) {
-        // Desktop: Use Rust bridge
+        // Desktop: Use Rust bridge, reusing an existing DM with the user
         // ignore: avoid_print
         print(
-            '[MatrixRoom] Creating room with Rust bridge, otherMxid: $otherMxid, creatorMxid: $creatorMxid');
-        roomId = await frb.createRoom(
-          otherMxid: otherMxid,
-          creatorMxid:
-              creatorMxid, // Pass creator's MXID to invite their other sessions
+            '[MatrixRoom] Getting or creating DM with Rust bridge, otherMxid: $otherMxid');
+        final dm = await frb.getOrCreateDm(
+          client: MatrixChatService.instance.clientHandle,
+          userId: otherMxid,
+          alsoInvite: creatorMxid == null ? null : [creatorMxid],
+          property: property,
         );
+        roomId = dm.roomId;
+        if (!dm.created) {
+          // ignore: avoid_print
+          print('[MatrixRoom] Reusing existing DM room: $roomId');
+        }
       } else {
         // Mobile: Use Dart Matrix SDK
         // ignore: avoid_print
@@ -701,15 +714,18 @@ class ChatService {
   }
 
   // Public helper for UI layers to resolve room mapping without duplicating logic
+  // [property] ties a newly created room to the property the chat is about
   Future<String?> getMatrixRoomIdForConversation({
     required String conversationId,
     required String currentUserId,
     required String otherUserId,
+    frb.PropertyMetadata? property,
   }) async {
     return _fetchOrCreateMatrixRoomId(
         conversationId: conversationId,
         creatorUserId: currentUserId,
-        otherUserId: otherUserId);
+        otherUserId: otherUserId,
+        property: property);
   }
 
   // Note: Conversation preview updates are handled by backend on message POST
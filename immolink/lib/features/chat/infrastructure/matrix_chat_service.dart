@@ -1,4 +1,5 @@
 import 'package:flutter/foundation.dart';
+import 'package:flutter_secure_storage/flutter_secure_storage.dart';
 import 'package:path_provider/path_provider.dart';
 import 'package:immosync/bridge.dart' as frb;
 import 'package:immosync/core/config/db_config.dart';
@@ -6,6 +7,7 @@ import 'package:http/http.dart' as http;
 import 'dart:convert';
 import 'dart:io';
 import 'dart:async';
+import 'dart:math';
 import 'mobile_matrix_client.dart';
 import 'package:immosync/core/services/token_manager.dart';
 // Timeline ingestion is handled by MatrixFrbEventsAdapter; avoid duplicating here.
@@ -15,19 +17,30 @@ class MatrixChatService {
   MatrixChatService._();
 
   bool _inited = false;
+  frb.ClientHandle? _client;
   String? _readyUserId;
   bool _loggedIn = false;
   bool _syncStarted = false;
   Future<void>? _initializationInProgress;
-  StreamSubscription<frb.MatrixEvent>? _eventSub;
+  StreamSubscription<frb.TimelineItem>? _eventSub;
   Completer<void>? _firstEventCompleter;
   final TokenManager _tokenManager = TokenManager();
+  static const _storePassphraseKey = 'matrix.store.passphrase';
+  final FlutterSecureStorage _secureStorage = const FlutterSecureStorage();
   // Broadcast bus for Matrix events so only ONE native subscription is used
-  final StreamController<frb.MatrixEvent> _eventBus =
-      StreamController<frb.MatrixEvent>.broadcast();
+  final StreamController<frb.TimelineItem> _eventBus =
+      StreamController<frb.TimelineItem>.broadcast();
 
   /// Public stream of Matrix events (ingested from the single native subscription)
-  Stream<frb.MatrixEvent> get eventStream => _eventBus.stream;
+  Stream<frb.TimelineItem> get eventStream => _eventBus.stream;
+
+  StreamSubscription<frb.SyncStatus>? _syncStatusSub;
+  final StreamController<frb.SyncStatus> _syncStatusBus =
+      StreamController<frb.SyncStatus>.broadcast();
+
+  /// Whether native chat is live, catching up, retrying, offline or needs a
+  /// new login. Only the desktop client reports this.
+  Stream<frb.SyncStatus> get syncStatusStream => _syncStatusBus.stream;
 
   /// Check if the current platform supports the Matrix Rust bridge
   bool get _isRustBridgeSupported {
@@ -40,7 +53,23 @@ class MatrixChatService {
   MobileMatrixClient? get _mobileClient =>
       _isRustBridgeSupported ? null : MobileMatrixClient.instance;
 
-  Future<void> ensureInitialized({required String homeserver}) async {
+  /// Handle of the native client created by [ensureInitialized].
+  frb.ClientHandle get clientHandle {
+    final client = _client;
+    if (client == null) {
+      throw StateError('Matrix client not initialized');
+    }
+    return client;
+  }
+
+  /// With [slidingSync] the desktop client syncs only the visible part of the
+  /// room list, falling back to classic sync if the server can't.
+  /// [invitePolicy] decides which invites are joined without asking the user
+  /// (all of them without one); see [invites].
+  Future<void> ensureInitialized(
+      {required String homeserver,
+      frb.SlidingSyncConfig? slidingSync,
+      frb.InvitePolicy? invitePolicy}) async {
     if (_inited) return;
 
     // Check for delete marker from previous device mismatch
@@ -67,18 +96,14 @@ class MatrixChatService {
 
     if (_isRustBridgeSupported) {
       // Use Rust bridge on desktop platforms
-      try {
-        await frb.init(homeserver: homeserver, dataDir: dir.path);
-      } catch (e) {
-        final msg = e.toString().toLowerCase();
-        if (msg.contains('already initialized') ||
-            msg.contains('client already initialized')) {
-          // Idempotent init: another call already created the client. Treat as success.
-          print('[MatrixChatService] init skipped (already initialized)');
-        } else {
-          rethrow;
-        }
-      }
+      // Idempotent: returns the existing handle for an already open store
+      // The store is encrypted with a passphrase kept in the platform keystore
+      _client = await frb.init(
+          homeserver: homeserver,
+          dataDir: dir.path,
+          passphrase: await _storePassphrase(),
+          slidingSync: slidingSync,
+          invitePolicy: invitePolicy);
     } else {
       // Use mobile client on mobile platforms
       print(
@@ -89,41 +114,125 @@ class MatrixChatService {
     _inited = true;
   }
 
-  Future<void> login(
+  /// Passphrase for the native Matrix store, generated on first use.
+  Future<String> _storePassphrase() async {
+    final existing = await _secureStorage.read(key: _storePassphraseKey);
+    if (existing != null) return existing;
+    final random = Random.secure();
+    final bytes = List<int>.generate(32, (_) => random.nextInt(256));
+    final passphrase = base64Encode(bytes);
+    await _secureStorage.write(key: _storePassphraseKey, value: passphrase);
+    return passphrase;
+  }
+
+  /// Log in with a password. On desktop the returned session (access token,
+  /// device id) can be saved and handed to [restoreSession] later.
+  Future<frb.LoginResult?> login(
       {required String username, required String password}) async {
+    frb.LoginResult? session;
     if (_isRustBridgeSupported) {
-      await frb.login(user: username, password: password);
+      session = await frb.login(
+          client: clientHandle, user: username, password: password);
     } else {
       final result = await _mobileClient!.login(username, password);
       print('[MatrixChatService] Mobile login successful: ${result['userId']}');
     }
     _loggedIn = true;
+    return session;
   }
 
+  /// Restore a previously saved session without a password round-trip,
+  /// e.g. after the local store was wiped.
+  Future<frb.LoginResult> restoreSession(frb.LoginResult session) async {
+    if (!_isRustBridgeSupported) {
+      throw UnsupportedError(
+          'Session restore is not supported by the mobile client yet');
+    }
+    final restored =
+        await frb.restoreSession(client: clientHandle, session: session);
+    _loggedIn = true;
+    return restored;
+  }
+
+  /// [options] bound the call on desktop with a timeout and/or a
+  /// [frb.CancelToken]; the mobile client ignores them.
   Future<String> sendMessage(
-      {required String roomId, required String body}) async {
+      {required String roomId,
+      required String body,
+      frb.CallOptions? options}) async {
     if (_isRustBridgeSupported) {
-      return frb.sendMessage(roomId: roomId, body: body);
+      return frb.sendMessage(
+          client: clientHandle, roomId: roomId, body: body, options: options);
     } else {
       return _mobileClient!.sendMessage(roomId, body);
     }
   }
 
+  /// Upload a file (from [filePath] or [data]) and post it to the room.
+  /// The native side picks m.image/m.file/m.video/m.audio from [mimeType].
+  Future<String> sendAttachment(
+      {required String roomId,
+      String? filePath,
+      Uint8List? data,
+      String? filename,
+      required String mimeType,
+      frb.CallOptions? options}) async {
+    if (_isRustBridgeSupported) {
+      return frb.sendAttachment(
+          client: clientHandle,
+          roomId: roomId,
+          filePath: filePath,
+          data: data,
+          filename: filename,
+          mimeType: mimeType,
+          options: options);
+    } else {
+      throw UnsupportedError(
+          'Matrix attachments are not supported by the mobile client yet');
+    }
+  }
+
+  /// Resolve an attachment to a local file path via the native media cache.
+  /// [source] is the `source` (or `thumbnailSource`) stored in the message
+  /// metadata. Pass the attachment's [size] so files too large for the cache
+  /// are turned down without downloading them.
+  Future<String> fetchMedia(
+      {required String source,
+      String? filename,
+      BigInt? size,
+      frb.ThumbnailRequest? thumbnail,
+      frb.CallOptions? options}) async {
+    if (_isRustBridgeSupported) {
+      return frb.fetchMedia(
+          client: clientHandle,
+          source: source,
+          filename: filename,
+          size: size,
+          thumbnail: thumbnail,
+          options: options);
+    } else {
+      throw UnsupportedError(
+          'Matrix media download is not supported by the mobile client yet');
+    }
+  }
+
+  /// What desktop sync leaves out to save data: members are lazy-loaded
+  /// and presence isn't shown anywhere.
+  static const _syncFilter = frb.SyncFilterConfig(
+      lazyLoadMembers: true,
+      timelineLimit: 20,
+      includePresence: false,
+      includeAccountData: true,
+      includeEphemeral: true);
+
   Future<void> startSync() async {
     if (_syncStarted) return;
 
     if (_isRustBridgeSupported) {
-      try {
-        await frb.startSync();
-      } catch (e) {
-        final msg = e.toString().toLowerCase();
-        if (msg.contains('already running') ||
-            msg.contains('already started')) {
-          print('[MatrixChatService] startSync skipped (already running)');
-        } else {
-          rethrow;
-        }
-      }
+      _ensureSyncStatusSubscription();
+      await frb.setSyncFilter(client: clientHandle, filter: _syncFilter);
+      // Calling startSync on a client that is already syncing is a no-op
+      await frb.startSync(client: clientHandle);
     } else {
       // Mobile client handles sync automatically after login
       print('[MatrixChatService] Mobile client sync is automatic');
@@ -135,7 +244,7 @@ class MatrixChatService {
     if (_eventSub != null) return;
     _firstEventCompleter ??= Completer<void>();
     try {
-      _eventSub = frb.subscribeEvents().listen((event) {
+      _eventSub = frb.subscribeEvents(client: clientHandle).listen((event) {
         // Signal that at least one sync event has been received
         if (!(_firstEventCompleter?.isCompleted ?? true)) {
           _firstEventCompleter?.complete();
@@ -162,6 +271,32 @@ class MatrixChatService {
     }
   }
 
+  void _ensureSyncStatusSubscription() {
+    if (_syncStatusSub != null) return;
+    _syncStatusSub =
+        frb.subscribeSyncStatus(client: clientHandle).listen((status) {
+      // Sync stops by itself once the session is gone; allow a restart after
+      // the next login
+      if (status.state == frb.SyncState.sessionExpired) {
+        _syncStarted = false;
+        _loggedIn = false;
+      }
+      if (!_syncStatusBus.isClosed) {
+        _syncStatusBus.add(status);
+      }
+    }, onDone: () {
+      _syncStatusSub = null;
+    });
+  }
+
+  /// Call when the device regains network connectivity, so a sync waiting
+  /// out its retry delay reconnects right away.
+  Future<void> notifyNetworkAvailable() async {
+    if (_isRustBridgeSupported) {
+      await frb.notifyNetworkAvailable();
+    }
+  }
+
   Future<void> waitForFirstSyncEvent(
       {Duration timeout = const Duration(seconds: 5)}) async {
     _ensureEventSubscription();
@@ -174,9 +309,10 @@ class MatrixChatService {
     }
   }
 
+  /// Completes once the sync in flight is stored and sync has stopped.
   Future<void> stopSync() async {
     if (_isRustBridgeSupported) {
-      await frb.stopSync();
+      await frb.stopSync(client: clientHandle);
     } else {
       // Mobile client doesn't need explicit sync stop
       print('[MatrixChatService] Mobile client sync management is automatic');
@@ -184,9 +320,281 @@ class MatrixChatService {
     _syncStarted = false;
   }
 
+  /// Log out on the homeserver and wipe the local Matrix store.
+  /// With [deleteDevice] the device is removed from the account too; the
+  /// server usually asks for the account [password] to allow that.
+  Future<void> logout(
+      {bool deleteDevice = false,
+      String? password,
+      frb.CallOptions? options}) async {
+    if (_isRustBridgeSupported) {
+      await frb.logout(
+          client: clientHandle,
+          deleteDevice: deleteDevice,
+          password: password,
+          options: options);
+    } else {
+      throw UnsupportedError('Logout is not supported by the mobile client yet');
+    }
+    // The native side disposes the client as part of logout
+    _resetClientState();
+  }
+
+  /// Tear down the native client so the next [ensureInitialized] builds a
+  /// fresh one (e.g. for another homeserver or account).
+  Future<void> disposeClient() async {
+    final client = _client;
+    if (_isRustBridgeSupported && client != null) {
+      await frb.disposeClient(client: client);
+    }
+    _resetClientState();
+  }
+
+  /// Live timeline items of every native client, tagged with the account
+  /// they arrived on. Feeds a unified inbox when several accounts are open
+  /// at once (see [frb.initAccount]).
+  Stream<frb.AccountTimelineItem> allAccountEvents() {
+    if (!_isRustBridgeSupported) {
+      throw UnsupportedError(
+          'Multiple accounts are not supported by the mobile client yet');
+    }
+    return frb.subscribeAllEvents();
+  }
+
+  /// The native clients that are currently open, one per account.
+  Future<List<frb.ClientInfo>> listClients() async {
+    if (!_isRustBridgeSupported) return const [];
+    return frb.listClients();
+  }
+
+  /// Reports recoveries from a native store that belonged to another device:
+  /// the old store is quarantined, and its room keys are backed up and
+  /// re-imported after the next login.
+  Stream<frb.StoreRecoveryEvent> storeRecoveryEvents() {
+    if (!_isRustBridgeSupported) return const Stream.empty();
+    return frb.subscribeStoreRecovery();
+  }
+
+  /// Joined and invited rooms with name, members, latest message and unread
+  /// counts, most recently active first.
+  /// With [spaceId], only the rooms in that space (e.g. a property's).
+  Future<List<frb.RoomSummary>> listRooms(
+      {String? spaceId, frb.CallOptions? options}) async {
+    if (!_isRustBridgeSupported) {
+      throw UnsupportedError(
+          'The room list is not supported by the mobile client yet');
+    }
+    return frb.listRooms(
+        client: clientHandle, spaceId: spaceId, options: options);
+  }
+
+  /// Changes to [listRooms] as sync brings them in.
+  Stream<frb.RoomListUpdate> roomListUpdates() {
+    if (!_isRustBridgeSupported) return const Stream.empty();
+    return frb.subscribeRoomList(client: clientHandle);
+  }
+
+  /// Invites the invite policy left to the user, as they come in.
+  Stream<frb.InviteInfo> invites() {
+    if (!_isRustBridgeSupported) return const Stream.empty();
+    return frb.subscribeInvites(client: clientHandle);
+  }
+
+  /// Invites waiting for an answer, including those from earlier sessions.
+  Future<List<frb.InviteInfo>> listInvites({frb.CallOptions? options}) async {
+    if (!_isRustBridgeSupported) return const [];
+    return frb.listInvites(client: clientHandle, options: options);
+  }
+
+  Future<void> acceptInvite(String roomId, {frb.CallOptions? options}) async {
+    if (!_isRustBridgeSupported) {
+      throw UnsupportedError(
+          'Invite handling is not supported by the mobile client yet');
+    }
+    await frb.acceptInvite(
+        client: clientHandle, roomId: roomId, options: options);
+  }
+
+  Future<void> declineInvite(String roomId, {frb.CallOptions? options}) async {
+    if (!_isRustBridgeSupported) {
+      throw UnsupportedError(
+          'Invite handling is not supported by the mobile client yet');
+    }
+    await frb.declineInvite(
+        client: clientHandle, roomId: roomId, options: options);
+  }
+
+  /// Create a shared room, e.g. for a property, and invite the participants
+  /// with power levels following their roles. Returns the room id.
+  Future<String> createGroupRoom(frb.GroupRoomConfig config,
+      {frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    return frb.createGroupRoom(
+        client: clientHandle, config: config, options: options);
+  }
+
+  /// Tie a room to a property and tenancy, replacing any metadata it had.
+  Future<void> setPropertyMetadata(String roomId, frb.PropertyMetadata metadata,
+      {frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    await frb.setPropertyMetadata(
+        client: clientHandle,
+        roomId: roomId,
+        metadata: metadata,
+        options: options);
+  }
+
+  /// The property a room is about, or null if it isn't tied to one.
+  Future<frb.PropertyMetadata?> getPropertyMetadata(String roomId,
+      {frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    return frb.getPropertyMetadata(
+        client: clientHandle, roomId: roomId, options: options);
+  }
+
+  /// Create a space for a property or portfolio, optionally inside
+  /// [parentSpaceId]. Returns the space's room id.
+  Future<String> createSpace(String name,
+      {String? topic, String? parentSpaceId, frb.CallOptions? options}) async {
+    _requireSpaceSupport();
+    return frb.createSpace(
+        client: clientHandle,
+        name: name,
+        topic: topic,
+        parentSpaceId: parentSpaceId,
+        options: options);
+  }
+
+  Future<void> addSpaceChild(String spaceId, String childId,
+      {frb.CallOptions? options}) async {
+    _requireSpaceSupport();
+    await frb.addSpaceChild(
+        client: clientHandle,
+        spaceId: spaceId,
+        childId: childId,
+        options: options);
+  }
+
+  Future<void> removeSpaceChild(String spaceId, String childId,
+      {frb.CallOptions? options}) async {
+    _requireSpaceSupport();
+    await frb.removeSpaceChild(
+        client: clientHandle,
+        spaceId: spaceId,
+        childId: childId,
+        options: options);
+  }
+
+  Future<List<frb.SpaceHierarchyEntry>> getSpaceHierarchy(String spaceId,
+      {frb.CallOptions? options}) async {
+    _requireSpaceSupport();
+    return frb.getSpaceHierarchy(
+        client: clientHandle, spaceId: spaceId, options: options);
+  }
+
+  void _requireSpaceSupport() {
+    if (!_isRustBridgeSupported) {
+      throw UnsupportedError(
+          'Spaces are not supported by the mobile client yet');
+    }
+  }
+
+  /// Members of a room with their membership and power level, including
+  /// those who left or were banned.
+  Future<List<frb.RoomMemberInfo>> getRoomMembers(String roomId,
+      {frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    return frb.getRoomMembers(
+        client: clientHandle, roomId: roomId, options: options);
+  }
+
+  Future<void> inviteUser(String roomId, String userId,
+      {frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    await frb.inviteUser(
+        client: clientHandle, roomId: roomId, userId: userId, options: options);
+  }
+
+  Future<void> kickUser(String roomId, String userId,
+      {String? reason, frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    await frb.kickUser(
+        client: clientHandle,
+        roomId: roomId,
+        userId: userId,
+        reason: reason,
+        options: options);
+  }
+
+  Future<void> banUser(String roomId, String userId,
+      {String? reason, frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    await frb.banUser(
+        client: clientHandle,
+        roomId: roomId,
+        userId: userId,
+        reason: reason,
+        options: options);
+  }
+
+  Future<void> unbanUser(String roomId, String userId,
+      {String? reason, frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    await frb.unbanUser(
+        client: clientHandle,
+        roomId: roomId,
+        userId: userId,
+        reason: reason,
+        options: options);
+  }
+
+  Future<void> leaveRoom(String roomId, {frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    await frb.leaveRoom(client: clientHandle, roomId: roomId, options: options);
+  }
+
+  /// Drop a room left earlier from the account for good.
+  Future<void> forgetRoom(String roomId, {frb.CallOptions? options}) async {
+    _requireMembershipSupport();
+    await frb.forgetRoom(
+        client: clientHandle, roomId: roomId, options: options);
+  }
+
+  void _requireMembershipSupport() {
+    if (!_isRustBridgeSupported) {
+      throw UnsupportedError(
+          'Membership management is not supported by the mobile client yet');
+    }
+  }
+
+  /// Tell sliding sync which rooms of the list are on screen, e.g. as the
+  /// user scrolls. A no-op under classic sync and on mobile.
+  Future<void> setVisibleRooms(
+      {required int start,
+      required int count,
+      frb.CallOptions? options}) async {
+    if (!_isRustBridgeSupported) return;
+    await frb.setVisibleRooms(
+        client: clientHandle, start: start, count: count, options: options);
+  }
+
+  void _resetClientState() {
+    _eventSub?.cancel();
+    _eventSub = null;
+    _syncStatusSub?.cancel();
+    _syncStatusSub = null;
+    _firstEventCompleter = null;
+    _client = null;
+    _inited = false;
+    _syncStarted = false;
+    _loggedIn = false;
+    _readyUserId = null;
+  }
+
   Future<void> markRead({required String roomId, required String eventId}) {
     if (_isRustBridgeSupported) {
-      return frb.markRead(roomId: roomId, eventId: eventId);
+      return frb.markRead(
+          client: clientHandle, roomId: roomId, eventId: eventId);
     } else {
       return _mobileClient!.markRead(roomId, eventId);
     }
@@ -325,131 +733,15 @@ class MatrixChatService {
 
       print('[MatrixChatService] Initializing Matrix client...');
 
-      // Try to initialize, if it fails with device mismatch, clear and retry
-      try {
-        await ensureInitialized(homeserver: homeserver);
-      } catch (e) {
-        final errorMsg = e.toString().toLowerCase();
-        if (errorMsg.contains('account in the store doesn\'t match') ||
-            errorMsg.contains('device mismatch')) {
-          print(
-              '[MatrixChatService] Device mismatch during init, clearing store...');
-          final dir = await getApplicationSupportDirectory();
-          final storeDir = Directory(dir.path);
-          if (await storeDir.exists()) {
-            print('[MatrixChatService] Deleting store directory: ${dir.path}');
-            await storeDir.delete(recursive: true);
-            print('[MatrixChatService] Store deleted, reinitializing...');
-          }
-          _inited = false;
-          await ensureInitialized(homeserver: homeserver);
-          print('[MatrixChatService] Reinitialized with fresh store');
-        } else {
-          rethrow;
-        }
-      }
+      // A store left behind by another device is quarantined natively (not
+      // deleted) and its room keys are re-imported after login; see
+      // [storeRecoveryEvents].
+      await ensureInitialized(homeserver: homeserver);
 
       if (!_loggedIn) {
         print('[MatrixChatService] Logging in as $username...');
-        try {
-          await login(username: username, password: password);
-          print('[MatrixChatService] Login successful');
-        } catch (e) {
-          // Check if it's a device mismatch error during login
-          final errorMsg = e.toString().toLowerCase();
-          if (errorMsg.contains('account in the store doesn\'t match') ||
-              errorMsg.contains('device mismatch') ||
-              errorMsg.contains('crypto store')) {
-            print(
-                '[MatrixChatService] Device mismatch detected during login, clearing store and retrying...');
-            try {
-              // Stop sync if running to release file handles
-              if (_syncStarted) {
-                try {
-                  print(
-                      '[MatrixChatService] Stopping sync to release file handles...');
-                  await stopSync();
-                  await Future.delayed(const Duration(
-                      milliseconds: 500)); // Give time for handles to close
-                } catch (stopError) {
-                  print('[MatrixChatService] Error stopping sync: $stopError');
-                }
-              }
-
-              // Reset state BEFORE deleting to invalidate the client
-              _inited = false;
-              _loggedIn = false;
-              _syncStarted = false;
-
-              final dir = await getApplicationSupportDirectory();
-              final storeDir = Directory(dir.path);
-
-              // Try to delete with retries
-              const int retries = 3;
-              bool deleted = false;
-              for (int i = 0; i < retries && !deleted; i++) {
-                if (i > 0) {
-                  print(
-                      '[MatrixChatService] Retry $i after ${i * 500}ms delay...');
-                  await Future.delayed(Duration(milliseconds: i * 500));
-                }
-
-                if (await storeDir.exists()) {
-                  try {
-                    print(
-                        '[MatrixChatService] Deleting store directory: ${dir.path}');
-                    await storeDir.delete(recursive: true);
-                    deleted = true;
-                    print(
-                        '[MatrixChatService] Store deleted, reinitializing...');
-                  } catch (deleteError) {
-                    if (i == retries - 1) {
-                      rethrow; // Rethrow on last retry
-                    }
-                    print(
-                        '[MatrixChatService] Delete attempt ${i + 1} failed: $deleteError');
-                  }
-                } else {
-                  deleted = true; // Already gone
-                }
-              }
-
-              // Reinitialize and login with fresh store
-              await ensureInitialized(homeserver: homeserver);
-              await login(username: username, password: password);
-              print('[MatrixChatService] Login successful after store clear');
-            } catch (clearError) {
-              print(
-                  '[MatrixChatService] Failed to recover from device mismatch: $clearError');
-              final dir = await getApplicationSupportDirectory();
-
-              // The store directory is locked by the Matrix client - force app restart
-              print(
-                  '[MatrixChatService] Store is locked, scheduling auto-restart...');
-              print('[MatrixChatService] Store location: ${dir.path}');
-
-              // Create a marker file so we know to delete the store on next startup
-              final markerFile = File('${dir.path}_delete_marker');
-              try {
-                await markerFile.writeAsString('delete_on_restart');
-                print('[MatrixChatService] Created delete marker file');
-              } catch (markerError) {
-                print(
-                    '[MatrixChatService] Failed to create marker: $markerError');
-              }
-
-              // Schedule app restart (platform-specific)
-              print(
-                  '[MatrixChatService] Requesting app restart to clear Matrix store...');
-
-              // Give user a brief notification before restart
-              throw Exception(
-                  'Matrix device mismatch detected. App will restart automatically to clear store...');
-            }
-          } else {
-            rethrow;
-          }
-        }
+        await login(username: username, password: password);
+        print('[MatrixChatService] Login successful');
       } else {
         print('[MatrixChatService] Already logged in');
       }
@@ -2,6 +2,7 @@ import 'dart:async';
 
 import 'package:flutter/foundation.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
+import 'package:immosync/bridge.dart' as frb;
 import 'package:immosync/features/chat/domain/models/chat_message.dart';
 import 'package:immosync/features/chat/infrastructure/matrix_timeline_service.dart';
 import 'package:immosync/features/chat/infrastructure/matrix_chat_service.dart';
@@ -43,63 +44,22 @@ class MatrixFrbEventsAdapter {
     // Signal that we're listening to the Rust bridge event stream
     print('[MatrixAdapter] Attaching Rust bridge events subscription...');
     // Subscribe to the MatrixChatService event bus (single native subscription upstream)
-    // The event exposes: room_id, event_id, sender, ts (secs), content (optional), is_encrypted
-    _sub = MatrixChatService.instance.eventStream.listen((evt) {
+    // Each event is a typed TimelineItem, the same model history loading uses
+    _sub = MatrixChatService.instance.eventStream.listen((item) {
       try {
-        final roomId = evt.roomId;
-        if (roomId.isEmpty) return;
-        // Timestamp is in seconds from Rust, convert to milliseconds
-        final tsSecs = evt.ts.toInt();
-        final ts =
-            DateTime.fromMillisecondsSinceEpoch(tsSecs * 1000, isUtc: true);
-
-        // Content should already be decrypted by the SDK if keys are available
-        // If content is null/empty but marked encrypted, show placeholder
-        final hasPlain = (evt.content != null && evt.content!.isNotEmpty);
-        final displayContent =
-            hasPlain ? evt.content! : (evt.isEncrypted ? '[encrypted]' : '');
-
-        // Extract user ID from Matrix MXID format: @userid:homeserver -> userid
-        // Example: @6838699baefe2c0213aba1c3:matrix.immosync.ch -> 6838699baefe2c0213aba1c3
-        String extractUserId(String mxid) {
-          if (mxid.startsWith('@') && mxid.contains(':')) {
-            return mxid.substring(1, mxid.indexOf(':'));
-          }
-          return mxid; // fallback if format is unexpected
-        }
-
-        final senderId = extractUserId(evt.sender);
-
-        final msg = ChatMessage(
-          id: evt.eventId,
-          senderId: senderId,
-          receiverId: '',
-          content: displayContent,
-          timestamp: ts,
-          isRead: false,
-          deliveredAt:
-              ts, // Message is delivered since we received it from server
-          readAt: null,
-          messageType: 'text',
-          metadata: null,
-          conversationId: roomId, // we will key timeline by roomId here
-          // In our app, Matrix DM rooms are always E2EE; show lock consistently
-          // Some SDKs may report decrypted timeline events as non-encrypted.
-          isEncrypted: true,
-          e2ee: null,
-        );
+        if (item.roomId.isEmpty) return;
 
         print(
-            '[MatrixAdapter] Ingesting event roomId=$roomId eventId=${evt.eventId} sender=${evt.sender} extracted=$senderId encrypted=${evt.isEncrypted} hasContent=${evt.content != null} content=${displayContent.substring(0, displayContent.length > 50 ? 50 : displayContent.length)}');
+            '[MatrixAdapter] Ingesting event roomId=${item.roomId} eventId=${item.eventId} sender=${item.sender} kind=${item.kind.name} encrypted=${item.isEncrypted}');
 
-        _timeline.ingestMatrixEvent(roomId, msg);
+        _timeline.ingestTimelineItem(item);
 
-        // If plaintext was not available yet, trigger a quick history refresh
-        // to backfill decrypted content and replace the placeholder.
-        if (!hasPlain && evt.isEncrypted) {
+        // If the keys were missing, trigger a quick history refresh to
+        // backfill decrypted content and replace the placeholder.
+        if (item.kind == frb.MessageKind.unableToDecrypt) {
           // Small delay allows crypto to catch up
           Future.delayed(const Duration(milliseconds: 300), () {
-            _timeline.refreshHistory(roomId);
+            _timeline.refreshHistory(item.roomId);
           });
         }
       } catch (e) {
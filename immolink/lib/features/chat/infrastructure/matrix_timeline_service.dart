@@ -4,6 +4,7 @@ import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:flutter/foundation.dart';
 import 'package:immosync/features/chat/domain/models/chat_message.dart';
 import 'package:immosync/bridge.dart' as frb;
+import 'matrix_chat_service.dart';
 import 'mobile_matrix_client.dart';
 
 /// MatrixTimelineService
@@ -101,31 +102,40 @@ class MatrixTimelineService {
       debugPrint('[MatrixTimeline] Calling getRoomMessages...');
 
       // Use appropriate client based on platform
-      String jsonStr;
       if (_isRustBridgeSupported) {
-        jsonStr = await frb.getRoomMessages(roomId: roomId, limit: 50);
-      } else {
-        // Use mobile client
-        final mobileClient = MobileMatrixClient.instance;
-        final ready = await _waitForMobileClientReady(roomId: roomId);
-        if (!ready) {
-          _historyLoaded[roomId] = false;
-          // Retry a few times; initial sync/encryption can be slow.
-          final attempts = (_historyRetryCount[roomId] ?? 0) + 1;
-          _historyRetryCount[roomId] = attempts;
-          if (attempts <= 5) {
-            final delay = Duration(seconds: attempts * 2);
-            scheduleMicrotask(() {
-              Future<void>.delayed(delay, () {
-                if (_historyLoaded[roomId] == true) return;
-                _loadHistoricalMessages(roomId, immediate: true);
-              });
+        final items = await frb.getRoomMessages(
+            client: MatrixChatService.instance.clientHandle,
+            roomId: roomId,
+            limit: 50);
+        debugPrint(
+            '[MatrixTimeline] Loaded ${items.length} historical messages');
+        for (final item in items) {
+          ingestTimelineItem(item);
+        }
+        _historyLoaded[roomId] = true;
+        return;
+      }
+
+      // Use mobile client
+      final mobileClient = MobileMatrixClient.instance;
+      final ready = await _waitForMobileClientReady(roomId: roomId);
+      if (!ready) {
+        _historyLoaded[roomId] = false;
+        // Retry a few times; initial sync/encryption can be slow.
+        final attempts = (_historyRetryCount[roomId] ?? 0) + 1;
+        _historyRetryCount[roomId] = attempts;
+        if (attempts <= 5) {
+          final delay = Duration(seconds: attempts * 2);
+          scheduleMicrotask(() {
+            Future<void>.delayed(delay, () {
+              if (_historyLoaded[roomId] == true) return;
+              _loadHistoricalMessages(roomId, immediate: true);
             });
-          }
-          return;
+          });
         }
-        jsonStr = await mobileClient.getRoomMessages(roomId, 50);
+        return;
       }
+      final jsonStr = await mobileClient.getRoomMessages(roomId, 50);
 
       debugPrint('[MatrixTimeline] getRoomMessages returned: $jsonStr');
 
@@ -143,18 +153,10 @@ class MatrixTimelineService {
 
       for (final msgData in messages) {
         try {
-          // Extract user ID from Matrix MXID format: @userid:homeserver -> userid
-          String extractUserId(String mxid) {
-            if (mxid.startsWith('@') && mxid.contains(':')) {
-              return mxid.substring(1, mxid.indexOf(':'));
-            }
-            return mxid;
-          }
-
-          // Timestamp is in seconds from Rust, convert to milliseconds
+          // Timestamp is in seconds from the mobile client, convert to milliseconds
           final timestampSecs = msgData['timestamp'] as int;
           final senderMxid = msgData['sender'] as String;
-          final senderId = extractUserId(senderMxid);
+          final senderId = _extractUserId(senderMxid);
 
           final ts = DateTime.fromMillisecondsSinceEpoch(timestampSecs * 1000,
               isUtc: true);
@@ -176,17 +178,7 @@ class MatrixTimelineService {
           debugPrint(
               '[MatrixTimeline] Ingesting message from $senderMxid -> $senderId: ${message.content}');
 
-          // If message already exists (e.g., placeholder from live event), replace with full content
-          final list = _buffers.putIfAbsent(roomId, () => <ChatMessage>[]);
-          final existingIdx = list.indexWhere((m) => m.id == message.id);
-          if (existingIdx >= 0) {
-            // Prefer non-empty/non-placeholder content, but replace regardless to normalize timestamps
-            list[existingIdx] = message;
-            _controllers[roomId]?.add(List.unmodifiable(
-                list..sort((a, b) => a.timestamp.compareTo(b.timestamp))));
-          } else {
-            ingestMatrixEvent(roomId, message);
-          }
+          _upsert(roomId, message);
         } catch (e) {
           debugPrint('[MatrixTimeline] Failed to parse message: $e');
         }
@@ -204,6 +196,120 @@ class MatrixTimelineService {
     }
   }
 
+  /// Extract user ID from Matrix MXID format: @userid:homeserver -> userid
+  static String _extractUserId(String mxid) {
+    if (mxid.startsWith('@') && mxid.contains(':')) {
+      return mxid.substring(1, mxid.indexOf(':'));
+    }
+    return mxid; // fallback if format is unexpected
+  }
+
+  static String _messageTypeFor(frb.MessageKind kind) {
+    switch (kind) {
+      case frb.MessageKind.image:
+        return 'image';
+      case frb.MessageKind.file:
+      case frb.MessageKind.video:
+      case frb.MessageKind.audio:
+        return 'file';
+      case frb.MessageKind.text:
+      case frb.MessageKind.notice:
+      case frb.MessageKind.emote:
+        return 'text';
+      default:
+        return 'other';
+    }
+  }
+
+  /// Map a native timeline item onto the app's ChatMessage model.
+  static ChatMessage chatMessageFromTimelineItem(frb.TimelineItem item) {
+    final ts = DateTime.fromMillisecondsSinceEpoch(item.timestampMs.toInt(),
+        isUtc: true);
+    final attachment = item.attachment;
+    final metadata = <String, dynamic>{
+      'kind': item.kind.name,
+      if (item.formattedBody != null) 'formattedBody': item.formattedBody,
+      if (item.inReplyTo != null) 'inReplyTo': item.inReplyTo,
+      if (item.decryptionError != null)
+        'decryptionError': item.decryptionError,
+      if (attachment != null) ...{
+        'fileType': _messageTypeFor(item.kind),
+        'fileName': attachment.filename,
+        'mimeType': attachment.mimetype,
+        'size': attachment.size?.toInt(),
+        'width': attachment.width,
+        'height': attachment.height,
+        'durationMs': attachment.durationMs?.toInt(),
+        'mxcUrl': attachment.url,
+        'source': attachment.source,
+        'thumbnailSource': attachment.thumbnailSource,
+        'isEncryptedFile': attachment.isEncrypted,
+      },
+    };
+    return ChatMessage(
+      id: item.eventId,
+      conversationId: item.roomId, // timeline is keyed by roomId
+      senderId: _extractUserId(item.sender),
+      receiverId: '', // Will be determined by context
+      content: item.body,
+      timestamp: ts,
+      // Items the server handed us are already delivered
+      deliveredAt: item.sendState == frb.SendState.sent ? ts : null,
+      messageType: _messageTypeFor(item.kind),
+      metadata: metadata,
+      // In our app, Matrix DM rooms are always E2EE; show lock consistently
+      isEncrypted: true,
+    );
+  }
+
+  /// Ingest a native timeline item (history or live).
+  /// Edits replace the content of the message they refer to instead of
+  /// appearing as a new message.
+  void ingestTimelineItem(frb.TimelineItem item) {
+    final roomId = item.roomId;
+    if (roomId.isEmpty) return;
+    final message = chatMessageFromTimelineItem(item);
+    final target = item.replacesEventId;
+    if (target != null) {
+      final list = _buffers[roomId];
+      final idx = list?.indexWhere((m) => m.id == target) ?? -1;
+      if (idx >= 0) {
+        final original = list![idx];
+        list[idx] = ChatMessage(
+          id: original.id,
+          senderId: original.senderId,
+          receiverId: original.receiverId,
+          content: message.content,
+          timestamp: original.timestamp,
+          isRead: original.isRead,
+          deliveredAt: original.deliveredAt,
+          readAt: original.readAt,
+          messageType: message.messageType,
+          metadata: {...?message.metadata, 'edited': true},
+          conversationId: original.conversationId,
+          isEncrypted: original.isEncrypted,
+        );
+        _controllers[roomId]?.add(List.unmodifiable(list));
+      }
+      return;
+    }
+    _upsert(roomId, message);
+  }
+
+  /// Insert a message, replacing an existing one with the same id
+  /// (e.g. a placeholder from a live event that history has since decrypted).
+  void _upsert(String roomId, ChatMessage message) {
+    final list = _buffers.putIfAbsent(roomId, () => <ChatMessage>[]);
+    final existingIdx = list.indexWhere((m) => m.id == message.id);
+    if (existingIdx >= 0) {
+      list[existingIdx] = message;
+      _controllers[roomId]?.add(List.unmodifiable(
+          list..sort((a, b) => a.timestamp.compareTo(b.timestamp))));
+    } else {
+      ingestMatrixEvent(roomId, message);
+    }
+  }
+
   /// Returns a snapshot of the currently buffered messages for a room.
   /// A defensive copy is returned so callers cannot mutate internal state.
   List<ChatMessage>? snapshot(String roomId) {
@@ -68,7 +68,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1416983484;
+  int get rustContentHash => 417158950;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,29 +79,254 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<void> crateBridgeAcceptInvite(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options});
+
+  Future<void> crateBridgeAddSpaceChild(
+      {required ClientHandle client,
+      required String spaceId,
+      required String childId,
+      CallOptions? options});
+
+  Future<void> crateBridgeBanUser(
+      {required ClientHandle client,
+      required String roomId,
+      required String userId,
+      String? reason,
+      CallOptions? options});
+
+  Future<CallOptions> crateBridgeCallOptionsDefault();
+
+  void crateBridgeCancelCalls({required CancelToken token});
+
+  Future<void> crateBridgeClearMediaCache({required ClientHandle client});
+
+  Future<void> crateBridgeClearStore({required String dataDir});
+
+  Future<void> crateBridgeConfigureMediaCache(
+      {required ClientHandle client, required MediaCacheConfig config});
+
+  Future<String> crateBridgeCreateGroupRoom(
+      {required ClientHandle client,
+      required GroupRoomConfig config,
+      CallOptions? options});
+
   Future<String> crateBridgeCreateRoom(
-      {required String otherMxid, String? creatorMxid});
+      {required ClientHandle client,
+      required String otherMxid,
+      String? creatorMxid,
+      PropertyMetadata? property,
+      CallOptions? options});
+
+  Future<String> crateBridgeCreateSpace(
+      {required ClientHandle client,
+      required String name,
+      String? topic,
+      String? parentSpaceId,
+      CallOptions? options});
+
+  Future<void> crateBridgeDeclineInvite(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options});
+
+  Future<void> crateBridgeDisposeClient({required ClientHandle client});
+
+  Future<String> crateBridgeFetchMedia(
+      {required ClientHandle client,
+      required String source,
+      String? filename,
+      BigInt? size,
+      ThumbnailRequest? thumbnail,
+      CallOptions? options});
+
+  Future<void> crateBridgeForgetRoom(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options});
+
+  Future<DirectRoom> crateBridgeGetOrCreateDm(
+      {required ClientHandle client,
+      required String userId,
+      List<String>? alsoInvite,
+      PropertyMetadata? property,
+      CallOptions? options});
+
+  Future<PropertyMetadata?> crateBridgeGetPropertyMetadata(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options});
 
-  Future<String> crateBridgeGetRoomMessages(
-      {required String roomId, required int limit});
+  Future<List<RoomMemberInfo>> crateBridgeGetRoomMembers(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options});
 
-  Future<void> crateBridgeInit(
-      {required String homeserver, required String dataDir});
+  Future<List<TimelineItem>> crateBridgeGetRoomMessages(
+      {required ClientHandle client,
+      required String roomId,
+      required int limit,
+      CallOptions? options});
+
+  Future<TimelinePage> crateBridgeGetRoomMessagesPage(
+      {required ClientHandle client,
+      required String roomId,
+      String? from,
+      required TimelineDirection direction,
+      required int limit,
+      CallOptions? options});
+
+  Future<List<SpaceHierarchyEntry>> crateBridgeGetSpaceHierarchy(
+      {required ClientHandle client,
+      required String spaceId,
+      CallOptions? options});
+
+  Future<SyncStatus> crateBridgeGetSyncStatus({required ClientHandle client});
+
+  Future<ClientHandle> crateBridgeInit(
+      {required String homeserver,
+      required String dataDir,
+      String? passphrase,
+      SlidingSyncConfig? slidingSync,
+      InvitePolicy? invitePolicy,
+      CallOptions? options});
+
+  Future<ClientHandle> crateBridgeInitAccount(
+      {required String homeserver,
+      required String baseDir,
+      required String account,
+      String? passphrase,
+      SlidingSyncConfig? slidingSync,
+      InvitePolicy? invitePolicy,
+      CallOptions? options});
+
+  Future<void> crateBridgeInviteUser(
+      {required ClientHandle client,
+      required String roomId,
+      required String userId,
+      CallOptions? options});
+
+  Future<void> crateBridgeKickUser(
+      {required ClientHandle client,
+      required String roomId,
+      required String userId,
+      String? reason,
+      CallOptions? options});
+
+  Future<void> crateBridgeLeaveRoom(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options});
+
+  Future<List<ClientInfo>> crateBridgeListClients();
+
+  Future<List<InviteInfo>> crateBridgeListInvites(
+      {required ClientHandle client, CallOptions? options});
+
+  Future<List<RoomSummary>> crateBridgeListRooms(
+      {required ClientHandle client, String? spaceId, CallOptions? options});
 
   Future<LoginResult> crateBridgeLogin(
-      {required String user, required String password});
+      {required ClientHandle client,
+      required String user,
+      required String password,
+      CallOptions? options});
+
+  Future<void> crateBridgeLogout(
+      {required ClientHandle client,
+      required bool deleteDevice,
+      String? password,
+      CallOptions? options});
 
   Future<void> crateBridgeMarkRead(
-      {required String roomId, required String eventId});
+      {required ClientHandle client,
+      required String roomId,
+      required String eventId,
+      CallOptions? options});
+
+  CancelToken crateBridgeNewCancelToken();
+
+  Future<void> crateBridgeNotifyNetworkAvailable();
+
+  Future<void> crateBridgeRekeyStore(
+      {required String dataDir,
+      String? oldPassphrase,
+      required String newPassphrase});
+
+  void crateBridgeReleaseCancelToken({required CancelToken token});
+
+  Future<void> crateBridgeRemoveSpaceChild(
+      {required ClientHandle client,
+      required String spaceId,
+      required String childId,
+      CallOptions? options});
+
+  Future<LoginResult> crateBridgeRestoreSession(
+      {required ClientHandle client,
+      required LoginResult session,
+      CallOptions? options});
+
+  Future<String> crateBridgeSendAttachment(
+      {required ClientHandle client,
+      required String roomId,
+      String? filePath,
+      Uint8List? data,
+      String? filename,
+      required String mimeType,
+      CallOptions? options});
 
   Future<String> crateBridgeSendMessage(
-      {required String roomId, required String body});
+      {required ClientHandle client,
+      required String roomId,
+      required String body,
+      CallOptions? options});
+
+  void crateBridgeSetDefaultTimeout({BigInt? timeoutMs});
+
+  Future<void> crateBridgeSetPropertyMetadata(
+      {required ClientHandle client,
+      required String roomId,
+      required PropertyMetadata metadata,
+      CallOptions? options});
+
+  Future<void> crateBridgeSetSyncFilter(
+      {required ClientHandle client, SyncFilterConfig? filter});
+
+  Future<void> crateBridgeSetVisibleRooms(
+      {required ClientHandle client,
+      required int start,
+      required int count,
+      CallOptions? options});
+
+  Future<void> crateBridgeStartSync({required ClientHandle client});
 
-  Future<void> crateBridgeStartSync();
+  Future<void> crateBridgeStopSync(
+      {required ClientHandle client, CallOptions? options});
 
-  Future<void> crateBridgeStopSync();
+  Stream<AccountTimelineItem> crateBridgeSubscribeAllEvents();
 
-  Stream<MatrixEvent> crateBridgeSubscribeEvents();
+  Stream<TimelineItem> crateBridgeSubscribeEvents(
+      {required ClientHandle client});
+
+  Stream<InviteInfo> crateBridgeSubscribeInvites(
+      {required ClientHandle client});
+
+  Stream<RoomListUpdate> crateBridgeSubscribeRoomList(
+      {required ClientHandle client});
+
+  Stream<StoreRecoveryEvent> crateBridgeSubscribeStoreRecovery();
+
+  Stream<SyncStatus> crateBridgeSubscribeSyncStatus(
+      {required ClientHandle client});
+
+  Future<void> crateBridgeUnbanUser(
+      {required ClientHandle client,
+      required String roomId,
+      required String userId,
+      String? reason,
+      CallOptions? options});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -113,494 +338,4227 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<String> crateBridgeCreateRoom(
-      {required String otherMxid, String? creatorMxid}) {
+  Future<void> crateBridgeAcceptInvite(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(otherMxid, serializer);
-        sse_encode_opt_String(creatorMxid, serializer);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 1, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateBridgeCreateRoomConstMeta,
-      argValues: [otherMxid, creatorMxid],
+      constMeta: kCrateBridgeAcceptInviteConstMeta,
+      argValues: [client, roomId, options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBridgeCreateRoomConstMeta => const TaskConstMeta(
-        debugName: 'create_room',
-        argNames: ['otherMxid', 'creatorMxid'],
+  TaskConstMeta get kCrateBridgeAcceptInviteConstMeta => const TaskConstMeta(
+        debugName: 'accept_invite',
+        argNames: ['client', 'roomId', 'options'],
       );
 
   @override
-  Future<String> crateBridgeGetRoomMessages(
-      {required String roomId, required int limit}) {
+  Future<void> crateBridgeAddSpaceChild(
+      {required ClientHandle client,
+      required String spaceId,
+      required String childId,
+      CallOptions? options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(roomId, serializer);
-        sse_encode_u_32(limit, serializer);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(spaceId, serializer);
+        sse_encode_String(childId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 2, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateBridgeGetRoomMessagesConstMeta,
-      argValues: [roomId, limit],
+      constMeta: kCrateBridgeAddSpaceChildConstMeta,
+      argValues: [client, spaceId, childId, options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBridgeGetRoomMessagesConstMeta => const TaskConstMeta(
-        debugName: 'get_room_messages',
-        argNames: ['roomId', 'limit'],
+  TaskConstMeta get kCrateBridgeAddSpaceChildConstMeta => const TaskConstMeta(
+        debugName: 'add_space_child',
+        argNames: ['client', 'spaceId', 'childId', 'options'],
       );
 
   @override
-  Future<void> crateBridgeInit(
-      {required String homeserver, required String dataDir}) {
+  Future<void> crateBridgeBanUser(
+      {required ClientHandle client,
+      required String roomId,
+      required String userId,
+      String? reason,
+      CallOptions? options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(homeserver, serializer);
-        sse_encode_String(dataDir, serializer);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_String(userId, serializer);
+        sse_encode_opt_String(reason, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 3, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateBridgeInitConstMeta,
-      argValues: [homeserver, dataDir],
+      constMeta: kCrateBridgeBanUserConstMeta,
+      argValues: [client, roomId, userId, reason, options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBridgeInitConstMeta => const TaskConstMeta(
-        debugName: 'init',
-        argNames: ['homeserver', 'dataDir'],
+  TaskConstMeta get kCrateBridgeBanUserConstMeta => const TaskConstMeta(
+        debugName: 'ban_user',
+        argNames: ['client', 'roomId', 'userId', 'reason', 'options'],
       );
 
   @override
-  Future<LoginResult> crateBridgeLogin(
-      {required String user, required String password}) {
+  Future<CallOptions> crateBridgeCallOptionsDefault() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(user, serializer);
-        sse_encode_String(password, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 4, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_login_result,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_call_options,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateBridgeLoginConstMeta,
-      argValues: [user, password],
+      constMeta: kCrateBridgeCallOptionsDefaultConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBridgeLoginConstMeta => const TaskConstMeta(
-        debugName: 'login',
-        argNames: ['user', 'password'],
+  TaskConstMeta get kCrateBridgeCallOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: 'call_options_default',
+        argNames: [],
       );
 
   @override
-  Future<void> crateBridgeMarkRead(
-      {required String roomId, required String eventId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateBridgeCancelCalls({required CancelToken token}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(roomId, serializer);
-        sse_encode_String(eventId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
+        sse_encode_box_autoadd_cancel_token(token, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5) !;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateBridgeMarkReadConstMeta,
-      argValues: [roomId, eventId],
+      constMeta: kCrateBridgeCancelCallsConstMeta,
+      argValues: [token],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBridgeMarkReadConstMeta => const TaskConstMeta(
-        debugName: 'mark_read',
-        argNames: ['roomId', 'eventId'],
+  TaskConstMeta get kCrateBridgeCancelCallsConstMeta => const TaskConstMeta(
+        debugName: 'cancel_calls',
+        argNames: ['token'],
       );
 
   @override
-  Future<String> crateBridgeSendMessage(
-      {required String roomId, required String body}) {
+  Future<void> crateBridgeClearMediaCache({required ClientHandle client}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(roomId, serializer);
-        sse_encode_String(body, serializer);
+        sse_encode_box_autoadd_client_handle(client, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 6, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateBridgeSendMessageConstMeta,
-      argValues: [roomId, body],
+      constMeta: kCrateBridgeClearMediaCacheConstMeta,
+      argValues: [client],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBridgeSendMessageConstMeta => const TaskConstMeta(
-        debugName: 'send_message',
-        argNames: ['roomId', 'body'],
+  TaskConstMeta get kCrateBridgeClearMediaCacheConstMeta => const TaskConstMeta(
+        debugName: 'clear_media_cache',
+        argNames: ['client'],
       );
 
   @override
-  Future<void> crateBridgeStartSync() {
+  Future<void> crateBridgeClearStore({required String dataDir}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dataDir, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 7, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateBridgeStartSyncConstMeta,
-      argValues: [],
+      constMeta: kCrateBridgeClearStoreConstMeta,
+      argValues: [dataDir],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBridgeStartSyncConstMeta => const TaskConstMeta(
-        debugName: 'start_sync',
-        argNames: [],
+  TaskConstMeta get kCrateBridgeClearStoreConstMeta => const TaskConstMeta(
+        debugName: 'clear_store',
+        argNames: ['dataDir'],
       );
 
   @override
-  Future<void> crateBridgeStopSync() {
+  Future<void> crateBridgeConfigureMediaCache(
+      {required ClientHandle client, required MediaCacheConfig config}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_box_autoadd_media_cache_config(config, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 8, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateBridgeStopSyncConstMeta,
-      argValues: [],
+      constMeta: kCrateBridgeConfigureMediaCacheConstMeta,
+      argValues: [client, config],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBridgeStopSyncConstMeta => const TaskConstMeta(
-        debugName: 'stop_sync',
-        argNames: [],
+  TaskConstMeta get kCrateBridgeConfigureMediaCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: 'configure_media_cache',
+        argNames: ['client', 'config'],
       );
 
   @override
-  Stream<MatrixEvent> crateBridgeSubscribeEvents() {
-    final sink = RustStreamSink<MatrixEvent>();
-    unawaited(handler.executeNormal(NormalTask(
+  Future<String> crateBridgeCreateGroupRoom(
+      {required ClientHandle client,
+      required GroupRoomConfig config,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_StreamSink_matrix_event_Sse(sink, serializer);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_box_autoadd_group_room_config(config, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 9, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
       ),
-      constMeta: kCrateBridgeSubscribeEventsConstMeta,
-      argValues: [sink],
+      constMeta: kCrateBridgeCreateGroupRoomConstMeta,
+      argValues: [client, config, options],
       apiImpl: this,
-    )));
-    return sink.stream;
+    ));
   }
 
-  TaskConstMeta get kCrateBridgeSubscribeEventsConstMeta => const TaskConstMeta(
-        debugName: 'subscribe_events',
-        argNames: ['sink'],
+  TaskConstMeta get kCrateBridgeCreateGroupRoomConstMeta => const TaskConstMeta(
+        debugName: 'create_group_room',
+        argNames: ['client', 'config', 'options'],
       );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<String> crateBridgeCreateRoom(
+      {required ClientHandle client,
+      required String otherMxid,
+      String? creatorMxid,
+      PropertyMetadata? property,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(otherMxid, serializer);
+        sse_encode_opt_String(creatorMxid, serializer);
+        sse_encode_opt_box_autoadd_property_metadata(property, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 10, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeCreateRoomConstMeta,
+      argValues: [client, otherMxid, creatorMxid, property, options],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  RustStreamSink<MatrixEvent> dco_decode_StreamSink_matrix_event_Sse(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  TaskConstMeta get kCrateBridgeCreateRoomConstMeta => const TaskConstMeta(
+        debugName: 'create_room',
+        argNames: ['client', 'otherMxid', 'creatorMxid', 'property', 'options'],
+      );
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  @override
+  Future<String> crateBridgeCreateSpace(
+      {required ClientHandle client,
+      required String name,
+      String? topic,
+      String? parentSpaceId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(name, serializer);
+        sse_encode_opt_String(topic, serializer);
+        sse_encode_opt_String(parentSpaceId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 11, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeCreateSpaceConstMeta,
+      argValues: [client, name, topic, parentSpaceId, options],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateBridgeCreateSpaceConstMeta => const TaskConstMeta(
+        debugName: 'create_space',
+        argNames: ['client', 'name', 'topic', 'parentSpaceId', 'options'],
+      );
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  @override
+  Future<void> crateBridgeDeclineInvite(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 12, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeDeclineInviteConstMeta,
+      argValues: [client, roomId, options],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
+  TaskConstMeta get kCrateBridgeDeclineInviteConstMeta => const TaskConstMeta(
+        debugName: 'decline_invite',
+        argNames: ['client', 'roomId', 'options'],
+      );
 
-  @protected
-  LoginResult dco_decode_login_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return LoginResult(
-      userId: dco_decode_String(arr[0]),
-      accessToken: dco_decode_String(arr[1]),
-    );
+  @override
+  Future<void> crateBridgeDisposeClient({required ClientHandle client}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 13, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeDisposeClientConstMeta,
+      argValues: [client],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  MatrixEvent dco_decode_matrix_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return MatrixEvent(
-      roomId: dco_decode_String(arr[0]),
-      eventId: dco_decode_String(arr[1]),
-      sender: dco_decode_String(arr[2]),
-      ts: dco_decode_i_64(arr[3]),
-      content: dco_decode_opt_String(arr[4]),
-      isEncrypted: dco_decode_bool(arr[5]),
-    );
-  }
+  TaskConstMeta get kCrateBridgeDisposeClientConstMeta => const TaskConstMeta(
+        debugName: 'dispose_client',
+        argNames: ['client'],
+      );
 
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  @override
+  Future<String> crateBridgeFetchMedia(
+      {required ClientHandle client,
+      required String source,
+      String? filename,
+      BigInt? size,
+      ThumbnailRequest? thumbnail,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(source, serializer);
+        sse_encode_opt_String(filename, serializer);
+        sse_encode_opt_box_autoadd_u_64(size, serializer);
+        sse_encode_opt_box_autoadd_thumbnail_request(thumbnail, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 14, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeFetchMediaConstMeta,
+      argValues: [client, source, filename, size, thumbnail, options],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
+  TaskConstMeta get kCrateBridgeFetchMediaConstMeta => const TaskConstMeta(
+        debugName: 'fetch_media',
+        argNames: [
+          'client',
+          'source',
+          'filename',
+          'size',
+          'thumbnail',
+          'options'
+        ],
+      );
 
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  @override
+  Future<void> crateBridgeForgetRoom(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 15, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeForgetRoomConstMeta,
+      argValues: [client, roomId, options],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  void dco_decode_unit(dynamic raw) {
+  TaskConstMeta get kCrateBridgeForgetRoomConstMeta => const TaskConstMeta(
+        debugName: 'forget_room',
+        argNames: ['client', 'roomId', 'options'],
+      );
+
+  @override
+  Future<DirectRoom> crateBridgeGetOrCreateDm(
+      {required ClientHandle client,
+      required String userId,
+      List<String>? alsoInvite,
+      PropertyMetadata? property,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(userId, serializer);
+        sse_encode_opt_list_String(alsoInvite, serializer);
+        sse_encode_opt_box_autoadd_property_metadata(property, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 16, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_direct_room,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeGetOrCreateDmConstMeta,
+      argValues: [client, userId, alsoInvite, property, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeGetOrCreateDmConstMeta => const TaskConstMeta(
+        debugName: 'get_or_create_dm',
+        argNames: ['client', 'userId', 'alsoInvite', 'property', 'options'],
+      );
+
+  @override
+  Future<PropertyMetadata?> crateBridgeGetPropertyMetadata(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 17, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_property_metadata,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeGetPropertyMetadataConstMeta,
+      argValues: [client, roomId, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeGetPropertyMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_property_metadata',
+        argNames: ['client', 'roomId', 'options'],
+      );
+
+  @override
+  Future<List<RoomMemberInfo>> crateBridgeGetRoomMembers(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 18, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_room_member_info,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeGetRoomMembersConstMeta,
+      argValues: [client, roomId, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeGetRoomMembersConstMeta => const TaskConstMeta(
+        debugName: 'get_room_members',
+        argNames: ['client', 'roomId', 'options'],
+      );
+
+  @override
+  Future<List<TimelineItem>> crateBridgeGetRoomMessages(
+      {required ClientHandle client,
+      required String roomId,
+      required int limit,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_u_32(limit, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 19, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_timeline_item,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeGetRoomMessagesConstMeta,
+      argValues: [client, roomId, limit, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeGetRoomMessagesConstMeta => const TaskConstMeta(
+        debugName: 'get_room_messages',
+        argNames: ['client', 'roomId', 'limit', 'options'],
+      );
+
+  @override
+  Future<TimelinePage> crateBridgeGetRoomMessagesPage(
+      {required ClientHandle client,
+      required String roomId,
+      String? from,
+      required TimelineDirection direction,
+      required int limit,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_opt_String(from, serializer);
+        sse_encode_timeline_direction(direction, serializer);
+        sse_encode_u_32(limit, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 20, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_timeline_page,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeGetRoomMessagesPageConstMeta,
+      argValues: [client, roomId, from, direction, limit, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeGetRoomMessagesPageConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_room_messages_page',
+        argNames: ['client', 'roomId', 'from', 'direction', 'limit', 'options'],
+      );
+
+  @override
+  Future<List<SpaceHierarchyEntry>> crateBridgeGetSpaceHierarchy(
+      {required ClientHandle client,
+      required String spaceId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(spaceId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 21, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_space_hierarchy_entry,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeGetSpaceHierarchyConstMeta,
+      argValues: [client, spaceId, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeGetSpaceHierarchyConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_space_hierarchy',
+        argNames: ['client', 'spaceId', 'options'],
+      );
+
+  @override
+  Future<SyncStatus> crateBridgeGetSyncStatus({required ClientHandle client}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 22, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_sync_status,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeGetSyncStatusConstMeta,
+      argValues: [client],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeGetSyncStatusConstMeta => const TaskConstMeta(
+        debugName: 'get_sync_status',
+        argNames: ['client'],
+      );
+
+  @override
+  Future<ClientHandle> crateBridgeInit(
+      {required String homeserver,
+      required String dataDir,
+      String? passphrase,
+      SlidingSyncConfig? slidingSync,
+      InvitePolicy? invitePolicy,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(homeserver, serializer);
+        sse_encode_String(dataDir, serializer);
+        sse_encode_opt_String(passphrase, serializer);
+        sse_encode_opt_box_autoadd_sliding_sync_config(slidingSync, serializer);
+        sse_encode_opt_box_autoadd_invite_policy(invitePolicy, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 23, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_client_handle,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeInitConstMeta,
+      argValues: [
+        homeserver,
+        dataDir,
+        passphrase,
+        slidingSync,
+        invitePolicy,
+        options
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeInitConstMeta => const TaskConstMeta(
+        debugName: 'init',
+        argNames: [
+          'homeserver',
+          'dataDir',
+          'passphrase',
+          'slidingSync',
+          'invitePolicy',
+          'options'
+        ],
+      );
+
+  @override
+  Future<ClientHandle> crateBridgeInitAccount(
+      {required String homeserver,
+      required String baseDir,
+      required String account,
+      String? passphrase,
+      SlidingSyncConfig? slidingSync,
+      InvitePolicy? invitePolicy,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(homeserver, serializer);
+        sse_encode_String(baseDir, serializer);
+        sse_encode_String(account, serializer);
+        sse_encode_opt_String(passphrase, serializer);
+        sse_encode_opt_box_autoadd_sliding_sync_config(slidingSync, serializer);
+        sse_encode_opt_box_autoadd_invite_policy(invitePolicy, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 24, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_client_handle,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeInitAccountConstMeta,
+      argValues: [
+        homeserver,
+        baseDir,
+        account,
+        passphrase,
+        slidingSync,
+        invitePolicy,
+        options
+      ],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeInitAccountConstMeta => const TaskConstMeta(
+        debugName: 'init_account',
+        argNames: [
+          'homeserver',
+          'baseDir',
+          'account',
+          'passphrase',
+          'slidingSync',
+          'invitePolicy',
+          'options'
+        ],
+      );
+
+  @override
+  Future<void> crateBridgeInviteUser(
+      {required ClientHandle client,
+      required String roomId,
+      required String userId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_String(userId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 25, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeInviteUserConstMeta,
+      argValues: [client, roomId, userId, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeInviteUserConstMeta => const TaskConstMeta(
+        debugName: 'invite_user',
+        argNames: ['client', 'roomId', 'userId', 'options'],
+      );
+
+  @override
+  Future<void> crateBridgeKickUser(
+      {required ClientHandle client,
+      required String roomId,
+      required String userId,
+      String? reason,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_String(userId, serializer);
+        sse_encode_opt_String(reason, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 26, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeKickUserConstMeta,
+      argValues: [client, roomId, userId, reason, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeKickUserConstMeta => const TaskConstMeta(
+        debugName: 'kick_user',
+        argNames: ['client', 'roomId', 'userId', 'reason', 'options'],
+      );
+
+  @override
+  Future<void> crateBridgeLeaveRoom(
+      {required ClientHandle client,
+      required String roomId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 27, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeLeaveRoomConstMeta,
+      argValues: [client, roomId, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeLeaveRoomConstMeta => const TaskConstMeta(
+        debugName: 'leave_room',
+        argNames: ['client', 'roomId', 'options'],
+      );
+
+  @override
+  Future<List<ClientInfo>> crateBridgeListClients() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 28, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_client_info,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeListClientsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeListClientsConstMeta => const TaskConstMeta(
+        debugName: 'list_clients',
+        argNames: [],
+      );
+
+  @override
+  Future<List<InviteInfo>> crateBridgeListInvites(
+      {required ClientHandle client, CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 29, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_invite_info,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeListInvitesConstMeta,
+      argValues: [client, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeListInvitesConstMeta => const TaskConstMeta(
+        debugName: 'list_invites',
+        argNames: ['client', 'options'],
+      );
+
+  @override
+  Future<List<RoomSummary>> crateBridgeListRooms(
+      {required ClientHandle client, String? spaceId, CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_opt_String(spaceId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 30, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_room_summary,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeListRoomsConstMeta,
+      argValues: [client, spaceId, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeListRoomsConstMeta => const TaskConstMeta(
+        debugName: 'list_rooms',
+        argNames: ['client', 'spaceId', 'options'],
+      );
+
+  @override
+  Future<LoginResult> crateBridgeLogin(
+      {required ClientHandle client,
+      required String user,
+      required String password,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(user, serializer);
+        sse_encode_String(password, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 31, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_login_result,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeLoginConstMeta,
+      argValues: [client, user, password, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeLoginConstMeta => const TaskConstMeta(
+        debugName: 'login',
+        argNames: ['client', 'user', 'password', 'options'],
+      );
+
+  @override
+  Future<void> crateBridgeLogout(
+      {required ClientHandle client,
+      required bool deleteDevice,
+      String? password,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_bool(deleteDevice, serializer);
+        sse_encode_opt_String(password, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 32, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeLogoutConstMeta,
+      argValues: [client, deleteDevice, password, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeLogoutConstMeta => const TaskConstMeta(
+        debugName: 'logout',
+        argNames: ['client', 'deleteDevice', 'password', 'options'],
+      );
+
+  @override
+  Future<void> crateBridgeMarkRead(
+      {required ClientHandle client,
+      required String roomId,
+      required String eventId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_String(eventId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 33, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeMarkReadConstMeta,
+      argValues: [client, roomId, eventId, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeMarkReadConstMeta => const TaskConstMeta(
+        debugName: 'mark_read',
+        argNames: ['client', 'roomId', 'eventId', 'options'],
+      );
+
+  @override
+  CancelToken crateBridgeNewCancelToken() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34) !;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_cancel_token,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateBridgeNewCancelTokenConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeNewCancelTokenConstMeta => const TaskConstMeta(
+        debugName: 'new_cancel_token',
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateBridgeNotifyNetworkAvailable() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeNotifyNetworkAvailableConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeNotifyNetworkAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: 'notify_network_available',
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateBridgeRekeyStore(
+      {required String dataDir,
+      String? oldPassphrase,
+      required String newPassphrase}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dataDir, serializer);
+        sse_encode_opt_String(oldPassphrase, serializer);
+        sse_encode_String(newPassphrase, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeRekeyStoreConstMeta,
+      argValues: [dataDir, oldPassphrase, newPassphrase],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeRekeyStoreConstMeta => const TaskConstMeta(
+        debugName: 'rekey_store',
+        argNames: ['dataDir', 'oldPassphrase', 'newPassphrase'],
+      );
+
+  @override
+  void crateBridgeReleaseCancelToken({required CancelToken token}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_cancel_token(token, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37) !;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateBridgeReleaseCancelTokenConstMeta,
+      argValues: [token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeReleaseCancelTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: 'release_cancel_token',
+        argNames: ['token'],
+      );
+
+  @override
+  Future<void> crateBridgeRemoveSpaceChild(
+      {required ClientHandle client,
+      required String spaceId,
+      required String childId,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(spaceId, serializer);
+        sse_encode_String(childId, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeRemoveSpaceChildConstMeta,
+      argValues: [client, spaceId, childId, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeRemoveSpaceChildConstMeta =>
+      const TaskConstMeta(
+        debugName: 'remove_space_child',
+        argNames: ['client', 'spaceId', 'childId', 'options'],
+      );
+
+  @override
+  Future<LoginResult> crateBridgeRestoreSession(
+      {required ClientHandle client,
+      required LoginResult session,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_box_autoadd_login_result(session, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_login_result,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeRestoreSessionConstMeta,
+      argValues: [client, session, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeRestoreSessionConstMeta => const TaskConstMeta(
+        debugName: 'restore_session',
+        argNames: ['client', 'session', 'options'],
+      );
+
+  @override
+  Future<String> crateBridgeSendAttachment(
+      {required ClientHandle client,
+      required String roomId,
+      String? filePath,
+      Uint8List? data,
+      String? filename,
+      required String mimeType,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_opt_String(filePath, serializer);
+        sse_encode_opt_list_prim_u_8_strict(data, serializer);
+        sse_encode_opt_String(filename, serializer);
+        sse_encode_String(mimeType, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSendAttachmentConstMeta,
+      argValues: [client, roomId, filePath, data, filename, mimeType, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeSendAttachmentConstMeta => const TaskConstMeta(
+        debugName: 'send_attachment',
+        argNames: [
+          'client',
+          'roomId',
+          'filePath',
+          'data',
+          'filename',
+          'mimeType',
+          'options'
+        ],
+      );
+
+  @override
+  Future<String> crateBridgeSendMessage(
+      {required ClientHandle client,
+      required String roomId,
+      required String body,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_String(body, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 41, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSendMessageConstMeta,
+      argValues: [client, roomId, body, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeSendMessageConstMeta => const TaskConstMeta(
+        debugName: 'send_message',
+        argNames: ['client', 'roomId', 'body', 'options'],
+      );
+
+  @override
+  void crateBridgeSetDefaultTimeout({BigInt? timeoutMs}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42) !;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateBridgeSetDefaultTimeoutConstMeta,
+      argValues: [timeoutMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeSetDefaultTimeoutConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_default_timeout',
+        argNames: ['timeoutMs'],
+      );
+
+  @override
+  Future<void> crateBridgeSetPropertyMetadata(
+      {required ClientHandle client,
+      required String roomId,
+      required PropertyMetadata metadata,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_box_autoadd_property_metadata(metadata, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 43, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSetPropertyMetadataConstMeta,
+      argValues: [client, roomId, metadata, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeSetPropertyMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_property_metadata',
+        argNames: ['client', 'roomId', 'metadata', 'options'],
+      );
+
+  @override
+  Future<void> crateBridgeSetSyncFilter(
+      {required ClientHandle client, SyncFilterConfig? filter}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_opt_box_autoadd_sync_filter_config(filter, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 44, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSetSyncFilterConstMeta,
+      argValues: [client, filter],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeSetSyncFilterConstMeta => const TaskConstMeta(
+        debugName: 'set_sync_filter',
+        argNames: ['client', 'filter'],
+      );
+
+  @override
+  Future<void> crateBridgeSetVisibleRooms(
+      {required ClientHandle client,
+      required int start,
+      required int count,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_u_32(start, serializer);
+        sse_encode_u_32(count, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 45, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSetVisibleRoomsConstMeta,
+      argValues: [client, start, count, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeSetVisibleRoomsConstMeta => const TaskConstMeta(
+        debugName: 'set_visible_rooms',
+        argNames: ['client', 'start', 'count', 'options'],
+      );
+
+  @override
+  Future<void> crateBridgeStartSync({required ClientHandle client}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 46, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeStartSyncConstMeta,
+      argValues: [client],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeStartSyncConstMeta => const TaskConstMeta(
+        debugName: 'start_sync',
+        argNames: ['client'],
+      );
+
+  @override
+  Future<void> crateBridgeStopSync(
+      {required ClientHandle client, CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 47, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeStopSyncConstMeta,
+      argValues: [client, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeStopSyncConstMeta => const TaskConstMeta(
+        debugName: 'stop_sync',
+        argNames: ['client', 'options'],
+      );
+
+  @override
+  Stream<AccountTimelineItem> crateBridgeSubscribeAllEvents() {
+    final sink = RustStreamSink<AccountTimelineItem>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_account_timeline_item_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSubscribeAllEventsConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateBridgeSubscribeAllEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'subscribe_all_events',
+        argNames: ['sink'],
+      );
+
+  @override
+  Stream<TimelineItem> crateBridgeSubscribeEvents(
+      {required ClientHandle client}) {
+    final sink = RustStreamSink<TimelineItem>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_StreamSink_timeline_item_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 49, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSubscribeEventsConstMeta,
+      argValues: [client, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateBridgeSubscribeEventsConstMeta => const TaskConstMeta(
+        debugName: 'subscribe_events',
+        argNames: ['client', 'sink'],
+      );
+
+  @override
+  Stream<InviteInfo> crateBridgeSubscribeInvites(
+      {required ClientHandle client}) {
+    final sink = RustStreamSink<InviteInfo>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_StreamSink_invite_info_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 50, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSubscribeInvitesConstMeta,
+      argValues: [client, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateBridgeSubscribeInvitesConstMeta =>
+      const TaskConstMeta(
+        debugName: 'subscribe_invites',
+        argNames: ['client', 'sink'],
+      );
+
+  @override
+  Stream<RoomListUpdate> crateBridgeSubscribeRoomList(
+      {required ClientHandle client}) {
+    final sink = RustStreamSink<RoomListUpdate>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_StreamSink_room_list_update_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 51, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSubscribeRoomListConstMeta,
+      argValues: [client, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateBridgeSubscribeRoomListConstMeta =>
+      const TaskConstMeta(
+        debugName: 'subscribe_room_list',
+        argNames: ['client', 'sink'],
+      );
+
+  @override
+  Stream<StoreRecoveryEvent> crateBridgeSubscribeStoreRecovery() {
+    final sink = RustStreamSink<StoreRecoveryEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_store_recovery_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 52, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSubscribeStoreRecoveryConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateBridgeSubscribeStoreRecoveryConstMeta =>
+      const TaskConstMeta(
+        debugName: 'subscribe_store_recovery',
+        argNames: ['sink'],
+      );
+
+  @override
+  Stream<SyncStatus> crateBridgeSubscribeSyncStatus(
+      {required ClientHandle client}) {
+    final sink = RustStreamSink<SyncStatus>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_StreamSink_sync_status_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 53, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeSubscribeSyncStatusConstMeta,
+      argValues: [client, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateBridgeSubscribeSyncStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: 'subscribe_sync_status',
+        argNames: ['client', 'sink'],
+      );
+
+  @override
+  Future<void> crateBridgeUnbanUser(
+      {required ClientHandle client,
+      required String roomId,
+      required String userId,
+      String? reason,
+      CallOptions? options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_client_handle(client, serializer);
+        sse_encode_String(roomId, serializer);
+        sse_encode_String(userId, serializer);
+        sse_encode_opt_String(reason, serializer);
+        sse_encode_opt_box_autoadd_call_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 54, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_bridge_error,
+      ),
+      constMeta: kCrateBridgeUnbanUserConstMeta,
+      argValues: [client, roomId, userId, reason, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBridgeUnbanUserConstMeta => const TaskConstMeta(
+        debugName: 'unban_user',
+        argNames: ['client', 'roomId', 'userId', 'reason', 'options'],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<AccountTimelineItem> dco_decode_StreamSink_account_timeline_item_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<InviteInfo> dco_decode_StreamSink_invite_info_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<RoomListUpdate> dco_decode_StreamSink_room_list_update_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<StoreRecoveryEvent> dco_decode_StreamSink_store_recovery_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<SyncStatus> dco_decode_StreamSink_sync_status_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<TimelineItem> dco_decode_StreamSink_timeline_item_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AccountTimelineItem dco_decode_account_timeline_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AccountTimelineItem(
+      client: dco_decode_client_handle(arr[0]),
+      userId: dco_decode_String(arr[1]),
+      item: dco_decode_timeline_item(arr[2]),
+    );
+  }
+
+  @protected
+  Attachment dco_decode_attachment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return Attachment(
+      url: dco_decode_String(arr[0]),
+      source: dco_decode_String(arr[1]),
+      isEncrypted: dco_decode_bool(arr[2]),
+      filename: dco_decode_String(arr[3]),
+      mimetype: dco_decode_opt_String(arr[4]),
+      size: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      width: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      durationMs: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      thumbnailSource: dco_decode_opt_String(arr[9]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  Attachment dco_decode_box_autoadd_attachment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_attachment(raw);
+  }
+
+  @protected
+  BridgeError dco_decode_box_autoadd_bridge_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bridge_error(raw);
+  }
+
+  @protected
+  CallOptions dco_decode_box_autoadd_call_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_call_options(raw);
+  }
+
+  @protected
+  CancelToken dco_decode_box_autoadd_cancel_token(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cancel_token(raw);
+  }
+
+  @protected
+  ClientHandle dco_decode_box_autoadd_client_handle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_client_handle(raw);
+  }
+
+  @protected
+  GroupRoomConfig dco_decode_box_autoadd_group_room_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_group_room_config(raw);
+  }
+
+  @protected
+  InvitePolicy dco_decode_box_autoadd_invite_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_invite_policy(raw);
+  }
+
+  @protected
+  LoginResult dco_decode_box_autoadd_login_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_login_result(raw);
+  }
+
+  @protected
+  MediaCacheConfig dco_decode_box_autoadd_media_cache_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_media_cache_config(raw);
+  }
+
+  @protected
+  PropertyMetadata dco_decode_box_autoadd_property_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_property_metadata(raw);
+  }
+
+  @protected
+  RoomSummary dco_decode_box_autoadd_room_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_room_summary(raw);
+  }
+
+  @protected
+  SlidingSyncConfig dco_decode_box_autoadd_sliding_sync_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sliding_sync_config(raw);
+  }
+
+  @protected
+  SyncFilterConfig dco_decode_box_autoadd_sync_filter_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_filter_config(raw);
+  }
+
+  @protected
+  ThumbnailRequest dco_decode_box_autoadd_thumbnail_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_thumbnail_request(raw);
+  }
+
+  @protected
+  TimelineItem dco_decode_box_autoadd_timeline_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_timeline_item(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  BridgeError dco_decode_bridge_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BridgeError(
+      kind: dco_decode_bridge_error_kind(arr[0]),
+      message: dco_decode_String(arr[1]),
+      retryAfterMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  BridgeErrorKind dco_decode_bridge_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BridgeErrorKind.values[raw as int];
+  }
+
+  @protected
+  CallOptions dco_decode_call_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CallOptions(
+      timeoutMs: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      cancel: dco_decode_opt_box_autoadd_cancel_token(arr[1]),
+    );
+  }
+
+  @protected
+  CancelToken dco_decode_cancel_token(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return CancelToken(
+      id: dco_decode_u_64(arr[0]),
+    );
+  }
+
+  @protected
+  ClientHandle dco_decode_client_handle(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ClientHandle(
+      id: dco_decode_u_32(arr[0]),
+    );
+  }
+
+  @protected
+  ClientInfo dco_decode_client_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ClientInfo(
+      handle: dco_decode_client_handle(arr[0]),
+      userId: dco_decode_opt_String(arr[1]),
+      deviceId: dco_decode_opt_String(arr[2]),
+      homeserver: dco_decode_String(arr[3]),
+      dataDir: dco_decode_String(arr[4]),
+      isSyncing: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  DirectRoom dco_decode_direct_room(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DirectRoom(
+      roomId: dco_decode_String(arr[0]),
+      created: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  GroupRoomConfig dco_decode_group_room_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return GroupRoomConfig(
+      name: dco_decode_String(arr[0]),
+      topic: dco_decode_opt_String(arr[1]),
+      avatar: dco_decode_opt_list_prim_u_8_strict(arr[2]),
+      avatarMimeType: dco_decode_opt_String(arr[3]),
+      participants: dco_decode_list_participant(arr[4]),
+      property: dco_decode_opt_box_autoadd_property_metadata(arr[5]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  InviteInfo dco_decode_invite_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return InviteInfo(
+      roomId: dco_decode_String(arr[0]),
+      displayName: dco_decode_String(arr[1]),
+      avatarUrl: dco_decode_opt_String(arr[2]),
+      topic: dco_decode_opt_String(arr[3]),
+      isDirect: dco_decode_bool(arr[4]),
+      property: dco_decode_opt_box_autoadd_property_metadata(arr[5]),
+      inviter: dco_decode_opt_String(arr[6]),
+      inviterDisplayName: dco_decode_opt_String(arr[7]),
+      inviterAvatarUrl: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  InvitePolicy dco_decode_invite_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return InvitePolicy(
+      kind: dco_decode_invite_policy_kind(arr[0]),
+      allowedUsers: dco_decode_list_String(arr[1]),
+      allowedServers: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  InvitePolicyKind dco_decode_invite_policy_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return InvitePolicyKind.values[raw as int];
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<ClientInfo> dco_decode_list_client_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_client_info).toList();
+  }
+
+  @protected
+  List<InviteInfo> dco_decode_list_invite_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_invite_info).toList();
+  }
+
+  @protected
+  List<Participant> dco_decode_list_participant(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_participant).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RoomMemberInfo> dco_decode_list_room_member_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_room_member_info).toList();
+  }
+
+  @protected
+  List<RoomSummary> dco_decode_list_room_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_room_summary).toList();
+  }
+
+  @protected
+  List<SpaceHierarchyEntry> dco_decode_list_space_hierarchy_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_space_hierarchy_entry).toList(
+        );
+  }
+
+  @protected
+  List<TimelineItem> dco_decode_list_timeline_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_timeline_item).toList();
+  }
+
+  @protected
+  LoginResult dco_decode_login_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LoginResult(
+      userId: dco_decode_String(arr[0]),
+      deviceId: dco_decode_String(arr[1]),
+      accessToken: dco_decode_String(arr[2]),
+      refreshToken: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  MediaCacheConfig dco_decode_media_cache_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MediaCacheConfig(
+      maxTotalBytes: dco_decode_u_64(arr[0]),
+      maxFileBytes: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  MemberState dco_decode_member_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MemberState.values[raw as int];
+  }
+
+  @protected
+  MessageKind dco_decode_message_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MessageKind.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  Attachment? dco_decode_opt_box_autoadd_attachment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_attachment(raw);
+  }
+
+  @protected
+  BridgeError? dco_decode_opt_box_autoadd_bridge_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bridge_error(raw);
+  }
+
+  @protected
+  CallOptions? dco_decode_opt_box_autoadd_call_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_call_options(raw);
+  }
+
+  @protected
+  CancelToken? dco_decode_opt_box_autoadd_cancel_token(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cancel_token(raw);
+  }
+
+  @protected
+  InvitePolicy? dco_decode_opt_box_autoadd_invite_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_invite_policy(raw);
+  }
+
+  @protected
+  PropertyMetadata? dco_decode_opt_box_autoadd_property_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_property_metadata(raw);
+  }
+
+  @protected
+  RoomSummary? dco_decode_opt_box_autoadd_room_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_room_summary(raw);
+  }
+
+  @protected
+  SlidingSyncConfig? dco_decode_opt_box_autoadd_sliding_sync_config(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sliding_sync_config(raw);
+  }
+
+  @protected
+  SyncFilterConfig? dco_decode_opt_box_autoadd_sync_filter_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sync_filter_config(raw);
+  }
+
+  @protected
+  ThumbnailRequest? dco_decode_opt_box_autoadd_thumbnail_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_thumbnail_request(raw);
+  }
+
+  @protected
+  TimelineItem? dco_decode_opt_box_autoadd_timeline_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_timeline_item(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  Participant dco_decode_participant(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Participant(
+      userId: dco_decode_String(arr[0]),
+      role: dco_decode_participant_role(arr[1]),
+    );
+  }
+
+  @protected
+  ParticipantRole dco_decode_participant_role(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ParticipantRole.values[raw as int];
+  }
+
+  @protected
+  PropertyMetadata dco_decode_property_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PropertyMetadata(
+      propertyId: dco_decode_String(arr[0]),
+      address: dco_decode_opt_String(arr[1]),
+      tenancyId: dco_decode_opt_String(arr[2]),
+      participants: dco_decode_list_participant(arr[3]),
+    );
+  }
+
+  @protected
+  RoomListChange dco_decode_room_list_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RoomListChange.values[raw as int];
+  }
+
+  @protected
+  RoomListUpdate dco_decode_room_list_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RoomListUpdate(
+      roomId: dco_decode_String(arr[0]),
+      change: dco_decode_room_list_change(arr[1]),
+      room: dco_decode_opt_box_autoadd_room_summary(arr[2]),
+    );
+  }
+
+  @protected
+  RoomMemberInfo dco_decode_room_member_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RoomMemberInfo(
+      userId: dco_decode_String(arr[0]),
+      displayName: dco_decode_opt_String(arr[1]),
+      avatarUrl: dco_decode_opt_String(arr[2]),
+      membership: dco_decode_member_state(arr[3]),
+      powerLevel: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  RoomMembership dco_decode_room_membership(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RoomMembership.values[raw as int];
+  }
+
+  @protected
+  RoomSummary dco_decode_room_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return RoomSummary(
+      roomId: dco_decode_String(arr[0]),
+      displayName: dco_decode_String(arr[1]),
+      avatarUrl: dco_decode_opt_String(arr[2]),
+      membership: dco_decode_room_membership(arr[3]),
+      isEncrypted: dco_decode_bool(arr[4]),
+      isDirect: dco_decode_bool(arr[5]),
+      members: dco_decode_list_String(arr[6]),
+      joinedMembers: dco_decode_u_64(arr[7]),
+      invitedMembers: dco_decode_u_64(arr[8]),
+      latestMessage: dco_decode_opt_box_autoadd_timeline_item(arr[9]),
+      property: dco_decode_opt_box_autoadd_property_metadata(arr[10]),
+      unreadCount: dco_decode_u_64(arr[11]),
+      highlightCount: dco_decode_u_64(arr[12]),
+    );
+  }
+
+  @protected
+  SendState dco_decode_send_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SendState.values[raw as int];
+  }
+
+  @protected
+  SlidingSyncConfig dco_decode_sliding_sync_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SlidingSyncConfig(
+      proxyUrl: dco_decode_opt_String(arr[0]),
+      windowSize: dco_decode_u_32(arr[1]),
+      timelineLimit: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  SpaceHierarchyEntry dco_decode_space_hierarchy_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return SpaceHierarchyEntry(
+      roomId: dco_decode_String(arr[0]),
+      parentId: dco_decode_opt_String(arr[1]),
+      name: dco_decode_opt_String(arr[2]),
+      topic: dco_decode_opt_String(arr[3]),
+      avatarUrl: dco_decode_opt_String(arr[4]),
+      isSpace: dco_decode_bool(arr[5]),
+      joinedMembers: dco_decode_u_64(arr[6]),
+      joined: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  StoreRecoveryEvent dco_decode_store_recovery_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return StoreRecoveryEvent(
+      client: dco_decode_client_handle(arr[0]),
+      stage: dco_decode_store_recovery_stage(arr[1]),
+      reason: dco_decode_String(arr[2]),
+      quarantinedDir: dco_decode_String(arr[3]),
+      keyBackupFile: dco_decode_opt_String(arr[4]),
+      exportedKeys: dco_decode_u_64(arr[5]),
+      importedKeys: dco_decode_u_64(arr[6]),
+      error: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  StoreRecoveryStage dco_decode_store_recovery_stage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StoreRecoveryStage.values[raw as int];
+  }
+
+  @protected
+  SyncFilterConfig dco_decode_sync_filter_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SyncFilterConfig(
+      lazyLoadMembers: dco_decode_bool(arr[0]),
+      timelineLimit: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      includePresence: dco_decode_bool(arr[2]),
+      includeAccountData: dco_decode_bool(arr[3]),
+      includeEphemeral: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  SyncState dco_decode_sync_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncState.values[raw as int];
+  }
+
+  @protected
+  SyncStatus dco_decode_sync_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SyncStatus(
+      state: dco_decode_sync_state(arr[0]),
+      attempt: dco_decode_u_32(arr[1]),
+      nextRetryMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      error: dco_decode_opt_box_autoadd_bridge_error(arr[3]),
+    );
+  }
+
+  @protected
+  ThumbnailRequest dco_decode_thumbnail_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ThumbnailRequest(
+      width: dco_decode_u_32(arr[0]),
+      height: dco_decode_u_32(arr[1]),
+      crop: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  TimelineDirection dco_decode_timeline_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TimelineDirection.values[raw as int];
+  }
+
+  @protected
+  TimelineItem dco_decode_timeline_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+    return TimelineItem(
+      roomId: dco_decode_String(arr[0]),
+      eventId: dco_decode_String(arr[1]),
+      sender: dco_decode_String(arr[2]),
+      timestampMs: dco_decode_i_64(arr[3]),
+      kind: dco_decode_message_kind(arr[4]),
+      body: dco_decode_String(arr[5]),
+      formattedBody: dco_decode_opt_String(arr[6]),
+      isEncrypted: dco_decode_bool(arr[7]),
+      decryptionError: dco_decode_opt_String(arr[8]),
+      replacesEventId: dco_decode_opt_String(arr[9]),
+      inReplyTo: dco_decode_opt_String(arr[10]),
+      attachment: dco_decode_opt_box_autoadd_attachment(arr[11]),
+      sendState: dco_decode_send_state(arr[12]),
+    );
+  }
+
+  @protected
+  TimelinePage dco_decode_timeline_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TimelinePage(
+      messages: dco_decode_list_timeline_item(arr[0]),
+      nextToken: dco_decode_opt_String(arr[1]),
+      reachedStart: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return;
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<AccountTimelineItem> sse_decode_StreamSink_account_timeline_item_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<InviteInfo> sse_decode_StreamSink_invite_info_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<RoomListUpdate> sse_decode_StreamSink_room_list_update_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<StoreRecoveryEvent> sse_decode_StreamSink_store_recovery_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<SyncStatus> sse_decode_StreamSink_sync_status_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<TimelineItem> sse_decode_StreamSink_timeline_item_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AccountTimelineItem sse_decode_account_timeline_item(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_client = sse_decode_client_handle(deserializer);
+    final var_userId = sse_decode_String(deserializer);
+    final var_item = sse_decode_timeline_item(deserializer);
+    return AccountTimelineItem(
+        client: var_client, userId: var_userId, item: var_item);
+  }
+
+  @protected
+  Attachment sse_decode_attachment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_url = sse_decode_String(deserializer);
+    final var_source = sse_decode_String(deserializer);
+    final var_isEncrypted = sse_decode_bool(deserializer);
+    final var_filename = sse_decode_String(deserializer);
+    final var_mimetype = sse_decode_opt_String(deserializer);
+    final var_size = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_durationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_thumbnailSource = sse_decode_opt_String(deserializer);
+    return Attachment(
+        url: var_url,
+        source: var_source,
+        isEncrypted: var_isEncrypted,
+        filename: var_filename,
+        mimetype: var_mimetype,
+        size: var_size,
+        width: var_width,
+        height: var_height,
+        durationMs: var_durationMs,
+        thumbnailSource: var_thumbnailSource);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  Attachment sse_decode_box_autoadd_attachment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_attachment(deserializer));
+  }
+
+  @protected
+  BridgeError sse_decode_box_autoadd_bridge_error(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bridge_error(deserializer));
+  }
+
+  @protected
+  CallOptions sse_decode_box_autoadd_call_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_call_options(deserializer));
+  }
+
+  @protected
+  CancelToken sse_decode_box_autoadd_cancel_token(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cancel_token(deserializer));
+  }
+
+  @protected
+  ClientHandle sse_decode_box_autoadd_client_handle(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_client_handle(deserializer));
+  }
+
+  @protected
+  GroupRoomConfig sse_decode_box_autoadd_group_room_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_group_room_config(deserializer));
+  }
+
+  @protected
+  InvitePolicy sse_decode_box_autoadd_invite_policy(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_invite_policy(deserializer));
+  }
+
+  @protected
+  LoginResult sse_decode_box_autoadd_login_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_login_result(deserializer));
+  }
+
+  @protected
+  MediaCacheConfig sse_decode_box_autoadd_media_cache_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_media_cache_config(deserializer));
+  }
+
+  @protected
+  PropertyMetadata sse_decode_box_autoadd_property_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_property_metadata(deserializer));
+  }
+
+  @protected
+  RoomSummary sse_decode_box_autoadd_room_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_room_summary(deserializer));
+  }
+
+  @protected
+  SlidingSyncConfig sse_decode_box_autoadd_sliding_sync_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sliding_sync_config(deserializer));
+  }
+
+  @protected
+  SyncFilterConfig sse_decode_box_autoadd_sync_filter_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_filter_config(deserializer));
+  }
+
+  @protected
+  ThumbnailRequest sse_decode_box_autoadd_thumbnail_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_thumbnail_request(deserializer));
+  }
+
+  @protected
+  TimelineItem sse_decode_box_autoadd_timeline_item(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_timeline_item(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  BridgeError sse_decode_bridge_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_kind = sse_decode_bridge_error_kind(deserializer);
+    final var_message = sse_decode_String(deserializer);
+    final var_retryAfterMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return BridgeError(
+        kind: var_kind, message: var_message, retryAfterMs: var_retryAfterMs);
+  }
+
+  @protected
+  BridgeErrorKind sse_decode_bridge_error_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return BridgeErrorKind.values[inner];
+  }
+
+  @protected
+  CallOptions sse_decode_call_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_timeoutMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_cancel = sse_decode_opt_box_autoadd_cancel_token(deserializer);
+    return CallOptions(timeoutMs: var_timeoutMs, cancel: var_cancel);
+  }
+
+  @protected
+  CancelToken sse_decode_cancel_token(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_u_64(deserializer);
+    return CancelToken(id: var_id);
+  }
+
+  @protected
+  ClientHandle sse_decode_client_handle(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_u_32(deserializer);
+    return ClientHandle(id: var_id);
+  }
+
+  @protected
+  ClientInfo sse_decode_client_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_handle = sse_decode_client_handle(deserializer);
+    final var_userId = sse_decode_opt_String(deserializer);
+    final var_deviceId = sse_decode_opt_String(deserializer);
+    final var_homeserver = sse_decode_String(deserializer);
+    final var_dataDir = sse_decode_String(deserializer);
+    final var_isSyncing = sse_decode_bool(deserializer);
+    return ClientInfo(
+        handle: var_handle,
+        userId: var_userId,
+        deviceId: var_deviceId,
+        homeserver: var_homeserver,
+        dataDir: var_dataDir,
+        isSyncing: var_isSyncing);
+  }
+
+  @protected
+  DirectRoom sse_decode_direct_room(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_roomId = sse_decode_String(deserializer);
+    final var_created = sse_decode_bool(deserializer);
+    return DirectRoom(roomId: var_roomId, created: var_created);
+  }
+
+  @protected
+  GroupRoomConfig sse_decode_group_room_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_name = sse_decode_String(deserializer);
+    final var_topic = sse_decode_opt_String(deserializer);
+    final var_avatar = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    final var_avatarMimeType = sse_decode_opt_String(deserializer);
+    final var_participants = sse_decode_list_participant(deserializer);
+    final var_property =
+        sse_decode_opt_box_autoadd_property_metadata(deserializer);
+    return GroupRoomConfig(
+        name: var_name,
+        topic: var_topic,
+        avatar: var_avatar,
+        avatarMimeType: var_avatarMimeType,
+        participants: var_participants,
+        property: var_property);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  InviteInfo sse_decode_invite_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_roomId = sse_decode_String(deserializer);
+    final var_displayName = sse_decode_String(deserializer);
+    final var_avatarUrl = sse_decode_opt_String(deserializer);
+    final var_topic = sse_decode_opt_String(deserializer);
+    final var_isDirect = sse_decode_bool(deserializer);
+    final var_property =
+        sse_decode_opt_box_autoadd_property_metadata(deserializer);
+    final var_inviter = sse_decode_opt_String(deserializer);
+    final var_inviterDisplayName = sse_decode_opt_String(deserializer);
+    final var_inviterAvatarUrl = sse_decode_opt_String(deserializer);
+    return InviteInfo(
+        roomId: var_roomId,
+        displayName: var_displayName,
+        avatarUrl: var_avatarUrl,
+        topic: var_topic,
+        isDirect: var_isDirect,
+        property: var_property,
+        inviter: var_inviter,
+        inviterDisplayName: var_inviterDisplayName,
+        inviterAvatarUrl: var_inviterAvatarUrl);
+  }
+
+  @protected
+  InvitePolicy sse_decode_invite_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_kind = sse_decode_invite_policy_kind(deserializer);
+    final var_allowedUsers = sse_decode_list_String(deserializer);
+    final var_allowedServers = sse_decode_list_String(deserializer);
+    return InvitePolicy(
+        kind: var_kind,
+        allowedUsers: var_allowedUsers,
+        allowedServers: var_allowedServers);
+  }
+
+  @protected
+  InvitePolicyKind sse_decode_invite_policy_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return InvitePolicyKind.values[inner];
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_;++ idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ClientInfo> sse_decode_list_client_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <ClientInfo>[];
+    for (var idx_ = 0; idx_ < len_;++ idx_) {
+      ans_.add(sse_decode_client_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InviteInfo> sse_decode_list_invite_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <InviteInfo>[];
+    for (var idx_ = 0; idx_ < len_;++ idx_) {
+      ans_.add(sse_decode_invite_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Participant> sse_decode_list_participant(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <Participant>[];
+    for (var idx_ = 0; idx_ < len_;++ idx_) {
+      ans_.add(sse_decode_participant(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RoomMemberInfo> sse_decode_list_room_member_info(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <RoomMemberInfo>[];
+    for (var idx_ = 0; idx_ < len_;++ idx_) {
+      ans_.add(sse_decode_room_member_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RoomSummary> sse_decode_list_room_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <RoomSummary>[];
+    for (var idx_ = 0; idx_ < len_;++ idx_) {
+      ans_.add(sse_decode_room_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SpaceHierarchyEntry> sse_decode_list_space_hierarchy_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <SpaceHierarchyEntry>[];
+    for (var idx_ = 0; idx_ < len_;++ idx_) {
+      ans_.add(sse_decode_space_hierarchy_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TimelineItem> sse_decode_list_timeline_item(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <TimelineItem>[];
+    for (var idx_ = 0; idx_ < len_;++ idx_) {
+      ans_.add(sse_decode_timeline_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LoginResult sse_decode_login_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_userId = sse_decode_String(deserializer);
+    final var_deviceId = sse_decode_String(deserializer);
+    final var_accessToken = sse_decode_String(deserializer);
+    final var_refreshToken = sse_decode_opt_String(deserializer);
+    return LoginResult(
+        userId: var_userId,
+        deviceId: var_deviceId,
+        accessToken: var_accessToken,
+        refreshToken: var_refreshToken);
+  }
+
+  @protected
+  MediaCacheConfig sse_decode_media_cache_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_maxTotalBytes = sse_decode_u_64(deserializer);
+    final var_maxFileBytes = sse_decode_u_64(deserializer);
+    return MediaCacheConfig(
+        maxTotalBytes: var_maxTotalBytes, maxFileBytes: var_maxFileBytes);
+  }
+
+  @protected
+  MemberState sse_decode_member_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return MemberState.values[inner];
+  }
+
+  @protected
+  MessageKind sse_decode_message_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return MessageKind.values[inner];
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Attachment? sse_decode_opt_box_autoadd_attachment(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_attachment(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BridgeError? sse_decode_opt_box_autoadd_bridge_error(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bridge_error(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CallOptions? sse_decode_opt_box_autoadd_call_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_call_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CancelToken? sse_decode_opt_box_autoadd_cancel_token(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cancel_token(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  InvitePolicy? sse_decode_opt_box_autoadd_invite_policy(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_invite_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PropertyMetadata? sse_decode_opt_box_autoadd_property_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_property_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RoomSummary? sse_decode_opt_box_autoadd_room_summary(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_room_summary(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SlidingSyncConfig? sse_decode_opt_box_autoadd_sliding_sync_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_sliding_sync_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SyncFilterConfig? sse_decode_opt_box_autoadd_sync_filter_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_sync_filter_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ThumbnailRequest? sse_decode_opt_box_autoadd_thumbnail_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_thumbnail_request(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  TimelineItem? sse_decode_opt_box_autoadd_timeline_item(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_timeline_item(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Participant sse_decode_participant(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_userId = sse_decode_String(deserializer);
+    final var_role = sse_decode_participant_role(deserializer);
+    return Participant(userId: var_userId, role: var_role);
+  }
+
+  @protected
+  ParticipantRole sse_decode_participant_role(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return ParticipantRole.values[inner];
+  }
+
+  @protected
+  PropertyMetadata sse_decode_property_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_propertyId = sse_decode_String(deserializer);
+    final var_address = sse_decode_opt_String(deserializer);
+    final var_tenancyId = sse_decode_opt_String(deserializer);
+    final var_participants = sse_decode_list_participant(deserializer);
+    return PropertyMetadata(
+        propertyId: var_propertyId,
+        address: var_address,
+        tenancyId: var_tenancyId,
+        participants: var_participants);
+  }
+
+  @protected
+  RoomListChange sse_decode_room_list_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return RoomListChange.values[inner];
+  }
+
+  @protected
+  RoomListUpdate sse_decode_room_list_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_roomId = sse_decode_String(deserializer);
+    final var_change = sse_decode_room_list_change(deserializer);
+    final var_room = sse_decode_opt_box_autoadd_room_summary(deserializer);
+    return RoomListUpdate(
+        roomId: var_roomId, change: var_change, room: var_room);
+  }
+
+  @protected
+  RoomMemberInfo sse_decode_room_member_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_userId = sse_decode_String(deserializer);
+    final var_displayName = sse_decode_opt_String(deserializer);
+    final var_avatarUrl = sse_decode_opt_String(deserializer);
+    final var_membership = sse_decode_member_state(deserializer);
+    final var_powerLevel = sse_decode_i_64(deserializer);
+    return RoomMemberInfo(
+        userId: var_userId,
+        displayName: var_displayName,
+        avatarUrl: var_avatarUrl,
+        membership: var_membership,
+        powerLevel: var_powerLevel);
+  }
+
+  @protected
+  RoomMembership sse_decode_room_membership(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return RoomMembership.values[inner];
+  }
+
+  @protected
+  RoomSummary sse_decode_room_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_roomId = sse_decode_String(deserializer);
+    final var_displayName = sse_decode_String(deserializer);
+    final var_avatarUrl = sse_decode_opt_String(deserializer);
+    final var_membership = sse_decode_room_membership(deserializer);
+    final var_isEncrypted = sse_decode_bool(deserializer);
+    final var_isDirect = sse_decode_bool(deserializer);
+    final var_members = sse_decode_list_String(deserializer);
+    final var_joinedMembers = sse_decode_u_64(deserializer);
+    final var_invitedMembers = sse_decode_u_64(deserializer);
+    final var_latestMessage =
+        sse_decode_opt_box_autoadd_timeline_item(deserializer);
+    final var_property =
+        sse_decode_opt_box_autoadd_property_metadata(deserializer);
+    final var_unreadCount = sse_decode_u_64(deserializer);
+    final var_highlightCount = sse_decode_u_64(deserializer);
+    return RoomSummary(
+        roomId: var_roomId,
+        displayName: var_displayName,
+        avatarUrl: var_avatarUrl,
+        membership: var_membership,
+        isEncrypted: var_isEncrypted,
+        isDirect: var_isDirect,
+        members: var_members,
+        joinedMembers: var_joinedMembers,
+        invitedMembers: var_invitedMembers,
+        latestMessage: var_latestMessage,
+        property: var_property,
+        unreadCount: var_unreadCount,
+        highlightCount: var_highlightCount);
+  }
+
+  @protected
+  SendState sse_decode_send_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return SendState.values[inner];
+  }
+
+  @protected
+  SlidingSyncConfig sse_decode_sliding_sync_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_proxyUrl = sse_decode_opt_String(deserializer);
+    final var_windowSize = sse_decode_u_32(deserializer);
+    final var_timelineLimit = sse_decode_u_32(deserializer);
+    return SlidingSyncConfig(
+        proxyUrl: var_proxyUrl,
+        windowSize: var_windowSize,
+        timelineLimit: var_timelineLimit);
+  }
+
+  @protected
+  SpaceHierarchyEntry sse_decode_space_hierarchy_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_roomId = sse_decode_String(deserializer);
+    final var_parentId = sse_decode_opt_String(deserializer);
+    final var_name = sse_decode_opt_String(deserializer);
+    final var_topic = sse_decode_opt_String(deserializer);
+    final var_avatarUrl = sse_decode_opt_String(deserializer);
+    final var_isSpace = sse_decode_bool(deserializer);
+    final var_joinedMembers = sse_decode_u_64(deserializer);
+    final var_joined = sse_decode_bool(deserializer);
+    return SpaceHierarchyEntry(
+        roomId: var_roomId,
+        parentId: var_parentId,
+        name: var_name,
+        topic: var_topic,
+        avatarUrl: var_avatarUrl,
+        isSpace: var_isSpace,
+        joinedMembers: var_joinedMembers,
+        joined: var_joined);
+  }
+
+  @protected
+  StoreRecoveryEvent sse_decode_store_recovery_event(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_client = sse_decode_client_handle(deserializer);
+    final var_stage = sse_decode_store_recovery_stage(deserializer);
+    final var_reason = sse_decode_String(deserializer);
+    final var_quarantinedDir = sse_decode_String(deserializer);
+    final var_keyBackupFile = sse_decode_opt_String(deserializer);
+    final var_exportedKeys = sse_decode_u_64(deserializer);
+    final var_importedKeys = sse_decode_u_64(deserializer);
+    final var_error = sse_decode_opt_String(deserializer);
+    return StoreRecoveryEvent(
+        client: var_client,
+        stage: var_stage,
+        reason: var_reason,
+        quarantinedDir: var_quarantinedDir,
+        keyBackupFile: var_keyBackupFile,
+        exportedKeys: var_exportedKeys,
+        importedKeys: var_importedKeys,
+        error: var_error);
+  }
+
+  @protected
+  StoreRecoveryStage sse_decode_store_recovery_stage(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return StoreRecoveryStage.values[inner];
+  }
+
+  @protected
+  SyncFilterConfig sse_decode_sync_filter_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_lazyLoadMembers = sse_decode_bool(deserializer);
+    final var_timelineLimit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_includePresence = sse_decode_bool(deserializer);
+    final var_includeAccountData = sse_decode_bool(deserializer);
+    final var_includeEphemeral = sse_decode_bool(deserializer);
+    return SyncFilterConfig(
+        lazyLoadMembers: var_lazyLoadMembers,
+        timelineLimit: var_timelineLimit,
+        includePresence: var_includePresence,
+        includeAccountData: var_includeAccountData,
+        includeEphemeral: var_includeEphemeral);
+  }
+
+  @protected
+  SyncState sse_decode_sync_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return SyncState.values[inner];
+  }
+
+  @protected
+  SyncStatus sse_decode_sync_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_state = sse_decode_sync_state(deserializer);
+    final var_attempt = sse_decode_u_32(deserializer);
+    final var_nextRetryMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_error = sse_decode_opt_box_autoadd_bridge_error(deserializer);
+    return SyncStatus(
+        state: var_state,
+        attempt: var_attempt,
+        nextRetryMs: var_nextRetryMs,
+        error: var_error);
+  }
+
+  @protected
+  ThumbnailRequest sse_decode_thumbnail_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_width = sse_decode_u_32(deserializer);
+    final var_height = sse_decode_u_32(deserializer);
+    final var_crop = sse_decode_bool(deserializer);
+    return ThumbnailRequest(
+        width: var_width, height: var_height, crop: var_crop);
+  }
+
+  @protected
+  TimelineDirection sse_decode_timeline_direction(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return TimelineDirection.values[inner];
+  }
+
+  @protected
+  TimelineItem sse_decode_timeline_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_roomId = sse_decode_String(deserializer);
+    final var_eventId = sse_decode_String(deserializer);
+    final var_sender = sse_decode_String(deserializer);
+    final var_timestampMs = sse_decode_i_64(deserializer);
+    final var_kind = sse_decode_message_kind(deserializer);
+    final var_body = sse_decode_String(deserializer);
+    final var_formattedBody = sse_decode_opt_String(deserializer);
+    final var_isEncrypted = sse_decode_bool(deserializer);
+    final var_decryptionError = sse_decode_opt_String(deserializer);
+    final var_replacesEventId = sse_decode_opt_String(deserializer);
+    final var_inReplyTo = sse_decode_opt_String(deserializer);
+    final var_attachment = sse_decode_opt_box_autoadd_attachment(deserializer);
+    final var_sendState = sse_decode_send_state(deserializer);
+    return TimelineItem(
+        roomId: var_roomId,
+        eventId: var_eventId,
+        sender: var_sender,
+        timestampMs: var_timestampMs,
+        kind: var_kind,
+        body: var_body,
+        formattedBody: var_formattedBody,
+        isEncrypted: var_isEncrypted,
+        decryptionError: var_decryptionError,
+        replacesEventId: var_replacesEventId,
+        inReplyTo: var_inReplyTo,
+        attachment: var_attachment,
+        sendState: var_sendState);
+  }
+
+  @protected
+  TimelinePage sse_decode_timeline_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_messages = sse_decode_list_timeline_item(deserializer);
+    final var_nextToken = sse_decode_opt_String(deserializer);
+    final var_reachedStart = sse_decode_bool(deserializer);
+    return TimelinePage(
+        messages: var_messages,
+        nextToken: var_nextToken,
+        reachedStart: var_reachedStart);
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_account_timeline_item_Sse(
+      RustStreamSink<AccountTimelineItem> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_account_timeline_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_invite_info_Sse(
+      RustStreamSink<InviteInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_invite_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_room_list_update_Sse(
+      RustStreamSink<RoomListUpdate> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_room_list_update,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_store_recovery_event_Sse(
+      RustStreamSink<StoreRecoveryEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_store_recovery_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_sync_status_Sse(
+      RustStreamSink<SyncStatus> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_timeline_item_Sse(
+      RustStreamSink<TimelineItem> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_timeline_item,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_account_timeline_item(
+      AccountTimelineItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_client_handle(self.client, serializer);
+    sse_encode_String(self.userId, serializer);
+    sse_encode_timeline_item(self.item, serializer);
+  }
+
+  @protected
+  void sse_encode_attachment(Attachment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.source, serializer);
+    sse_encode_bool(self.isEncrypted, serializer);
+    sse_encode_String(self.filename, serializer);
+    sse_encode_opt_String(self.mimetype, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.size, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.durationMs, serializer);
+    sse_encode_opt_String(self.thumbnailSource, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_attachment(
+      Attachment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_attachment(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bridge_error(
+      BridgeError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bridge_error(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_call_options(
+      CallOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_call_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cancel_token(
+      CancelToken self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cancel_token(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_client_handle(
+      ClientHandle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_client_handle(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_group_room_config(
+      GroupRoomConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_room_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_invite_policy(
+      InvitePolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_invite_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_login_result(
+      LoginResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_login_result(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_media_cache_config(
+      MediaCacheConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_media_cache_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_property_metadata(
+      PropertyMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_property_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_room_summary(
+      RoomSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_room_summary(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sliding_sync_config(
+      SlidingSyncConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sliding_sync_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_filter_config(
+      SyncFilterConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_filter_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_thumbnail_request(
+      ThumbnailRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_thumbnail_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_timeline_item(
+      TimelineItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_timeline_item(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_bridge_error(BridgeError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bridge_error_kind(self.kind, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.retryAfterMs, serializer);
+  }
+
+  @protected
+  void sse_encode_bridge_error_kind(
+      BridgeErrorKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_call_options(CallOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.timeoutMs, serializer);
+    sse_encode_opt_box_autoadd_cancel_token(self.cancel, serializer);
+  }
+
+  @protected
+  void sse_encode_cancel_token(CancelToken self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.id, serializer);
+  }
+
+  @protected
+  void sse_encode_client_handle(ClientHandle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.id, serializer);
+  }
+
+  @protected
+  void sse_encode_client_info(ClientInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_client_handle(self.handle, serializer);
+    sse_encode_opt_String(self.userId, serializer);
+    sse_encode_opt_String(self.deviceId, serializer);
+    sse_encode_String(self.homeserver, serializer);
+    sse_encode_String(self.dataDir, serializer);
+    sse_encode_bool(self.isSyncing, serializer);
+  }
+
+  @protected
+  void sse_encode_direct_room(DirectRoom self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_bool(self.created, serializer);
+  }
+
+  @protected
+  void sse_encode_group_room_config(
+      GroupRoomConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.avatar, serializer);
+    sse_encode_opt_String(self.avatarMimeType, serializer);
+    sse_encode_list_participant(self.participants, serializer);
+    sse_encode_opt_box_autoadd_property_metadata(self.property, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_invite_info(InviteInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_String(self.displayName, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_bool(self.isDirect, serializer);
+    sse_encode_opt_box_autoadd_property_metadata(self.property, serializer);
+    sse_encode_opt_String(self.inviter, serializer);
+    sse_encode_opt_String(self.inviterDisplayName, serializer);
+    sse_encode_opt_String(self.inviterAvatarUrl, serializer);
+  }
+
+  @protected
+  void sse_encode_invite_policy(InvitePolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_invite_policy_kind(self.kind, serializer);
+    sse_encode_list_String(self.allowedUsers, serializer);
+    sse_encode_list_String(self.allowedServers, serializer);
+  }
+
+  @protected
+  void sse_encode_invite_policy_kind(
+      InvitePolicyKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_client_info(
+      List<ClientInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_client_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_invite_info(
+      List<InviteInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_invite_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_participant(
+      List<Participant> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_participant(item, serializer);
+    }
   }
 
   @protected
-  RustStreamSink<MatrixEvent> sse_decode_StreamSink_matrix_event_Sse(
-      SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_room_member_info(
+      List<RoomMemberInfo> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_room_member_info(item, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_room_summary(
+      List<RoomSummary> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_room_summary(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_space_hierarchy_entry(
+      List<SpaceHierarchyEntry> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_space_hierarchy_entry(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_timeline_item(
+      List<TimelineItem> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_timeline_item(item, serializer);
+    }
   }
 
   @protected
-  LoginResult sse_decode_login_result(SseDeserializer deserializer) {
+  void sse_encode_login_result(LoginResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_userId = sse_decode_String(deserializer);
-    final var_accessToken = sse_decode_String(deserializer);
-    return LoginResult(userId: var_userId, accessToken: var_accessToken);
+    sse_encode_String(self.userId, serializer);
+    sse_encode_String(self.deviceId, serializer);
+    sse_encode_String(self.accessToken, serializer);
+    sse_encode_opt_String(self.refreshToken, serializer);
   }
 
   @protected
-  MatrixEvent sse_decode_matrix_event(SseDeserializer deserializer) {
+  void sse_encode_media_cache_config(
+      MediaCacheConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_roomId = sse_decode_String(deserializer);
-    final var_eventId = sse_decode_String(deserializer);
-    final var_sender = sse_decode_String(deserializer);
-    final var_ts = sse_decode_i_64(deserializer);
-    final var_content = sse_decode_opt_String(deserializer);
-    final var_isEncrypted = sse_decode_bool(deserializer);
-    return MatrixEvent(
-        roomId: var_roomId,
-        eventId: var_eventId,
-        sender: var_sender,
-        ts: var_ts,
-        content: var_content,
-        isEncrypted: var_isEncrypted);
+    sse_encode_u_64(self.maxTotalBytes, serializer);
+    sse_encode_u_64(self.maxFileBytes, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_member_state(MemberState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_message_kind(MessageKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_attachment(
+      Attachment? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_attachment(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_bridge_error(
+      BridgeError? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bridge_error(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_call_options(
+      CallOptions? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_call_options(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_cancel_token(
+      CancelToken? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cancel_token(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_invite_policy(
+      InvitePolicy? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_invite_policy(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_matrix_event_Sse(
-      RustStreamSink<MatrixEvent> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_property_metadata(
+      PropertyMetadata? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: SseCodec(
-          decodeSuccessData: sse_decode_matrix_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        )),
-        serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_property_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_room_summary(
+      RoomSummary? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_room_summary(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_sliding_sync_config(
+      SlidingSyncConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sliding_sync_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_sync_filter_config(
+      SyncFilterConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sync_filter_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_thumbnail_request(
+      ThumbnailRequest? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_thumbnail_request(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_login_result(LoginResult self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_timeline_item(
+      TimelineItem? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_timeline_item(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_String(
+      List<String>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+      Uint8List? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_participant(Participant self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.userId, serializer);
-    sse_encode_String(self.accessToken, serializer);
+    sse_encode_participant_role(self.role, serializer);
+  }
+
+  @protected
+  void sse_encode_participant_role(
+      ParticipantRole self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_property_metadata(
+      PropertyMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.propertyId, serializer);
+    sse_encode_opt_String(self.address, serializer);
+    sse_encode_opt_String(self.tenancyId, serializer);
+    sse_encode_list_participant(self.participants, serializer);
+  }
+
+  @protected
+  void sse_encode_room_list_change(
+      RoomListChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_room_list_update(
+      RoomListUpdate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_room_list_change(self.change, serializer);
+    sse_encode_opt_box_autoadd_room_summary(self.room, serializer);
+  }
+
+  @protected
+  void sse_encode_room_member_info(
+      RoomMemberInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.userId, serializer);
+    sse_encode_opt_String(self.displayName, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_member_state(self.membership, serializer);
+    sse_encode_i_64(self.powerLevel, serializer);
+  }
+
+  @protected
+  void sse_encode_room_membership(
+      RoomMembership self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_room_summary(RoomSummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_String(self.displayName, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_room_membership(self.membership, serializer);
+    sse_encode_bool(self.isEncrypted, serializer);
+    sse_encode_bool(self.isDirect, serializer);
+    sse_encode_list_String(self.members, serializer);
+    sse_encode_u_64(self.joinedMembers, serializer);
+    sse_encode_u_64(self.invitedMembers, serializer);
+    sse_encode_opt_box_autoadd_timeline_item(self.latestMessage, serializer);
+    sse_encode_opt_box_autoadd_property_metadata(self.property, serializer);
+    sse_encode_u_64(self.unreadCount, serializer);
+    sse_encode_u_64(self.highlightCount, serializer);
+  }
+
+  @protected
+  void sse_encode_send_state(SendState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sliding_sync_config(
+      SlidingSyncConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.proxyUrl, serializer);
+    sse_encode_u_32(self.windowSize, serializer);
+    sse_encode_u_32(self.timelineLimit, serializer);
+  }
+
+  @protected
+  void sse_encode_space_hierarchy_entry(
+      SpaceHierarchyEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.roomId, serializer);
+    sse_encode_opt_String(self.parentId, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.topic, serializer);
+    sse_encode_opt_String(self.avatarUrl, serializer);
+    sse_encode_bool(self.isSpace, serializer);
+    sse_encode_u_64(self.joinedMembers, serializer);
+    sse_encode_bool(self.joined, serializer);
+  }
+
+  @protected
+  void sse_encode_store_recovery_event(
+      StoreRecoveryEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_client_handle(self.client, serializer);
+    sse_encode_store_recovery_stage(self.stage, serializer);
+    sse_encode_String(self.reason, serializer);
+    sse_encode_String(self.quarantinedDir, serializer);
+    sse_encode_opt_String(self.keyBackupFile, serializer);
+    sse_encode_u_64(self.exportedKeys, serializer);
+    sse_encode_u_64(self.importedKeys, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_store_recovery_stage(
+      StoreRecoveryStage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_filter_config(
+      SyncFilterConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.lazyLoadMembers, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.timelineLimit, serializer);
+    sse_encode_bool(self.includePresence, serializer);
+    sse_encode_bool(self.includeAccountData, serializer);
+    sse_encode_bool(self.includeEphemeral, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_state(SyncState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_status(SyncStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_state(self.state, serializer);
+    sse_encode_u_32(self.attempt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.nextRetryMs, serializer);
+    sse_encode_opt_box_autoadd_bridge_error(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_thumbnail_request(
+      ThumbnailRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_bool(self.crop, serializer);
+  }
+
+  @protected
+  void sse_encode_timeline_direction(
+      TimelineDirection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_matrix_event(MatrixEvent self, SseSerializer serializer) {
+  void sse_encode_timeline_item(TimelineItem self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.roomId, serializer);
     sse_encode_String(self.eventId, serializer);
     sse_encode_String(self.sender, serializer);
-    sse_encode_i_64(self.ts, serializer);
-    sse_encode_opt_String(self.content, serializer);
+    sse_encode_i_64(self.timestampMs, serializer);
+    sse_encode_message_kind(self.kind, serializer);
+    sse_encode_String(self.body, serializer);
+    sse_encode_opt_String(self.formattedBody, serializer);
     sse_encode_bool(self.isEncrypted, serializer);
+    sse_encode_opt_String(self.decryptionError, serializer);
+    sse_encode_opt_String(self.replacesEventId, serializer);
+    sse_encode_opt_String(self.inReplyTo, serializer);
+    sse_encode_opt_box_autoadd_attachment(self.attachment, serializer);
+    sse_encode_send_state(self.sendState, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_timeline_page(TimelinePage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sse_encode_list_timeline_item(self.messages, serializer);
+    sse_encode_opt_String(self.nextToken, serializer);
+    sse_encode_bool(self.reachedStart, serializer);
   }
 
   @protected
@@ -610,19 +4568,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
   }
 }
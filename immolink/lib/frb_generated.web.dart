@@ -24,33 +24,283 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
   @protected
-  RustStreamSink<MatrixEvent> dco_decode_StreamSink_matrix_event_Sse(
+  RustStreamSink<AccountTimelineItem> dco_decode_StreamSink_account_timeline_item_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<InviteInfo> dco_decode_StreamSink_invite_info_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<RoomListUpdate> dco_decode_StreamSink_room_list_update_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<StoreRecoveryEvent> dco_decode_StreamSink_store_recovery_event_Sse(
+      dynamic raw);
+
+  @protected
+  RustStreamSink<SyncStatus> dco_decode_StreamSink_sync_status_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<TimelineItem> dco_decode_StreamSink_timeline_item_Sse(
       dynamic raw);
 
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AccountTimelineItem dco_decode_account_timeline_item(dynamic raw);
+
+  @protected
+  Attachment dco_decode_attachment(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  Attachment dco_decode_box_autoadd_attachment(dynamic raw);
+
+  @protected
+  BridgeError dco_decode_box_autoadd_bridge_error(dynamic raw);
+
+  @protected
+  CallOptions dco_decode_box_autoadd_call_options(dynamic raw);
+
+  @protected
+  CancelToken dco_decode_box_autoadd_cancel_token(dynamic raw);
+
+  @protected
+  ClientHandle dco_decode_box_autoadd_client_handle(dynamic raw);
+
+  @protected
+  GroupRoomConfig dco_decode_box_autoadd_group_room_config(dynamic raw);
+
+  @protected
+  InvitePolicy dco_decode_box_autoadd_invite_policy(dynamic raw);
+
+  @protected
+  LoginResult dco_decode_box_autoadd_login_result(dynamic raw);
+
+  @protected
+  MediaCacheConfig dco_decode_box_autoadd_media_cache_config(dynamic raw);
+
+  @protected
+  PropertyMetadata dco_decode_box_autoadd_property_metadata(dynamic raw);
+
+  @protected
+  RoomSummary dco_decode_box_autoadd_room_summary(dynamic raw);
+
+  @protected
+  SlidingSyncConfig dco_decode_box_autoadd_sliding_sync_config(dynamic raw);
+
+  @protected
+  SyncFilterConfig dco_decode_box_autoadd_sync_filter_config(dynamic raw);
+
+  @protected
+  ThumbnailRequest dco_decode_box_autoadd_thumbnail_request(dynamic raw);
+
+  @protected
+  TimelineItem dco_decode_box_autoadd_timeline_item(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  BridgeError dco_decode_bridge_error(dynamic raw);
+
+  @protected
+  BridgeErrorKind dco_decode_bridge_error_kind(dynamic raw);
+
+  @protected
+  CallOptions dco_decode_call_options(dynamic raw);
+
+  @protected
+  CancelToken dco_decode_cancel_token(dynamic raw);
+
+  @protected
+  ClientHandle dco_decode_client_handle(dynamic raw);
+
+  @protected
+  ClientInfo dco_decode_client_info(dynamic raw);
+
+  @protected
+  DirectRoom dco_decode_direct_room(dynamic raw);
+
+  @protected
+  GroupRoomConfig dco_decode_group_room_config(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  InviteInfo dco_decode_invite_info(dynamic raw);
+
+  @protected
+  InvitePolicy dco_decode_invite_policy(dynamic raw);
+
+  @protected
+  InvitePolicyKind dco_decode_invite_policy_kind(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<ClientInfo> dco_decode_list_client_info(dynamic raw);
+
+  @protected
+  List<InviteInfo> dco_decode_list_invite_info(dynamic raw);
+
+  @protected
+  List<Participant> dco_decode_list_participant(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<RoomMemberInfo> dco_decode_list_room_member_info(dynamic raw);
+
+  @protected
+  List<RoomSummary> dco_decode_list_room_summary(dynamic raw);
+
+  @protected
+  List<SpaceHierarchyEntry> dco_decode_list_space_hierarchy_entry(dynamic raw);
+
+  @protected
+  List<TimelineItem> dco_decode_list_timeline_item(dynamic raw);
+
   @protected
   LoginResult dco_decode_login_result(dynamic raw);
 
   @protected
-  MatrixEvent dco_decode_matrix_event(dynamic raw);
+  MediaCacheConfig dco_decode_media_cache_config(dynamic raw);
+
+  @protected
+  MemberState dco_decode_member_state(dynamic raw);
+
+  @protected
+  MessageKind dco_decode_message_kind(dynamic raw);
 
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  Attachment? dco_decode_opt_box_autoadd_attachment(dynamic raw);
+
+  @protected
+  BridgeError? dco_decode_opt_box_autoadd_bridge_error(dynamic raw);
+
+  @protected
+  CallOptions? dco_decode_opt_box_autoadd_call_options(dynamic raw);
+
+  @protected
+  CancelToken? dco_decode_opt_box_autoadd_cancel_token(dynamic raw);
+
+  @protected
+  InvitePolicy? dco_decode_opt_box_autoadd_invite_policy(dynamic raw);
+
+  @protected
+  PropertyMetadata? dco_decode_opt_box_autoadd_property_metadata(dynamic raw);
+
+  @protected
+  RoomSummary? dco_decode_opt_box_autoadd_room_summary(dynamic raw);
+
+  @protected
+  SlidingSyncConfig? dco_decode_opt_box_autoadd_sliding_sync_config(
+      dynamic raw);
+
+  @protected
+  SyncFilterConfig? dco_decode_opt_box_autoadd_sync_filter_config(dynamic raw);
+
+  @protected
+  ThumbnailRequest? dco_decode_opt_box_autoadd_thumbnail_request(dynamic raw);
+
+  @protected
+  TimelineItem? dco_decode_opt_box_autoadd_timeline_item(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  Participant dco_decode_participant(dynamic raw);
+
+  @protected
+  ParticipantRole dco_decode_participant_role(dynamic raw);
+
+  @protected
+  PropertyMetadata dco_decode_property_metadata(dynamic raw);
+
+  @protected
+  RoomListChange dco_decode_room_list_change(dynamic raw);
+
+  @protected
+  RoomListUpdate dco_decode_room_list_update(dynamic raw);
+
+  @protected
+  RoomMemberInfo dco_decode_room_member_info(dynamic raw);
+
+  @protected
+  RoomMembership dco_decode_room_membership(dynamic raw);
+
+  @protected
+  RoomSummary dco_decode_room_summary(dynamic raw);
+
+  @protected
+  SendState dco_decode_send_state(dynamic raw);
+
+  @protected
+  SlidingSyncConfig dco_decode_sliding_sync_config(dynamic raw);
+
+  @protected
+  SpaceHierarchyEntry dco_decode_space_hierarchy_entry(dynamic raw);
+
+  @protected
+  StoreRecoveryEvent dco_decode_store_recovery_event(dynamic raw);
+
+  @protected
+  StoreRecoveryStage dco_decode_store_recovery_stage(dynamic raw);
+
+  @protected
+  SyncFilterConfig dco_decode_sync_filter_config(dynamic raw);
+
+  @protected
+  SyncState dco_decode_sync_state(dynamic raw);
+
+  @protected
+  SyncStatus dco_decode_sync_status(dynamic raw);
+
+  @protected
+  ThumbnailRequest dco_decode_thumbnail_request(dynamic raw);
+
+  @protected
+  TimelineDirection dco_decode_timeline_direction(dynamic raw);
+
+  @protected
+  TimelineItem dco_decode_timeline_item(dynamic raw);
+
+  @protected
+  TimelinePage dco_decode_timeline_page(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -61,83 +311,659 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
   @protected
-  RustStreamSink<MatrixEvent> sse_decode_StreamSink_matrix_event_Sse(
+  RustStreamSink<AccountTimelineItem> sse_decode_StreamSink_account_timeline_item_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<InviteInfo> sse_decode_StreamSink_invite_info_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<RoomListUpdate> sse_decode_StreamSink_room_list_update_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<StoreRecoveryEvent> sse_decode_StreamSink_store_recovery_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<SyncStatus> sse_decode_StreamSink_sync_status_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<TimelineItem> sse_decode_StreamSink_timeline_item_Sse(
       SseDeserializer deserializer);
 
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AccountTimelineItem sse_decode_account_timeline_item(
+      SseDeserializer deserializer);
+
+  @protected
+  Attachment sse_decode_attachment(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  Attachment sse_decode_box_autoadd_attachment(SseDeserializer deserializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  BridgeError sse_decode_box_autoadd_bridge_error(SseDeserializer deserializer);
 
   @protected
-  LoginResult sse_decode_login_result(SseDeserializer deserializer);
+  CallOptions sse_decode_box_autoadd_call_options(SseDeserializer deserializer);
 
   @protected
-  MatrixEvent sse_decode_matrix_event(SseDeserializer deserializer);
+  CancelToken sse_decode_box_autoadd_cancel_token(SseDeserializer deserializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  ClientHandle sse_decode_box_autoadd_client_handle(
+      SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  GroupRoomConfig sse_decode_box_autoadd_group_room_config(
+      SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  InvitePolicy sse_decode_box_autoadd_invite_policy(
+      SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  LoginResult sse_decode_box_autoadd_login_result(SseDeserializer deserializer);
+
+  @protected
+  MediaCacheConfig sse_decode_box_autoadd_media_cache_config(
+      SseDeserializer deserializer);
+
+  @protected
+  PropertyMetadata sse_decode_box_autoadd_property_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  RoomSummary sse_decode_box_autoadd_room_summary(SseDeserializer deserializer);
+
+  @protected
+  SlidingSyncConfig sse_decode_box_autoadd_sliding_sync_config(
+      SseDeserializer deserializer);
+
+  @protected
+  SyncFilterConfig sse_decode_box_autoadd_sync_filter_config(
+      SseDeserializer deserializer);
+
+  @protected
+  ThumbnailRequest sse_decode_box_autoadd_thumbnail_request(
+      SseDeserializer deserializer);
+
+  @protected
+  TimelineItem sse_decode_box_autoadd_timeline_item(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BridgeError sse_decode_bridge_error(SseDeserializer deserializer);
+
+  @protected
+  BridgeErrorKind sse_decode_bridge_error_kind(SseDeserializer deserializer);
+
+  @protected
+  CallOptions sse_decode_call_options(SseDeserializer deserializer);
+
+  @protected
+  CancelToken sse_decode_cancel_token(SseDeserializer deserializer);
+
+  @protected
+  ClientHandle sse_decode_client_handle(SseDeserializer deserializer);
+
+  @protected
+  ClientInfo sse_decode_client_info(SseDeserializer deserializer);
+
+  @protected
+  DirectRoom sse_decode_direct_room(SseDeserializer deserializer);
+
+  @protected
+  GroupRoomConfig sse_decode_group_room_config(SseDeserializer deserializer);
 
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_StreamSink_matrix_event_Sse(
-      RustStreamSink<MatrixEvent> self, SseSerializer serializer);
+  InviteInfo sse_decode_invite_info(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  InvitePolicy sse_decode_invite_policy(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  InvitePolicyKind sse_decode_invite_policy_kind(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+  List<ClientInfo> sse_decode_list_client_info(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_login_result(LoginResult self, SseSerializer serializer);
+  List<InviteInfo> sse_decode_list_invite_info(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_matrix_event(MatrixEvent self, SseSerializer serializer);
+  List<Participant> sse_decode_list_participant(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  List<RoomMemberInfo> sse_decode_list_room_member_info(
+      SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  List<RoomSummary> sse_decode_list_room_summary(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  List<SpaceHierarchyEntry> sse_decode_list_space_hierarchy_entry(
+      SseDeserializer deserializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  List<TimelineItem> sse_decode_list_timeline_item(
+      SseDeserializer deserializer);
+
+  @protected
+  LoginResult sse_decode_login_result(SseDeserializer deserializer);
+
+  @protected
+  MediaCacheConfig sse_decode_media_cache_config(SseDeserializer deserializer);
+
+  @protected
+  MemberState sse_decode_member_state(SseDeserializer deserializer);
+
+  @protected
+  MessageKind sse_decode_message_kind(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  Attachment? sse_decode_opt_box_autoadd_attachment(
+      SseDeserializer deserializer);
+
+  @protected
+  BridgeError? sse_decode_opt_box_autoadd_bridge_error(
+      SseDeserializer deserializer);
+
+  @protected
+  CallOptions? sse_decode_opt_box_autoadd_call_options(
+      SseDeserializer deserializer);
+
+  @protected
+  CancelToken? sse_decode_opt_box_autoadd_cancel_token(
+      SseDeserializer deserializer);
+
+  @protected
+  InvitePolicy? sse_decode_opt_box_autoadd_invite_policy(
+      SseDeserializer deserializer);
+
+  @protected
+  PropertyMetadata? sse_decode_opt_box_autoadd_property_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  RoomSummary? sse_decode_opt_box_autoadd_room_summary(
+      SseDeserializer deserializer);
+
+  @protected
+  SlidingSyncConfig? sse_decode_opt_box_autoadd_sliding_sync_config(
+      SseDeserializer deserializer);
+
+  @protected
+  SyncFilterConfig? sse_decode_opt_box_autoadd_sync_filter_config(
+      SseDeserializer deserializer);
+
+  @protected
+  ThumbnailRequest? sse_decode_opt_box_autoadd_thumbnail_request(
+      SseDeserializer deserializer);
+
+  @protected
+  TimelineItem? sse_decode_opt_box_autoadd_timeline_item(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  Participant sse_decode_participant(SseDeserializer deserializer);
+
+  @protected
+  ParticipantRole sse_decode_participant_role(SseDeserializer deserializer);
+
+  @protected
+  PropertyMetadata sse_decode_property_metadata(SseDeserializer deserializer);
+
+  @protected
+  RoomListChange sse_decode_room_list_change(SseDeserializer deserializer);
+
+  @protected
+  RoomListUpdate sse_decode_room_list_update(SseDeserializer deserializer);
+
+  @protected
+  RoomMemberInfo sse_decode_room_member_info(SseDeserializer deserializer);
+
+  @protected
+  RoomMembership sse_decode_room_membership(SseDeserializer deserializer);
+
+  @protected
+  RoomSummary sse_decode_room_summary(SseDeserializer deserializer);
+
+  @protected
+  SendState sse_decode_send_state(SseDeserializer deserializer);
+
+  @protected
+  SlidingSyncConfig sse_decode_sliding_sync_config(
+      SseDeserializer deserializer);
+
+  @protected
+  SpaceHierarchyEntry sse_decode_space_hierarchy_entry(
+      SseDeserializer deserializer);
+
+  @protected
+  StoreRecoveryEvent sse_decode_store_recovery_event(
+      SseDeserializer deserializer);
+
+  @protected
+  StoreRecoveryStage sse_decode_store_recovery_stage(
+      SseDeserializer deserializer);
+
+  @protected
+  SyncFilterConfig sse_decode_sync_filter_config(SseDeserializer deserializer);
+
+  @protected
+  SyncState sse_decode_sync_state(SseDeserializer deserializer);
+
+  @protected
+  SyncStatus sse_decode_sync_status(SseDeserializer deserializer);
+
+  @protected
+  ThumbnailRequest sse_decode_thumbnail_request(SseDeserializer deserializer);
+
+  @protected
+  TimelineDirection sse_decode_timeline_direction(SseDeserializer deserializer);
+
+  @protected
+  TimelineItem sse_decode_timeline_item(SseDeserializer deserializer);
+
+  @protected
+  TimelinePage sse_decode_timeline_page(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_account_timeline_item_Sse(
+      RustStreamSink<AccountTimelineItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_invite_info_Sse(
+      RustStreamSink<InviteInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_room_list_update_Sse(
+      RustStreamSink<RoomListUpdate> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_store_recovery_event_Sse(
+      RustStreamSink<StoreRecoveryEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_sync_status_Sse(
+      RustStreamSink<SyncStatus> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_timeline_item_Sse(
+      RustStreamSink<TimelineItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_account_timeline_item(
+      AccountTimelineItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_attachment(Attachment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_attachment(
+      Attachment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bridge_error(
+      BridgeError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_call_options(
+      CallOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_cancel_token(
+      CancelToken self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_client_handle(
+      ClientHandle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_group_room_config(
+      GroupRoomConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_invite_policy(
+      InvitePolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_login_result(
+      LoginResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_media_cache_config(
+      MediaCacheConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_property_metadata(
+      PropertyMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_room_summary(
+      RoomSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_sliding_sync_config(
+      SlidingSyncConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_sync_filter_config(
+      SyncFilterConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_thumbnail_request(
+      ThumbnailRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_timeline_item(
+      TimelineItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bridge_error(BridgeError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bridge_error_kind(
+      BridgeErrorKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_call_options(CallOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cancel_token(CancelToken self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_client_handle(ClientHandle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_client_info(ClientInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_direct_room(DirectRoom self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_room_config(
+      GroupRoomConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_invite_info(InviteInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_invite_policy(InvitePolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_invite_policy_kind(
+      InvitePolicyKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_client_info(
+      List<ClientInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_invite_info(
+      List<InviteInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_participant(
+      List<Participant> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_room_member_info(
+      List<RoomMemberInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_room_summary(
+      List<RoomSummary> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_space_hierarchy_entry(
+      List<SpaceHierarchyEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_timeline_item(
+      List<TimelineItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_login_result(LoginResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_cache_config(
+      MediaCacheConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_member_state(MemberState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_message_kind(MessageKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_attachment(
+      Attachment? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_bridge_error(
+      BridgeError? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_call_options(
+      CallOptions? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_cancel_token(
+      CancelToken? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_invite_policy(
+      InvitePolicy? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_property_metadata(
+      PropertyMetadata? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_room_summary(
+      RoomSummary? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_sliding_sync_config(
+      SlidingSyncConfig? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_sync_filter_config(
+      SyncFilterConfig? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_thumbnail_request(
+      ThumbnailRequest? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_timeline_item(
+      TimelineItem? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+      Uint8List? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_participant(Participant self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_participant_role(
+      ParticipantRole self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_property_metadata(
+      PropertyMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_list_change(
+      RoomListChange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_list_update(
+      RoomListUpdate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_member_info(
+      RoomMemberInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_membership(
+      RoomMembership self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_room_summary(RoomSummary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_send_state(SendState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sliding_sync_config(
+      SlidingSyncConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_space_hierarchy_entry(
+      SpaceHierarchyEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_store_recovery_event(
+      StoreRecoveryEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_store_recovery_stage(
+      StoreRecoveryStage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_filter_config(
+      SyncFilterConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_state(SyncState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_status(SyncStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_thumbnail_request(
+      ThumbnailRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_timeline_direction(
+      TimelineDirection self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_timeline_item(TimelineItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_timeline_page(TimelinePage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -0,0 +1,81 @@
+// Retry delays for the sync loop.
+//
+// Failed syncs are retried with exponential backoff. Half of each delay is
+// random, so the clients that lost the homeserver at the same moment (e.g.
+// when it restarts) don't all come back at the same moment too. A server that
+// rate limits us gets exactly the pause it asked for, plus a little jitter.
+
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::bridge::{BridgeError, BridgeErrorKind};
+
+/// First retry after the homeserver couldn't be reached, and the cap for later ones
+const NETWORK_BASE: Duration = Duration::from_secs(1);
+const NETWORK_MAX: Duration = Duration::from_secs(60);
+/// Same for errors the server returned; those usually take longer to go away
+const SERVER_BASE: Duration = Duration::from_secs(2);
+const SERVER_MAX: Duration = Duration::from_secs(300);
+
+/// How long to wait before retrying after the `attempt`th failed sync in a row (starting at 1).
+pub(crate) fn retry_delay(attempt: u32, error: &BridgeError) -> Duration {
+    if error.kind == BridgeErrorKind::RateLimited {
+        if let Some(retry_after) = error.retry_after_ms {
+            let retry_after = Duration::from_millis(retry_after);
+            return retry_after + jitter(retry_after / 10);
+        }
+    }
+    let (base, max) = match error.kind {
+        BridgeErrorKind::NetworkUnavailable => (NETWORK_BASE, NETWORK_MAX),
+        _ => (SERVER_BASE, SERVER_MAX),
+    };
+    let delay = base.saturating_mul(1 << attempt.saturating_sub(1).min(16)).min(max);
+    delay / 2 + jitter(delay / 2)
+}
+
+/// A random duration up to `max`.
+fn jitter(max: Duration) -> Duration {
+    Duration::from_millis(rand::thread_rng().gen_range(0..=max.as_millis() as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(kind: BridgeErrorKind) -> BridgeError {
+        BridgeError::new(kind, "test")
+    }
+
+    #[test]
+    fn delays_double_up_to_the_cap() {
+        let network = error(BridgeErrorKind::NetworkUnavailable);
+        for (attempt, full) in [(1, 1), (2, 2), (3, 4), (7, 60), (100, 60)] {
+            let delay = retry_delay(attempt, &network);
+            let full = Duration::from_secs(full);
+            assert!(delay >= full / 2 && delay <= full, "attempt {attempt}: {delay:?}");
+        }
+    }
+
+    #[test]
+    fn server_errors_wait_longer() {
+        let server = error(BridgeErrorKind::Forbidden);
+        for (attempt, full) in [(1, 2), (2, 4), (9, 300), (u32::MAX, 300)] {
+            let delay = retry_delay(attempt, &server);
+            let full = Duration::from_secs(full);
+            assert!(delay >= full / 2 && delay <= full, "attempt {attempt}: {delay:?}");
+        }
+    }
+
+    #[test]
+    fn rate_limits_wait_as_long_as_asked() {
+        let mut rate_limited = error(BridgeErrorKind::RateLimited);
+        rate_limited.retry_after_ms = Some(10_000);
+        let delay = retry_delay(1, &rate_limited);
+        assert!(delay >= Duration::from_secs(10) && delay <= Duration::from_secs(11), "{delay:?}");
+
+        // Without a hint it's backed off like any server error
+        rate_limited.retry_after_ms = None;
+        assert!(retry_delay(1, &rate_limited) <= SERVER_BASE);
+    }
+}
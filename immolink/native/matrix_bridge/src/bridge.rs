@@ -1,73 +1,677 @@
 use flutter_rust_bridge::frb;
 use crate::frb_generated::StreamSink;
-use matrix_sdk::{Client, config::SyncSettings, room::Room};
-use matrix_sdk::ruma::{RoomId, OwnedEventId};
+use matrix_sdk::{Client, RoomState, room::Room};
+use matrix_sdk::deserialized_responses::TimelineEvent;
+use matrix_sdk::ruma::{RoomId, OwnedEventId, UInt, UserId};
+use matrix_sdk::ruma::serde::Raw;
+use matrix_sdk::ruma::events::{AnySyncMessageLikeEvent, AnySyncTimelineEvent, SyncMessageLikeEvent};
+use matrix_sdk::ruma::events::room::MediaSource;
 use matrix_sdk::ruma::api::client::receipt::create_receipt::v3::ReceiptType;
 use matrix_sdk::ruma::events::receipt::ReceiptThread;
-use matrix_sdk::ruma::events::room::message::{RoomMessageEventContent, OriginalSyncRoomMessageEvent, MessageType};
-use matrix_sdk::ruma::events::room::member::StrippedRoomMemberEvent;
+use matrix_sdk::ruma::events::room::message::{RoomMessageEventContent, OriginalSyncRoomMessageEvent, MessageType, Relation};
 use serde::{Deserialize, Serialize};
 use once_cell::sync::OnceCell;
-use tokio::task::JoinHandle;
 use tokio::runtime::Runtime;
+use tokio::sync::watch;
 use url::Url;
-use std::sync::Mutex;
+use std::sync::Arc;
+use crate::calls;
+use crate::clients::{self, ClientState, DataDirClaim, SyncTask};
+use crate::direct_rooms;
+use crate::group_rooms;
+use crate::invites;
+use crate::property_state;
+use crate::room_list;
+use crate::spaces;
+use crate::store_encryption::{self, StoreEncryption};
+use crate::store_recovery;
+use crate::sync_loop;
+use crate::media_cache;
+use crate::members;
 
 static TOKIO_RT: OnceCell<Runtime> = OnceCell::new();
-static CLIENT: OnceCell<Client> = OnceCell::new();
-static SYNC_HANDLE: OnceCell<Mutex<Option<JoinHandle<()>>>> = OnceCell::new();
-static EVENT_SINK: OnceCell<Mutex<Option<StreamSink<MatrixEvent>>>> = OnceCell::new();
 
+/// What kind of message a timeline item carries (the `msgtype` of `m.room.message`).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageKind {
+    Text,
+    Notice,
+    Emote,
+    Image,
+    File,
+    Video,
+    Audio,
+    Location,
+    /// A msgtype the bridge doesn't model; `body` still holds the fallback text
+    Other,
+    /// An encrypted event we don't have the keys for (see `decryption_error`)
+    UnableToDecrypt,
+}
+
+/// Delivery state of a timeline item.
+/// Events coming from the server are always `Sent`; `Sending` and `Failed` are for local echoes.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SendState {
+    Sending,
+    Sent,
+    Failed,
+}
+
+/// Media attached to an image/file/video/audio message.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Attachment {
+    /// mxc:// URI of the (possibly encrypted) media
+    pub url: String,
+    /// JSON of the event's media source (`{"url":..}` or `{"file":{..}}`), needed to fetch and decrypt it
+    pub source: String,
+    pub is_encrypted: bool,
+    pub filename: String,
+    pub mimetype: Option<String>,
+    pub size: Option<u64>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub duration_ms: Option<u64>,
+    /// JSON media source of the thumbnail, same shape as `source`
+    pub thumbnail_source: Option<String>,
+}
+
+/// A room message as shown in the timeline, shared by history and the live event stream.
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct MatrixEvent {
+pub struct TimelineItem {
     pub room_id: String,
     pub event_id: String,
     pub sender: String,
-    pub ts: i64,
-    pub content: Option<String>,
+    /// origin_server_ts in milliseconds since the Unix epoch
+    pub timestamp_ms: i64,
+    pub kind: MessageKind,
+    /// Plain text body with any reply fallback stripped
+    pub body: String,
+    /// org.matrix.custom.html body, if the sender provided one
+    pub formatted_body: Option<String>,
+    /// True if the event was sent encrypted (whether or not we could decrypt it)
     pub is_encrypted: bool,
+    /// Why decryption failed, only set for `MessageKind::UnableToDecrypt`
+    pub decryption_error: Option<String>,
+    /// Set when this event is an edit; body/kind then hold the new content for that event
+    pub replaces_event_id: Option<String>,
+    /// Event this message replies to
+    pub in_reply_to: Option<String>,
+    pub attachment: Option<Attachment>,
+    pub send_state: SendState,
+}
+
+/// Which way to paginate through a room's history.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub enum TimelineDirection {
+    /// From newer to older events (scrolling up)
+    Backward,
+    /// From older to newer events
+    Forward,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TimelinePage {
+    /// Messages in chronological order
+    pub messages: Vec<TimelineItem>,
+    /// Continuation token for the next page in the same direction, None when there is nothing further
+    pub next_token: Option<String>,
+    /// True once backward pagination has reached the beginning of the room
+    pub reached_start: bool,
+}
+
+/// Size of a server-generated thumbnail to fetch instead of the full media.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct ThumbnailRequest {
+    pub width: u32,
+    pub height: u32,
+    /// Crop to exactly width x height instead of scaling to fit
+    pub crop: bool,
+}
+
+/// Limits for the on-disk media cache under the client's data_dir.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct MediaCacheConfig {
+    /// Least recently used files are evicted once the cache grows past this
+    pub max_total_bytes: u64,
+    /// Media larger than this is not downloaded through the cache. Media whose size is known up
+    /// front isn't downloaded at all, other media only once
+    pub max_file_bytes: u64,
 }
 
-#[derive(Serialize, Deserialize)]
+/// The logged-in session. Keep it somewhere safe to pass back to `restore_session` later.
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct LoginResult {
     pub user_id: String,
+    pub device_id: String,
     pub access_token: String,
+    /// Only set if the homeserver issued a refresh token
+    pub refresh_token: Option<String>,
+}
+
+/// What went wrong, for the app to branch on (and localise) instead of parsing messages.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BridgeErrorKind {
+    /// The client handle is unknown: never initialized, or already disposed
+    NotInitialized,
+    /// The client has no session, or the server no longer accepts its access token
+    NotLoggedIn,
+    /// The room isn't known locally, or the server doesn't know it (M_NOT_FOUND)
+    RoomNotFound,
+    /// The server refused the request (M_FORBIDDEN), e.g. for lack of power level
+    Forbidden,
+    /// Too many requests; wait `retry_after_ms` (if the server said) before trying again
+    RateLimited,
+    /// The homeserver couldn't be reached
+    NetworkUnavailable,
+    /// The store belongs to another account or device and couldn't be recovered
+    CryptoStoreMismatch,
+    /// A Matrix ID, URL, MIME type or media source didn't parse
+    InvalidId,
+    /// The store passphrase is missing or doesn't open the store
+    WrongPassphrase,
+    /// A live client (or an `init` still opening it) has the store open
+    StoreInUse,
+    /// The call was cancelled through its `CancelToken`
+    Cancelled,
+    /// The call ran past its `CallOptions.timeout_ms`
+    TimedOut,
+    /// Anything else; see `message`
+    Other,
+}
+
+/// Error returned by every bridge function. A struct with a plain kind enum rather than a
+/// data-carrying enum, so it maps onto a simple Dart exception class.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[frb(dart_code = "
+  @override
+  String toString() => 'BridgeError(${kind.name}): $message';
+")]
+pub struct BridgeError {
+    pub kind: BridgeErrorKind,
+    /// Developer-facing description, not meant for display
+    pub message: String,
+    /// Only set for `RateLimited`, when the server says how long to back off
+    pub retry_after_ms: Option<u64>,
+}
+
+/// Refers to a client created by `init`. Valid until `dispose_client` or `logout`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClientHandle {
+    pub id: u32,
+}
+
+/// The user's membership of a room in the room list.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoomMembership {
+    Joined,
+    Invited,
+    Left,
+}
+
+/// One entry of the room list.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RoomSummary {
+    pub room_id: String,
+    /// Room name, or one computed from the members as the spec describes
+    pub display_name: String,
+    /// mxc:// URI, fetch it with `fetch_media`
+    pub avatar_url: Option<String>,
+    pub membership: RoomMembership,
+    pub is_encrypted: bool,
+    pub is_direct: bool,
+    /// User IDs of joined and invited members known locally; may be incomplete for large rooms
+    pub members: Vec<String>,
+    pub joined_members: u64,
+    pub invited_members: u64,
+    /// None for invites, and for rooms with no message in their recent history
+    pub latest_message: Option<TimelineItem>,
+    /// None for rooms not tied to a property
+    pub property: Option<PropertyMetadata>,
+    /// Unread notifications, as counted by the server
+    pub unread_count: u64,
+    /// Unread notifications that mention the user or match a highlight rule
+    pub highlight_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoomListChange {
+    /// The room is new to the list or something about it changed
+    Updated,
+    /// The user left the room or was kicked or banned from it
+    Removed,
+}
+
+/// A change to the room list, pushed after each sync.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RoomListUpdate {
+    pub room_id: String,
+    pub change: RoomListChange,
+    /// The new summary; None for `Removed`
+    pub room: Option<RoomSummary>,
+}
+
+/// What the sync loop of a client is doing.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncState {
+    /// Not syncing: never started, or stopped with `stop_sync`
+    Idle,
+    /// Started and catching up on what happened while the client was away
+    InitialSync,
+    /// Up to date and waiting for new events
+    Running,
+    /// The last sync failed (`error` has a `RateLimited` kind if the server is throttling us);
+    /// the next attempt is at `next_retry_ms`
+    Backoff,
+    /// The homeserver can't be reached; the next attempt is at `next_retry_ms`
+    Offline,
+    /// The server no longer accepts the access token. Sync has stopped; log in again
+    /// (or restore a session) and start it again
+    SessionExpired,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SyncStatus {
+    pub state: SyncState,
+    /// Failed syncs in a row, for `Backoff` and `Offline`
+    pub attempt: u32,
+    /// Unix time in ms of the next attempt, for `Backoff` and `Offline`
+    pub next_retry_ms: Option<u64>,
+    /// Why the last sync failed
+    pub error: Option<BridgeError>,
+}
+
+/// A room or subspace in a space, from `get_space_hierarchy`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SpaceHierarchyEntry {
+    pub room_id: String,
+    /// The space listing this room; None for the space the hierarchy was asked for
+    pub parent_id: Option<String>,
+    pub name: Option<String>,
+    pub topic: Option<String>,
+    /// mxc:// URI, fetch it with `fetch_media`
+    pub avatar_url: Option<String>,
+    pub is_space: bool,
+    pub joined_members: u64,
+    /// Whether we're in the room
+    pub joined: bool,
+}
+
+/// What someone does in a property, deciding their power level in its group room.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParticipantRole {
+    /// Manages the room and everyone's roles (power level 100)
+    Landlord,
+    /// Manages the room: name, topic, avatar and members (50)
+    Manager,
+    /// Posts, like a tenant (10)
+    Caretaker,
+    /// Posts (0)
+    Tenant,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Participant {
+    pub user_id: String,
+    pub role: ParticipantRole,
+}
+
+/// A group room for `create_group_room`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct GroupRoomConfig {
+    pub name: String,
+    pub topic: Option<String>,
+    /// Image for the room avatar, uploaded with the room
+    pub avatar: Option<Vec<u8>>,
+    /// MIME type of `avatar`, e.g. `image/png`
+    pub avatar_mime_type: Option<String>,
+    /// Everyone to invite, with their role. The creator gets the landlord's level whatever their role
+    pub participants: Vec<Participant>,
+    /// Property the room is about, stored with the room
+    pub property: Option<PropertyMetadata>,
+}
+
+/// The property and tenancy a room is about, kept in its `ch.immosync.property` state event.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PropertyMetadata {
+    pub property_id: String,
+    pub address: Option<String>,
+    pub tenancy_id: Option<String>,
+    /// Everyone's role in the property. Roles only inform the app; power levels aren't changed
+    pub participants: Vec<Participant>,
+}
+
+/// A member's membership of a room, for `get_room_members`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemberState {
+    Joined,
+    Invited,
+    /// Left, or was kicked
+    Left,
+    Banned,
+    /// Asked to join
+    Knocked,
+}
+
+/// One member of a room, from `get_room_members`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RoomMemberInfo {
+    pub user_id: String,
+    pub display_name: Option<String>,
+    /// mxc:// URI, fetch it with `fetch_media`
+    pub avatar_url: Option<String>,
+    pub membership: MemberState,
+    /// 100 for admins, 50 for moderators, 0 for everyone else by default
+    pub power_level: i64,
+}
+
+/// Which invites a client joins without asking; see `InvitePolicy`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvitePolicyKind {
+    /// Join every invite
+    AcceptAll,
+    /// Join invites from `allowed_users`, or from any user on `allowed_servers`
+    AcceptAllowlisted,
+    /// Join invites to rooms carrying property metadata (`ch.immosync.property` state)
+    AcceptPropertyRooms,
+    /// Join nothing; every invite goes to `subscribe_invites`
+    Manual,
+}
+
+/// Invite policy for `init`. Invites it doesn't accept stay pending and go to `subscribe_invites`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InvitePolicy {
+    pub kind: InvitePolicyKind,
+    /// Full Matrix user IDs, for `AcceptAllowlisted`
+    pub allowed_users: Vec<String>,
+    /// Server names (e.g. `matrix.immosync.ch`), for `AcceptAllowlisted`
+    pub allowed_servers: Vec<String>,
+}
+
+/// A pending invite with what the invite tells about the room.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InviteInfo {
+    pub room_id: String,
+    /// Room name, or one computed from the members the invite shows
+    pub display_name: String,
+    /// mxc:// URI, fetch it with `fetch_media`
+    pub avatar_url: Option<String>,
+    pub topic: Option<String>,
+    pub is_direct: bool,
+    /// Only known before joining if the server shares `ch.immosync.property` in invites
+    pub property: Option<PropertyMetadata>,
+    /// User ID of whoever sent the invite
+    pub inviter: Option<String>,
+    pub inviter_display_name: Option<String>,
+    pub inviter_avatar_url: Option<String>,
+}
+
+/// What classic sync should leave out, for `set_sync_filter`. Everything left out saves mobile data.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SyncFilterConfig {
+    /// Only send the members who sent the synced events, not every member of every room
+    pub lazy_load_members: bool,
+    /// Timeline events per room and sync; None for the server's default
+    pub timeline_limit: Option<u32>,
+    /// Online status of other users
+    pub include_presence: bool,
+    /// Global and per-room account data. Needed to tell DMs apart (`m.direct`)
+    pub include_account_data: bool,
+    /// Typing notifications and read receipts
+    pub include_ephemeral: bool,
+}
+
+/// Sliding sync (MSC3575) setup for `init`. Instead of every room, only a window of the room list
+/// (most recently active first) is synced, with a few timeline events each, so a cold start with
+/// hundreds of rooms is quick. If the server doesn't support it, sync falls back to classic sync.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SlidingSyncConfig {
+    /// Sliding sync proxy to use; None for a homeserver that supports sliding sync natively
+    pub proxy_url: Option<String>,
+    /// Rooms synced at first, from the top of the room list; `set_visible_rooms` moves the window
+    pub window_size: u32,
+    /// Timeline events synced per room in the window
+    pub timeline_limit: u32,
+}
+
+/// A direct message room from `get_or_create_dm`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DirectRoom {
+    pub room_id: String,
+    /// False if an existing DM was reused
+    pub created: bool,
+}
+
+/// Cancels the calls it is passed to (in `CallOptions`). Create one per screen or operation with
+/// `new_cancel_token`, and `release_cancel_token` it once done, whether `cancel_calls` was used or not.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CancelToken {
+    pub id: u64,
+}
+
+/// Per-call limits for the functions that talk to the homeserver.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct CallOptions {
+    /// Fail with `TimedOut` after this long; None uses `set_default_timeout`
+    pub timeout_ms: Option<u64>,
+    pub cancel: Option<CancelToken>,
+}
+
+fn client_state(handle: ClientHandle) -> Result<Arc<ClientState>, BridgeError> {
+    clients::get(handle.id)
+}
+
+/// A live timeline item tagged with the account it arrived on.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountTimelineItem {
+    pub client: ClientHandle,
+    /// Matrix user ID of the account, empty if it isn't logged in
+    pub user_id: String,
+    pub item: TimelineItem,
+}
+
+/// A live client, as listed by `list_clients`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ClientInfo {
+    pub handle: ClientHandle,
+    pub user_id: Option<String>,
+    pub device_id: Option<String>,
+    pub homeserver: String,
+    pub data_dir: String,
+    pub is_syncing: bool,
 }
 
-/// Subscribe a Dart StreamSink to receive Matrix events.
+/// Progress of recovering from a store that belongs to another account or device.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoreRecoveryStage {
+    /// The old store was moved aside and a fresh one built; its keys wait for the next login
+    Quarantined,
+    /// The room keys of the old store were imported into the new one
+    KeysImported,
+    /// The backup couldn't be imported; the keys are still in the backup file and the old store
+    KeyImportFailed,
+}
+
+/// Reported on `subscribe_store_recovery` whenever a mismatched store is recovered.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StoreRecoveryEvent {
+    pub client: ClientHandle,
+    pub stage: StoreRecoveryStage,
+    /// The SDK error that triggered the recovery
+    pub reason: String,
+    /// Where the old store's database files now live; nothing in it is deleted
+    pub quarantined_dir: String,
+    /// Room key export of the old store, encrypted with the store passphrase (or a one-time one
+    /// kept until the import if the store has none). None if the export failed. Deleted once the
+    /// keys are imported
+    pub key_backup_file: Option<String>,
+    pub exported_keys: u64,
+    pub imported_keys: u64,
+    pub error: Option<String>,
+}
+
+/// Subscribe a Dart StreamSink to receive live timeline items of a client.
+#[frb]
+pub fn subscribe_events(client: ClientHandle, sink: StreamSink<TimelineItem>) -> Result<(), BridgeError> {
+    let state = client_state(client)?;
+    let mut guard = state.event_sink.lock().map_err(|_| "failed to lock event sink".to_string())?;
+    *guard = Some(sink);
+    Ok(())
+}
+
+/// Subscribe a Dart StreamSink to changes of the client's room list (see `list_rooms`), sent as sync
+/// updates rooms.
+#[frb]
+pub fn subscribe_room_list(client: ClientHandle, sink: StreamSink<RoomListUpdate>) -> Result<(), BridgeError> {
+    let state = client_state(client)?;
+    let mut guard = state.room_list_sink.lock().map_err(|_| "failed to lock room list sink".to_string())?;
+    *guard = Some(sink);
+    Ok(())
+}
+
+/// Subscribe a Dart StreamSink to the invites of a client that its invite policy didn't accept,
+/// as they come in. `list_invites` has the ones pending from before.
+#[frb]
+pub fn subscribe_invites(client: ClientHandle, sink: StreamSink<InviteInfo>) -> Result<(), BridgeError> {
+    let state = client_state(client)?;
+    let mut guard = state.invite_sink.lock().map_err(|_| "failed to lock invite sink".to_string())?;
+    *guard = Some(sink);
+    Ok(())
+}
+
+/// Subscribe a Dart StreamSink to the sync status of a client. The current status is sent right away.
 #[frb]
-pub fn subscribe_events(sink: StreamSink<MatrixEvent>) -> Result<(), String> {
-    let cell = EVENT_SINK.get_or_init(|| Mutex::new(None));
-    let mut guard = cell.lock().map_err(|_| "failed to lock event sink".to_string())?;
+pub fn subscribe_sync_status(client: ClientHandle, sink: StreamSink<SyncStatus>) -> Result<(), BridgeError> {
+    let state = client_state(client)?;
+    let mut guard = state.sync_status_sink.lock().map_err(|_| "failed to lock sync status sink".to_string())?;
+    if let Err(e) = sink.add(state.sync_status()) {
+        eprintln!("[Bridge][sync] Failed to emit sync status: {:?}", e);
+    }
     *guard = Some(sink);
     Ok(())
 }
 
-fn get_rt() -> &'static Runtime {
+#[frb]
+pub fn get_sync_status(client: ClientHandle) -> Result<SyncStatus, BridgeError> {
+    Ok(client_state(client)?.sync_status())
+}
+
+/// Subscribe a Dart StreamSink to the live timeline items of every client, tagged with their account.
+/// This is what a unified inbox listens to; it works alongside the per-client `subscribe_events`.
+#[frb]
+pub fn subscribe_all_events(sink: StreamSink<AccountTimelineItem>) -> Result<(), BridgeError> {
+    clients::set_all_accounts_sink(sink);
+    Ok(())
+}
+
+/// Subscribe a Dart StreamSink to store recovery events of all clients.
+#[frb]
+pub fn subscribe_store_recovery(sink: StreamSink<StoreRecoveryEvent>) -> Result<(), BridgeError> {
+    clients::set_store_recovery_sink(sink);
+    Ok(())
+}
+
+pub(crate) fn get_rt() -> &'static Runtime {
     TOKIO_RT.get_or_init(|| {
         Runtime::new().expect("Failed to create Tokio runtime")
     })
 }
 
+#[frb(sync)]
+pub fn new_cancel_token() -> CancelToken {
+    calls::new_token()
+}
+
+/// Cancel every call started with `token`; they fail with `Cancelled`, and so do calls passed it
+/// later, until it is released. Does nothing to a released token.
+#[frb(sync)]
+pub fn cancel_calls(token: CancelToken) {
+    calls::cancel(token)
+}
+
+/// Drop a token that is no longer needed, leaving its running calls alone. Calls passed it later
+/// run as if they had no token.
+#[frb(sync)]
+pub fn release_cancel_token(token: CancelToken) {
+    calls::release(token)
+}
+
+/// Timeout for calls whose `CallOptions` don't set one. None (the default) means no timeout
+/// beyond the SDK's own per-request HTTP timeout.
+#[frb(sync)]
+pub fn set_default_timeout(timeout_ms: Option<u64>) {
+    calls::set_default_timeout(timeout_ms)
+}
+
+/// Delete the store in `data_dir`, which must not be open. Only the SDK's database files go; the
+/// media cache, other accounts' stores and recovery files stay.
 #[frb]
-pub fn clear_store(data_dir: String) -> Result<(), String> {
-    use std::fs;
+pub fn clear_store(data_dir: String) -> Result<(), BridgeError> {
     eprintln!("[Bridge][clear_store] Clearing Matrix store at: {}", data_dir);
     let store_path = std::path::Path::new(&data_dir);
-    if store_path.exists() {
-        fs::remove_dir_all(store_path).map_err(|e| format!("Failed to clear store: {}", e))?;
-        eprintln!("[Bridge][clear_store] Store cleared successfully");
-    } else {
-        eprintln!("[Bridge][clear_store] Store directory doesn't exist, nothing to clear");
-    }
+    let _reservation = match clients::claim_data_dir(store_path) {
+        DataDirClaim::Reserved(reservation) => reservation,
+        DataDirClaim::Live(id, _) => {
+            return Err(BridgeError::new(
+                BridgeErrorKind::StoreInUse,
+                format!("Store {} is in use by client handle {}; dispose it first", data_dir, id),
+            ));
+        }
+        DataDirClaim::Busy => {
+            return Err(BridgeError::new(
+                BridgeErrorKind::StoreInUse,
+                format!("Store {} is being opened by another call", data_dir),
+            ));
+        }
+    };
+    store_encryption::remove_store(store_path)?;
+    eprintln!("[Bridge][clear_store] Store cleared successfully");
     Ok(())
 }
 
+/// Build a client on the store in `data_dir` and return its handle.
+/// Calling it again for a store that already has a live client returns that client's handle;
+/// to switch homeserver on the same store, `dispose_client` the old one first. A call for a store
+/// another `init` is still opening fails.
+/// With a `passphrase` the store is encrypted (an existing plaintext store is encrypted in place);
+/// it has to be the same on every start, see `rekey_store` to change it.
+/// With `sliding_sync` the client syncs with sliding sync, see `SlidingSyncConfig`. Calling `init`
+/// again on a live client replaces that setting, effective from the next `start_sync`.
+/// `invite_policy` decides which invites are joined without asking; without one, all are.
+/// A repeated `init` replaces it right away.
 #[frb]
-pub fn init(homeserver: String, data_dir: String) -> Result<(), String> {
-    let url = Url::parse(&homeserver).map_err(|e| e.to_string())?;
-    let rt = get_rt();
-    rt.block_on(async move {
+pub async fn init(
+    homeserver: String,
+    data_dir: String,
+    passphrase: Option<String>,
+    sliding_sync: Option<SlidingSyncConfig>,
+    invite_policy: Option<InvitePolicy>,
+    options: Option<CallOptions>,
+) -> Result<ClientHandle, BridgeError> {
+    let url = Url::parse(&homeserver)?;
+    let invite_policy = invite_policy.unwrap_or_else(InvitePolicy::accept_all);
+    // Held until the client is registered, so a concurrent `init` can't open the store too
+    let reservation = match clients::claim_data_dir(std::path::Path::new(&data_dir)) {
+        DataDirClaim::Reserved(reservation) => reservation,
+        DataDirClaim::Live(id, state) if state.client.homeserver() == url => {
+            eprintln!("[Bridge][init] Client for {} already initialized (handle {})", data_dir, id);
+            state.set_sliding_sync_config(sliding_sync);
+            state.set_invite_policy(invite_policy);
+            return Ok(ClientHandle { id });
+        }
+        DataDirClaim::Live(id, state) => {
+            return Err(format!(
+                "Store {} is in use by a client for {} (handle {}); dispose it first",
+                data_dir,
+                state.client.homeserver(),
+                id
+            )
+            .into());
+        }
+        DataDirClaim::Busy => {
+            return Err(format!("Store {} is being opened by another init call", data_dir).into());
+        }
+    };
+    calls::run("init", options, async move {
         eprintln!("[Bridge][init] Initializing Matrix client with persistent storage at: {}", data_dir);
         
         // Use SQLite store for persistent state, crypto keys, AND session
@@ -75,36 +679,45 @@ pub fn init(homeserver: String, data_dir: String) -> Result<(), String> {
         
         // IMPORTANT: For session persistence to work, we need to:
         // 1. Create the store directory if it doesn't exist
-        // 2. Use the same passphrase (None for no encryption) consistently
+        // 2. Use the same passphrase consistently
         // 3. The SDK will automatically load the session from the store on build
         
         std::fs::create_dir_all(store_path).map_err(|e| format!("Failed to create store directory: {}", e))?;
-        
-        // Try to build client, if device mismatch occurs, clear store and retry
-        let client = match Client::builder()
-            .homeserver_url(url.clone())
-            .sqlite_store(store_path, None) // None = no passphrase encryption
-            .build()
-            .await
-        {
+        clear_logged_out_store(store_path)?;
+
+        // Opening a plaintext store with a passphrase (or the other way round) would make the
+        // SDK misread every row, so settle that first
+        match (store_encryption::store_encryption(store_path)?, &passphrase) {
+            (StoreEncryption::Plain, Some(passphrase)) => {
+                eprintln!("[Bridge][init] Encrypting existing plaintext store");
+                store_encryption::encrypt_plain_store(store_path, passphrase)?;
+            }
+            (StoreEncryption::Encrypted, None) => {
+                return Err(BridgeError::new(
+                    BridgeErrorKind::WrongPassphrase,
+                    format!("Store {} is encrypted; a passphrase is required", data_dir),
+                ));
+            }
+            _ => {}
+        }
+
+        // Try to build client; if the store belongs to another device, quarantine it and retry
+        let mut quarantine = None;
+        let client = match build_client(url.clone(), store_path, passphrase.as_deref()).await {
             Ok(c) => c,
             Err(e) => {
                 let err_str = e.to_string();
-                if err_str.contains("account in the store doesn't match") {
-                    eprintln!("[Bridge][init] Device mismatch detected during build, clearing store and retrying");
-                    if let Err(clear_err) = std::fs::remove_dir_all(store_path) {
-                        eprintln!("[Bridge][init] Warning: Failed to clear store: {}", clear_err);
-                    }
-                    // Recreate directory and retry
-                    std::fs::create_dir_all(store_path).map_err(|e| format!("Failed to recreate store directory: {}", e))?;
-                    Client::builder()
-                        .homeserver_url(url)
-                        .sqlite_store(store_path, None)
-                        .build()
-                        .await
-                        .map_err(|e| format!("Failed to build client after clearing store: {}", e))?
+                if store_recovery::is_store_mismatch(&err_str) {
+                    eprintln!("[Bridge][init] Device mismatch detected during build, quarantining store and retrying");
+                    quarantine = Some(
+                        store_recovery::quarantine_store(store_path, passphrase.as_deref(), err_str)
+                            .await
+                            .map_err(|e| BridgeError::new(BridgeErrorKind::CryptoStoreMismatch, e))?,
+                    );
+                    build_client(url, store_path, passphrase.as_deref()).await?
                 } else {
-                    return Err(format!("Failed to build client: {}", err_str));
+                    eprintln!("[Bridge][init] Failed to build client: {}", err_str);
+                    return Err(e.into());
                 }
             }
         };
@@ -129,43 +742,138 @@ pub fn init(homeserver: String, data_dir: String) -> Result<(), String> {
         }
         
         eprintln!("[Bridge][init] Client initialized with persistent store");
-        CLIENT.set(client).map_err(|_| "Client already initialized".to_string())?;
-        Ok(())
+        let id = clients::insert(client, store_path.to_path_buf(), passphrase);
+        drop(reservation);
+        if let Ok(state) = clients::get(id) {
+            state.set_sliding_sync_config(sliding_sync);
+            state.set_invite_policy(invite_policy);
+        }
+        if let Some(quarantine) = quarantine {
+            announce_quarantine(id, quarantine);
+        }
+        eprintln!("[Bridge][init] Client handle: {}", id);
+        Ok(ClientHandle { id })
     })
+    .await
 }
 
+/// Build a client for one of several accounts that can be logged in at the same time.
+/// Each account gets its own store under `<base_dir>/accounts/<account>` (e.g. the provisioned
+/// Matrix username), and is otherwise used like a client from `init`.
 #[frb]
-pub fn login(user: String, password: String) -> Result<LoginResult, String> {
-    let rt = get_rt();
-    rt.block_on(async move {
-        let client = CLIENT.get().ok_or_else(|| "Client not initialized".to_string())?.clone();
+pub async fn init_account(
+    homeserver: String,
+    base_dir: String,
+    account: String,
+    passphrase: Option<String>,
+    sliding_sync: Option<SlidingSyncConfig>,
+    invite_policy: Option<InvitePolicy>,
+    options: Option<CallOptions>,
+) -> Result<ClientHandle, BridgeError> {
+    let dir_name: String = account
+        .trim_start_matches('@')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '.' { c } else { '_' })
+        .collect();
+    if dir_name.is_empty() {
+        return Err(BridgeError::new(BridgeErrorKind::InvalidId, "Account name must not be empty"));
+    }
+    let data_dir = std::path::Path::new(&base_dir).join("accounts").join(dir_name);
+    init(homeserver, data_dir.to_string_lossy().into_owned(), passphrase, sliding_sync, invite_policy, options).await
+}
+
+/// Change the passphrase of the store in `data_dir`, which must not be open.
+/// With `old_passphrase` = None the store is a plaintext one and gets encrypted in place: message
+/// keys and the device identity are kept, cached room state is re-fetched by the next sync.
+#[frb]
+pub fn rekey_store(data_dir: String, old_passphrase: Option<String>, new_passphrase: String) -> Result<(), BridgeError> {
+    let store_path = std::path::Path::new(&data_dir);
+    let _reservation = match clients::claim_data_dir(store_path) {
+        DataDirClaim::Reserved(reservation) => reservation,
+        DataDirClaim::Live(id, _) => {
+            return Err(format!("Store {} is in use by client handle {}; dispose it first", data_dir, id).into());
+        }
+        DataDirClaim::Busy => return Err(format!("Store {} is being opened by another call", data_dir).into()),
+    };
+    match (store_encryption::store_encryption(store_path)?, old_passphrase) {
+        (StoreEncryption::Missing, _) => Err(format!("No store found at {}", data_dir).into()),
+        (StoreEncryption::Plain, None) => {
+            eprintln!("[Bridge][rekey_store] Encrypting plaintext store at {}", data_dir);
+            Ok(store_encryption::encrypt_plain_store(store_path, &new_passphrase)?)
+        }
+        (StoreEncryption::Plain, Some(_)) => Err(format!("Store {} is not encrypted", data_dir).into()),
+        (StoreEncryption::Encrypted, None) => Err(BridgeError::new(
+            BridgeErrorKind::WrongPassphrase,
+            format!("Store {} is encrypted; the old passphrase is required", data_dir),
+        )),
+        (StoreEncryption::Encrypted, Some(old)) => {
+            eprintln!("[Bridge][rekey_store] Changing passphrase of store at {}", data_dir);
+            store_encryption::change_passphrase(store_path, &old, &new_passphrase)
+        }
+    }
+}
+
+/// List every live client with the account it's logged in as.
+#[frb]
+pub fn list_clients() -> Result<Vec<ClientInfo>, BridgeError> {
+    Ok(clients::list()
+        .into_iter()
+        .map(|state| ClientInfo {
+            handle: ClientHandle { id: state.id },
+            user_id: state.client.user_id().map(|u| u.to_string()),
+            device_id: state.client.device_id().map(|d| d.to_string()),
+            homeserver: state.client.homeserver().to_string(),
+            data_dir: state.data_dir.to_string_lossy().into_owned(),
+            is_syncing: state.is_syncing(),
+        })
+        .collect())
+}
+
+/// Tear down a client: stop its sync loop, drop its event sink and release the store,
+/// so `init` can build a new client (e.g. for another homeserver or user).
+#[frb]
+pub fn dispose_client(client: ClientHandle) -> Result<(), BridgeError> {
+    match clients::remove(client.id) {
+        Some(_) => eprintln!("[Bridge][dispose_client] Disposed client handle {}", client.id),
+        None => eprintln!("[Bridge][dispose_client] Client handle {} was already disposed", client.id),
+    }
+    Ok(())
+}
+
+#[frb]
+pub async fn login(
+    client: ClientHandle,
+    user: String,
+    password: String,
+    options: Option<CallOptions>,
+) -> Result<LoginResult, BridgeError> {
+    calls::run("login", options, async move {
+        let handle_id = client.id;
+        let mut client = client_state(client)?.client.clone();
         
         // Check if already logged in (session restored from store)
         if let Some(uid) = client.user_id() {
             eprintln!("[Bridge][login] Already logged in as {}, skipping login", uid);
             eprintln!("[Bridge][login] Device ID: {:?}", client.device_id());
-            return Ok(LoginResult { 
-                user_id: uid.to_string(), 
-                access_token: String::new() 
-            });
+            return current_session(&client);
         }
         
         eprintln!("[Bridge][login] Not logged in, performing login for {}", user);
-        eprintln!("[Bridge][login] Username: {}", user);
-        eprintln!("[Bridge][login] Password length: {} chars", password.len());
-        eprintln!("[Bridge][login] Password (first 8 chars): {}...", &password[..password.len().min(8)]);
+        eprintln!("[Bridge][login] Homeserver: {}", client.homeserver());
         
         // CRITICAL: The login MUST be done with the Matrix client that has the SQLite store
         // configured, otherwise the session won't persist across restarts!
         eprintln!("[Bridge][login] Using matrix_auth().login_username()...");
         
         // Login using username & password (new API via matrix_auth)
-        match client
-            .matrix_auth()
-            .login_username(&user, &password)
-            .send()
-            .await
-        {
+        let mut result = client.matrix_auth().login_username(&user, &password).send().await;
+        let mismatch = result.as_ref().err().map(|e| e.to_string()).filter(|e| store_recovery::is_store_mismatch(e));
+        if let Some(reason) = mismatch {
+            eprintln!("[Bridge][login] Store belongs to another device, recovering it and retrying");
+            client = recover_mismatched_store(handle_id, client, reason).await?;
+            result = client.matrix_auth().login_username(&user, &password).send().await;
+        }
+        match result {
             Ok(_resp) => {
                 let uid = client
                     .user_id()
@@ -176,54 +884,322 @@ pub fn login(user: String, password: String) -> Result<LoginResult, String> {
                 eprintln!("[Bridge][login] ✓ Login successful!");
                 eprintln!("[Bridge][login] User ID: {}", uid);
                 eprintln!("[Bridge][login] Device ID: {}", device_id);
-                eprintln!("[Bridge][login] Session is now persisted in SQLite store");
-                eprintln!("[Bridge][login] On next restart, session should be auto-restored");
+                eprintln!("[Bridge][login] Returning session; pass it to restore_session after a restart");
                 
                 // Initialize encryption (Olm machine)
                 eprintln!("[Bridge][login] Initializing encryption...");
                 client.encryption().wait_for_e2ee_initialization_tasks().await;
                 eprintln!("[Bridge][login] Encryption initialized successfully");
-                
-                // Access token retrieval differs across SDK versions; keep empty for now.
-                Ok(LoginResult { user_id: uid, access_token: String::new() })
+                import_pending_room_keys(handle_id).await;
+
+                current_session(&client)
             }
             Err(e) => {
-                let err_str = e.to_string();
-                eprintln!("[Bridge][login] Login failed: {}", err_str);
-                
-                // If crypto store mismatch, the store needs to be cleared
-                if err_str.contains("account in the store doesn't match") {
-                    return Err(format!(
-                        "Device mismatch error. The crypto store has data from a different device. \
-                        Please restart the app to clear the store automatically."
-                    ));
+                eprintln!("[Bridge][login] Login failed: {}", e);
+                Err(e.into())
+            }
+        }
+    })
+    .await
+}
+
+/// Restore a session saved from `login` (or provisioned by the backend) into the client
+/// created by `init`, without a password round-trip.
+/// If the store was wiped the device's encryption keys are gone with it, so messages sent to the
+/// old keys can't be decrypted; the SDK creates fresh keys for the same device ID.
+#[frb]
+pub async fn restore_session(
+    client: ClientHandle,
+    session: LoginResult,
+    options: Option<CallOptions>,
+) -> Result<LoginResult, BridgeError> {
+    use matrix_sdk::matrix_auth::{MatrixSession, MatrixSessionTokens};
+    use matrix_sdk::ruma::{OwnedDeviceId, UserId};
+    use matrix_sdk::SessionMeta;
+
+    calls::run("restore_session", options, async move {
+        let handle_id = client.id;
+        let mut client = client_state(client)?.client.clone();
+        let user_id = UserId::parse(&session.user_id)?;
+
+        if let Some(uid) = client.user_id() {
+            if uid == user_id {
+                eprintln!("[Bridge][restore_session] Already logged in as {}, nothing to restore", uid);
+                return current_session(&client);
+            }
+            return Err(format!("Client is already logged in as {}", uid).into());
+        }
+
+        eprintln!("[Bridge][restore_session] Restoring session for {} ({})", user_id, session.device_id);
+        let matrix_session = MatrixSession {
+            meta: SessionMeta { user_id, device_id: OwnedDeviceId::from(session.device_id) },
+            tokens: MatrixSessionTokens {
+                access_token: session.access_token,
+                refresh_token: session.refresh_token,
+            },
+        };
+        let mut result = client.matrix_auth().restore_session(matrix_session.clone()).await;
+        let mismatch = result.as_ref().err().map(|e| e.to_string()).filter(|e| store_recovery::is_store_mismatch(e));
+        if let Some(reason) = mismatch {
+            eprintln!("[Bridge][restore_session] Store belongs to another device, recovering it and retrying");
+            client = recover_mismatched_store(handle_id, client, reason).await?;
+            result = client.matrix_auth().restore_session(matrix_session).await;
+        }
+        result.map_err(|e| {
+            eprintln!("[Bridge][restore_session] Failed to restore session: {}", e);
+            BridgeError::from(e)
+        })?;
+
+        client.encryption().wait_for_e2ee_initialization_tasks().await;
+        import_pending_room_keys(handle_id).await;
+        eprintln!("[Bridge][restore_session] ✓ Session restored");
+        current_session(&client)
+    })
+    .await
+}
+
+async fn build_client(
+    homeserver: Url,
+    store_path: &std::path::Path,
+    passphrase: Option<&str>,
+) -> Result<Client, matrix_sdk::ClientBuildError> {
+    Client::builder().homeserver_url(homeserver).sqlite_store(store_path, passphrase).build().await
+}
+
+/// Replace the client behind `handle_id`, whose store belongs to another account or device, with
+/// one on a fresh store. The old store is quarantined and its room keys are imported after the
+/// next successful login; the handle stays the same.
+async fn recover_mismatched_store(handle_id: u32, client: Client, reason: String) -> Result<Client, BridgeError> {
+    let old = clients::get(handle_id)?;
+    let sinks = clients::take_sinks(&old);
+    let data_dir = old.data_dir.clone();
+    let passphrase = old.passphrase.clone();
+    let sync_filter = old.sync_filter();
+    let sliding_sync = old.sliding_sync_config();
+    let invite_policy = old.invite_policy();
+    let homeserver = client.homeserver();
+    // The old client has to be gone so the store files are closed before they're moved
+    clients::remove(handle_id);
+    drop(old);
+    drop(client);
+
+    let quarantine = match store_recovery::quarantine_store(&data_dir, passphrase.as_deref(), reason).await {
+        Ok(quarantine) => quarantine,
+        Err(e) => {
+            // Put a client on the untouched store back so the handle stays usable
+            if let Ok(client) = build_client(homeserver, &data_dir, passphrase.as_deref()).await {
+                let state = clients::reinsert(handle_id, client, data_dir, passphrase, sinks);
+                state.set_sync_filter(sync_filter);
+                state.set_sliding_sync_config(sliding_sync);
+                state.set_invite_policy(invite_policy);
+            }
+            return Err(BridgeError::new(BridgeErrorKind::CryptoStoreMismatch, e));
+        }
+    };
+    let client = build_client(homeserver, &data_dir, passphrase.as_deref()).await?;
+    let state = clients::reinsert(handle_id, client.clone(), data_dir, passphrase, sinks);
+    state.set_sync_filter(sync_filter);
+    state.set_sliding_sync_config(sliding_sync);
+    state.set_invite_policy(invite_policy);
+    announce_quarantine(handle_id, quarantine);
+    Ok(client)
+}
+
+fn announce_quarantine(handle_id: u32, quarantine: store_recovery::Quarantine) {
+    clients::emit_store_recovery(store_recovery_event(handle_id, &quarantine, StoreRecoveryStage::Quarantined, 0, None));
+    if let Ok(state) = clients::get(handle_id) {
+        if let Ok(mut pending) = state.pending_key_import.lock() {
+            *pending = Some(quarantine);
+        }
+    }
+}
+
+/// Import the room keys of a quarantined store into the now logged-in client that replaced it.
+async fn import_pending_room_keys(handle_id: u32) {
+    let Ok(state) = clients::get(handle_id) else {
+        return;
+    };
+    let Some(quarantine) = state.pending_key_import.lock().ok().and_then(|mut pending| pending.take()) else {
+        return;
+    };
+    let Some(key_file) = quarantine.key_file.clone() else {
+        return;
+    };
+    let event = match state.client.encryption().import_room_keys(key_file.clone(), &quarantine.key_passphrase).await {
+        Ok(result) => {
+            eprintln!("[Bridge][recovery] Imported {} of {} room keys", result.imported_count, result.total_count);
+            // The keys are in the new store now; the quarantined store still has them too
+            if let Err(e) = std::fs::remove_file(&key_file) {
+                eprintln!("[Bridge][recovery] Failed to delete key backup {}: {}", key_file.display(), e);
+            }
+            store_recovery_event(handle_id, &quarantine, StoreRecoveryStage::KeysImported, result.imported_count, None)
+        }
+        Err(e) => {
+            eprintln!("[Bridge][recovery] Failed to import room keys: {}", e);
+            store_recovery_event(handle_id, &quarantine, StoreRecoveryStage::KeyImportFailed, 0, Some(e.to_string()))
+        }
+    };
+    clients::emit_store_recovery(event);
+}
+
+fn store_recovery_event(
+    handle_id: u32,
+    quarantine: &store_recovery::Quarantine,
+    stage: StoreRecoveryStage,
+    imported_keys: usize,
+    error: Option<String>,
+) -> StoreRecoveryEvent {
+    StoreRecoveryEvent {
+        client: ClientHandle { id: handle_id },
+        stage,
+        reason: quarantine.reason.clone(),
+        quarantined_dir: quarantine.quarantined_dir.to_string_lossy().into_owned(),
+        key_backup_file: quarantine.key_file.as_ref().map(|f| f.to_string_lossy().into_owned()),
+        exported_keys: quarantine.exported_keys as u64,
+        imported_keys: imported_keys as u64,
+        error,
+    }
+}
+
+/// Export the client's current session.
+fn current_session(client: &Client) -> Result<LoginResult, BridgeError> {
+    let session = client
+        .matrix_auth()
+        .session()
+        .ok_or_else(|| BridgeError::new(BridgeErrorKind::NotLoggedIn, "Client has no active session"))?;
+    Ok(LoginResult {
+        user_id: session.meta.user_id.to_string(),
+        device_id: session.meta.device_id.to_string(),
+        access_token: session.tokens.access_token,
+        refresh_token: session.tokens.refresh_token,
+    })
+}
+
+/// Log out for good: stop sync, invalidate the access token on the homeserver and wipe the local
+/// state and crypto store. With `delete_device` the device is removed from the account as well
+/// (which also revokes its token); servers usually want the account `password` for that.
+/// Only the store's database files are deleted; anything else in its data_dir (other accounts'
+/// stores included) stays. If they can't be removed right away (they're still open on Windows), a
+/// delete marker is left next to the store and the next `init` on it clears them.
+#[frb]
+pub async fn logout(
+    client: ClientHandle,
+    delete_device: bool,
+    password: Option<String>,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    use matrix_sdk::ruma::api::client::error::ErrorKind;
+
+    let state = client_state(client)?;
+    let session_state = state.clone();
+    calls::run("logout", options, async move {
+        // Let the sync in flight finish so its state is saved before the store goes away
+        session_state.stop_sync().await;
+        let client = session_state.client.clone();
+        let not_logged_in = || BridgeError::new(BridgeErrorKind::NotLoggedIn, "Not logged in");
+        let user_id = client.user_id().ok_or_else(not_logged_in)?.to_owned();
+        let device_id = client.device_id().ok_or_else(not_logged_in)?.to_owned();
+
+        if delete_device {
+            eprintln!("[Bridge][logout] Deleting device {} of {}", device_id, user_id);
+            delete_own_device(&client, &user_id, device_id, password).await?;
+        } else {
+            eprintln!("[Bridge][logout] Logging out {} ({})", user_id, device_id);
+            match client.matrix_auth().logout().await {
+                Ok(_) => {}
+                // The token is already dead server-side, which is all we wanted
+                Err(e) if matches!(e.client_api_error_kind(), Some(ErrorKind::UnknownToken { .. })) => {
+                    eprintln!("[Bridge][logout] Access token was already invalid");
                 }
-                
-                Err(err_str)
+                Err(e) => return Err(e.into()),
             }
         }
+        Ok(())
     })
+    .await?;
+
+    // The client is useless without a session, so release it before wiping its store
+    clients::remove(client.id);
+    let dir = state.data_dir.clone();
+    drop(state);
+    if let Err(e) = store_encryption::remove_store(&dir) {
+        eprintln!("[Bridge][logout] Failed to clear store ({}), leaving delete marker", e);
+        std::fs::write(delete_marker(&dir), b"").map_err(|e| format!("Failed to write delete marker: {}", e))?;
+    }
+    eprintln!("[Bridge][logout] ✓ Logged out");
+    Ok(())
+}
+
+/// Left by `logout` next to a store it couldn't delete.
+fn delete_marker(dir: &std::path::Path) -> std::path::PathBuf {
+    std::path::PathBuf::from(format!("{}_delete_marker", dir.display()))
+}
+
+/// Finish deleting the store in `dir` if a logout left a delete marker for it.
+fn clear_logged_out_store(dir: &std::path::Path) -> Result<(), BridgeError> {
+    let marker = delete_marker(dir);
+    if !marker.exists() {
+        return Ok(());
+    }
+    eprintln!("[Bridge][init] Clearing store left behind by a logout");
+    store_encryption::remove_store(dir)?;
+    std::fs::remove_file(&marker).map_err(|e| format!("Failed to remove delete marker: {}", e))?;
+    Ok(())
+}
+
+/// Delete this device, answering the user-interactive auth password stage if the server asks for it.
+async fn delete_own_device(
+    client: &Client,
+    user_id: &matrix_sdk::ruma::UserId,
+    device_id: matrix_sdk::ruma::OwnedDeviceId,
+    password: Option<String>,
+) -> Result<(), BridgeError> {
+    use matrix_sdk::ruma::api::client::uiaa;
+
+    let devices = [device_id];
+    let err = match client.delete_devices(&devices, None).await {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
+    let Some(info) = err.as_uiaa_response() else {
+        return Err(err.into());
+    };
+    let password = password.ok_or_else(|| {
+        BridgeError::new(BridgeErrorKind::Forbidden, "Deleting the device requires the account password")
+    })?;
+
+    eprintln!("[Bridge][logout] Server requested user-interactive auth, sending password stage");
+    let mut auth = uiaa::Password::new(uiaa::UserIdentifier::UserIdOrLocalpart(user_id.to_string()), password);
+    auth.session = info.session.clone();
+    client.delete_devices(&devices, Some(uiaa::AuthData::Password(auth))).await?;
+    Ok(())
 }
 
 /// Create a direct message room and invite another user
 /// The creator is automatically added to the room when it's created
 /// If creator_mxid is provided, it will also be invited (for multi-device support)
+/// This always creates a new room; `get_or_create_dm` reuses an existing DM instead.
+/// `property` ties the room to a property, as `set_property_metadata` does.
 #[frb]
-pub fn create_room(other_mxid: String, creator_mxid: Option<String>) -> Result<String, String> {
-    use matrix_sdk::ruma::UserId;
+pub async fn create_room(
+    client: ClientHandle,
+    other_mxid: String,
+    creator_mxid: Option<String>,
+    property: Option<PropertyMetadata>,
+    options: Option<CallOptions>,
+) -> Result<String, BridgeError> {
     use matrix_sdk::ruma::api::client::room::create_room::v3::Request as CreateRoomRequest;
     use matrix_sdk::ruma::api::client::room::Visibility;
     use matrix_sdk::ruma::events::room::encryption::RoomEncryptionEventContent;
     
-    let rt = get_rt();
-    rt.block_on(async move {
-        let client = CLIENT.get().ok_or_else(|| "Client not initialized".to_string())?.clone();
+    calls::run("create_room", options, async move {
+        let client = client_state(client)?.client.clone();
         
         eprintln!("[Bridge][create_room] Creating DM room with {}", other_mxid);
         eprintln!("[Bridge][create_room] Creator (me): {:?}", client.user_id());
         eprintln!("[Bridge][create_room] Creator MXID to invite: {:?}", creator_mxid);
         
-        let other_user_id = UserId::parse(&other_mxid).map_err(|e| e.to_string())?;
+        let other_user_id = UserId::parse(&other_mxid)?;
         
         // Build invitation list
         let mut invitees = vec![other_user_id];
@@ -258,12 +1234,16 @@ pub fn create_room(other_mxid: String, creator_mxid: Option<String>) -> Result<S
         request.visibility = Visibility::Private;
         request.is_direct = true;
         request.invite = invitees;
+        // Part of the room from the start, so there's no room without it to clean up
+        if let Some(property) = &property {
+            request.initial_state = vec![property_state::initial_state(property)?];
+        }
         // preset is optional in the builder, defaults to private_chat
         // Note: The creator is automatically joined to the room
         
         let response = client.create_room(request).await.map_err(|e| {
             eprintln!("[Bridge][create_room] Failed to create room: {}", e);
-            e.to_string()
+            BridgeError::from(e)
         })?;
         
         let room_id = response.room_id().to_string();
@@ -282,47 +1262,469 @@ pub fn create_room(other_mxid: String, creator_mxid: Option<String>) -> Result<S
         
         Ok(room_id)
     })
+    .await
 }
 
+/// Create an encrypted group room (e.g. one per property) and invite the participants, with power
+/// levels following their roles: landlords and managers can rename the room and invite or remove
+/// people, everyone can post. `config.property` is stored as the room's property metadata. Returns
+/// the room ID.
 #[frb]
-pub fn send_message(room_id: String, body: String) -> Result<String, String> {
-    let rt = get_rt();
-    rt.block_on(async move {
-        let client = CLIENT.get().ok_or_else(|| "Client not initialized".to_string())?.clone();
-        let rid = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
-        
-        eprintln!("[Bridge][send_message] Looking for room {}", room_id);
-        
-        // Try to join by ID first (handles invites and returns quickly if already member)
-        match client.join_room_by_id(&rid).await {
-            Ok(_) => eprintln!("[Bridge][send_message] join_room_by_id succeeded"),
-            Err(e) => eprintln!("[Bridge][send_message] join_room_by_id failed: {}", e),
+pub async fn create_group_room(
+    client: ClientHandle,
+    config: GroupRoomConfig,
+    options: Option<CallOptions>,
+) -> Result<String, BridgeError> {
+    use matrix_sdk::ruma::api::client::room::create_room::v3::{Request as CreateRoomRequest, RoomPreset};
+    use matrix_sdk::ruma::api::client::room::Visibility;
+    use matrix_sdk::ruma::events::room::avatar::RoomAvatarEventContent;
+    use matrix_sdk::ruma::events::room::encryption::RoomEncryptionEventContent;
+    use matrix_sdk::ruma::events::InitialStateEvent;
+
+    calls::run("create_group_room", options, async move {
+        let client = client_state(client)?.client.clone();
+        let creator = client
+            .user_id()
+            .ok_or_else(|| BridgeError::new(BridgeErrorKind::NotLoggedIn, "Not logged in"))?
+            .to_owned();
+        let mut roles = Vec::with_capacity(config.participants.len());
+        for participant in &config.participants {
+            roles.push((UserId::parse(&participant.user_id)?, participant.role));
         }
-        
-        // Wait and poll for room to appear (up to 5 seconds)
-        let mut room = None;
-        for i in 0..50 {
-            if let Some(r) = client.get_room(&rid) {
-                eprintln!("[Bridge][send_message] Found room after {} attempts", i + 1);
-                room = Some(r);
-                break;
-            }
-            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let encryption = RoomEncryptionEventContent::with_recommended_defaults();
+        let mut initial_state = vec![InitialStateEvent::new(encryption).to_raw_any()];
+        if let Some(avatar) = config.avatar {
+            let mime_type = config.avatar_mime_type.as_deref().unwrap_or("image/png");
+            let content_type: mime::Mime = mime_type.parse().map_err(|e| {
+                BridgeError::new(BridgeErrorKind::InvalidId, format!("Invalid MIME type {}: {}", mime_type, e))
+            })?;
+            let upload = client.media().upload(&content_type, avatar).await?;
+            let mut avatar_content = RoomAvatarEventContent::new();
+            avatar_content.url = Some(upload.content_uri);
+            initial_state.push(InitialStateEvent::new(avatar_content).to_raw_any());
         }
-        
-        let room = room.ok_or_else(|| {
-            eprintln!("[Bridge][send_message] Room {} not found in client after polling", room_id);
-            format!("Room {} not found after join attempt - sync may not have received invite yet", room_id)
+        if let Some(property) = &config.property {
+            initial_state.push(property_state::initial_state(property)?);
+        }
+
+        let mut request = CreateRoomRequest::new();
+        request.visibility = Visibility::Private;
+        request.preset = Some(RoomPreset::PrivateChat);
+        request.name = Some(config.name.clone());
+        request.topic = config.topic;
+        request.invite =
+            roles.iter().map(|(user_id, _)| user_id.clone()).filter(|user_id| *user_id != creator).collect();
+        request.initial_state = initial_state;
+        let power_levels = Raw::new(&group_rooms::power_levels(&creator, &roles))
+            .map_err(|e| format!("Failed to serialize power levels: {}", e))?;
+        request.power_level_content_override = Some(power_levels);
+
+        eprintln!("[Bridge][create_group_room] Creating {:?} with {} participants", config.name, roles.len());
+        let room = client.create_room(request).await.map_err(|e| {
+            eprintln!("[Bridge][create_group_room] Failed to create room: {}", e);
+            BridgeError::from(e)
         })?;
-        
-        // Explicitly join the room object (handles accepting invites)
-        match room.join().await {
-            Ok(_) => eprintln!("[Bridge][send_message] room.join() succeeded"),
-            Err(e) => eprintln!("[Bridge][send_message] room.join() failed: {} (may already be joined)", e),
+        eprintln!("[Bridge][create_group_room] Created room: {}", room.room_id());
+        Ok(room.room_id().to_string())
+    })
+    .await
+}
+
+/// Get the direct message room with `user_id`, creating an encrypted one only if there is none yet.
+/// Existing DMs are looked up in the `m.direct` account data and by room membership (a DM the user
+/// invited us to is accepted); new and newly found rooms are recorded in `m.direct`, so other
+/// clients list them as DMs too.
+/// `also_invite` are further users to have in the room, e.g. the creator's dashboard account. They
+/// are invited if they aren't in the room yet, but the room isn't recorded as a DM with them.
+/// `property` is stored with a new room, and added to a reused one that has no property yet.
+#[frb]
+pub async fn get_or_create_dm(
+    client: ClientHandle,
+    user_id: String,
+    also_invite: Option<Vec<String>>,
+    property: Option<PropertyMetadata>,
+    options: Option<CallOptions>,
+) -> Result<DirectRoom, BridgeError> {
+    use matrix_sdk::ruma::api::client::room::create_room::v3::{Request as CreateRoomRequest, RoomPreset};
+    use matrix_sdk::ruma::events::room::encryption::RoomEncryptionEventContent;
+    use matrix_sdk::ruma::events::InitialStateEvent;
+
+    calls::run("get_or_create_dm", options, async move {
+        let client = client_state(client)?.client.clone();
+        let user_id = UserId::parse(&user_id)?;
+        if client.user_id() == Some(user_id.as_ref()) {
+            return Err(BridgeError::new(BridgeErrorKind::InvalidId, "Can't open a DM with yourself"));
         }
-        
-        // Wait a bit for state to settle
-        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        // We are in the room anyway
+        let mut extra = Vec::new();
+        for id in also_invite.unwrap_or_default() {
+            let id = UserId::parse(&id)?;
+            if client.user_id() != Some(id.as_ref()) && id != user_id {
+                extra.push(id);
+            }
+        }
+
+        if let Some(room) = direct_rooms::find_dm(&client, &user_id).await? {
+            direct_rooms::invite_missing(&room, &extra).await;
+            // The room is usable either way; a DM already tied to a property keeps it
+            if let Some(property) = &property {
+                if property_state::read(&room).await.is_none() {
+                    if let Err(e) = property_state::write(&room, property).await {
+                        eprintln!("[Bridge][get_or_create_dm] Failed to link {} to property: {}", room.room_id(), e);
+                    }
+                }
+            }
+            return Ok(DirectRoom { room_id: room.room_id().to_string(), created: false });
+        }
+
+        eprintln!("[Bridge][get_or_create_dm] No DM with {} yet, creating one", user_id);
+        // What `Client::create_dm` sends, plus the property
+        let mut initial_state =
+            vec![InitialStateEvent::new(RoomEncryptionEventContent::with_recommended_defaults()).to_raw_any()];
+        if let Some(property) = &property {
+            initial_state.push(property_state::initial_state(property)?);
+        }
+        let mut request = CreateRoomRequest::new();
+        request.invite = vec![user_id.clone()];
+        request.is_direct = true;
+        request.preset = Some(RoomPreset::TrustedPrivateChat);
+        request.initial_state = initial_state;
+        let room = client.create_room(request).await.map_err(|e| {
+            eprintln!("[Bridge][get_or_create_dm] Failed to create room: {}", e);
+            BridgeError::from(e)
+        })?;
+        // The SDK only logs a failed m.direct update; the room is still usable, and found by
+        // membership next time
+        if let Err(e) = direct_rooms::mark_as_dm(&client, &room, &user_id).await {
+            eprintln!("[Bridge][get_or_create_dm] Failed to record {} in m.direct: {}", room.room_id(), e);
+        }
+        direct_rooms::invite_missing(&room, &extra).await;
+        eprintln!("[Bridge][get_or_create_dm] Created DM {}", room.room_id());
+        Ok(DirectRoom { room_id: room.room_id().to_string(), created: true })
+    })
+    .await
+}
+
+/// Summaries of every joined and invited room, most recently active first. With `space_id`, only
+/// the rooms in that space and its subspaces. Spaces themselves aren't listed.
+/// Rooms without a message since the client started get their latest one fetched once.
+#[frb]
+pub async fn list_rooms(
+    client: ClientHandle,
+    space_id: Option<String>,
+    options: Option<CallOptions>,
+) -> Result<Vec<RoomSummary>, BridgeError> {
+    let state = client_state(client)?;
+    calls::run("list_rooms", options, async move {
+        let within = match space_id {
+            Some(space_id) => Some(spaces::descendants(&state.client, &RoomId::parse(&space_id)?).await?),
+            None => None,
+        };
+        Ok(room_list::list(&state, within.as_ref()).await)
+    })
+    .await
+}
+
+/// Create a space, e.g. for a property or a portfolio of properties, and return its room ID.
+/// With `parent_space_id` it's added to that space as a subspace.
+#[frb]
+pub async fn create_space(
+    client: ClientHandle,
+    name: String,
+    topic: Option<String>,
+    parent_space_id: Option<String>,
+    options: Option<CallOptions>,
+) -> Result<String, BridgeError> {
+    use matrix_sdk::ruma::api::client::room::create_room::v3::{
+        CreationContent, Request as CreateRoomRequest, RoomPreset,
+    };
+    use matrix_sdk::ruma::api::client::room::Visibility;
+    use matrix_sdk::ruma::room::RoomType;
+
+    calls::run("create_space", options, async move {
+        let client = client_state(client)?.client.clone();
+        let parent = parent_space_id.as_deref().map(|id| known_room(&client, id)).transpose()?;
+
+        let mut creation_content = CreationContent::new();
+        creation_content.room_type = Some(RoomType::Space);
+        let mut request = CreateRoomRequest::new();
+        request.visibility = Visibility::Private;
+        request.preset = Some(RoomPreset::PrivateChat);
+        request.name = Some(name.clone());
+        request.topic = topic;
+        request.creation_content =
+            Some(Raw::new(&creation_content).map_err(|e| format!("Failed to serialize creation content: {}", e))?);
+
+        let space = client.create_room(request).await?;
+        eprintln!("[Bridge][spaces] Created space {:?}: {}", name, space.room_id());
+        if let Some(parent) = parent {
+            spaces::add_child(&client, &parent, space.room_id()).await?;
+        }
+        Ok(space.room_id().to_string())
+    })
+    .await
+}
+
+/// Add a room (or subspace) to a space. Needs the power level to change the space's state.
+#[frb]
+pub async fn add_space_child(
+    client: ClientHandle,
+    space_id: String,
+    child_id: String,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    calls::run("add_space_child", options, async move {
+        let client = client_state(client)?.client.clone();
+        let space = known_room(&client, &space_id)?;
+        spaces::add_child(&client, &space, &RoomId::parse(&child_id)?).await?;
+        eprintln!("[Bridge][spaces] Added {} to {}", child_id, space_id);
+        Ok(())
+    })
+    .await
+}
+
+/// Take a room (or subspace) out of a space. The room itself stays as it is.
+#[frb]
+pub async fn remove_space_child(
+    client: ClientHandle,
+    space_id: String,
+    child_id: String,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    calls::run("remove_space_child", options, async move {
+        let client = client_state(client)?.client.clone();
+        let space = known_room(&client, &space_id)?;
+        spaces::remove_child(&client, &space, &RoomId::parse(&child_id)?).await?;
+        eprintln!("[Bridge][spaces] Removed {} from {}", child_id, space_id);
+        Ok(())
+    })
+    .await
+}
+
+/// Everything below a space, including rooms we aren't in: the space itself first, then its rooms
+/// and subspaces with the ID of the space listing them.
+#[frb]
+pub async fn get_space_hierarchy(
+    client: ClientHandle,
+    space_id: String,
+    options: Option<CallOptions>,
+) -> Result<Vec<SpaceHierarchyEntry>, BridgeError> {
+    calls::run("get_space_hierarchy", options, async move {
+        let client = client_state(client)?.client.clone();
+        spaces::hierarchy(&client, &RoomId::parse(&space_id)?).await
+    })
+    .await
+}
+
+/// Tie a joined room to a property, replacing any metadata it had. Needs the power level for state
+/// events (managers and landlords in group rooms).
+#[frb]
+pub async fn set_property_metadata(
+    client: ClientHandle,
+    room_id: String,
+    metadata: PropertyMetadata,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    calls::run("set_property_metadata", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        property_state::write(&room, &metadata).await?;
+        eprintln!("[Bridge][set_property_metadata] Linked {} to property {}", room_id, metadata.property_id);
+        Ok(())
+    })
+    .await
+}
+
+/// The property a room is about, or None if it isn't tied to one.
+#[frb]
+pub async fn get_property_metadata(
+    client: ClientHandle,
+    room_id: String,
+    options: Option<CallOptions>,
+) -> Result<Option<PropertyMetadata>, BridgeError> {
+    calls::run("get_property_metadata", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        Ok(property_state::read(&room).await)
+    })
+    .await
+}
+
+/// Every pending invite of a client.
+#[frb]
+pub async fn list_invites(client: ClientHandle, options: Option<CallOptions>) -> Result<Vec<InviteInfo>, BridgeError> {
+    let state = client_state(client)?;
+    calls::run("list_invites", options, async move {
+        let mut pending = Vec::new();
+        for room in state.client.invited_rooms() {
+            pending.push(invites::preview(&room).await);
+        }
+        Ok(pending)
+    })
+    .await
+}
+
+/// Join a room we're invited to.
+#[frb]
+pub async fn accept_invite(client: ClientHandle, room_id: String, options: Option<CallOptions>) -> Result<(), BridgeError> {
+    calls::run("accept_invite", options, async move {
+        let room = invited_room(&client_state(client)?.client, &room_id)?;
+        room.join().await?;
+        eprintln!("[Bridge][accept_invite] Joined {}", room_id);
+        Ok(())
+    })
+    .await
+}
+
+/// Reject an invite. The inviter can invite us again later.
+#[frb]
+pub async fn decline_invite(client: ClientHandle, room_id: String, options: Option<CallOptions>) -> Result<(), BridgeError> {
+    calls::run("decline_invite", options, async move {
+        let room = invited_room(&client_state(client)?.client, &room_id)?;
+        room.leave().await?;
+        eprintln!("[Bridge][decline_invite] Declined {}", room_id);
+        Ok(())
+    })
+    .await
+}
+
+fn invited_room(client: &Client, room_id: &str) -> Result<Room, BridgeError> {
+    let rid = RoomId::parse(room_id)?;
+    client
+        .get_room(&rid)
+        .filter(|room| room.state() == RoomState::Invited)
+        .ok_or_else(|| BridgeError::new(BridgeErrorKind::RoomNotFound, format!("No pending invite to {}", room_id)))
+}
+
+/// Every member of a room with their membership and power level, including those who left or
+/// were banned. Loads the full member list from the homeserver the first time.
+#[frb]
+pub async fn get_room_members(
+    client: ClientHandle,
+    room_id: String,
+    options: Option<CallOptions>,
+) -> Result<Vec<RoomMemberInfo>, BridgeError> {
+    calls::run("get_room_members", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        members::list(&room).await
+    })
+    .await
+}
+
+/// Invite a user to a room we're in.
+#[frb]
+pub async fn invite_user(
+    client: ClientHandle,
+    room_id: String,
+    user_id: String,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    calls::run("invite_user", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        room.invite_user_by_id(&UserId::parse(&user_id)?).await?;
+        eprintln!("[Bridge][members] Invited {} to {}", user_id, room_id);
+        Ok(())
+    })
+    .await
+}
+
+/// Remove a member from a room; they can join again if invited.
+#[frb]
+pub async fn kick_user(
+    client: ClientHandle,
+    room_id: String,
+    user_id: String,
+    reason: Option<String>,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    calls::run("kick_user", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        room.kick_user(&UserId::parse(&user_id)?, reason.as_deref()).await?;
+        eprintln!("[Bridge][members] Kicked {} from {}", user_id, room_id);
+        Ok(())
+    })
+    .await
+}
+
+/// Remove a member (or anyone else) from a room and keep them out until `unban_user`.
+#[frb]
+pub async fn ban_user(
+    client: ClientHandle,
+    room_id: String,
+    user_id: String,
+    reason: Option<String>,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    calls::run("ban_user", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        room.ban_user(&UserId::parse(&user_id)?, reason.as_deref()).await?;
+        eprintln!("[Bridge][members] Banned {} from {}", user_id, room_id);
+        Ok(())
+    })
+    .await
+}
+
+/// Lift a ban. The user isn't re-invited.
+#[frb]
+pub async fn unban_user(
+    client: ClientHandle,
+    room_id: String,
+    user_id: String,
+    reason: Option<String>,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    calls::run("unban_user", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        room.unban_user(&UserId::parse(&user_id)?, reason.as_deref()).await?;
+        eprintln!("[Bridge][members] Unbanned {} from {}", user_id, room_id);
+        Ok(())
+    })
+    .await
+}
+
+/// Leave a room. It drops out of the room list with the next sync; `forget_room` also removes it
+/// from the account's history.
+#[frb]
+pub async fn leave_room(client: ClientHandle, room_id: String, options: Option<CallOptions>) -> Result<(), BridgeError> {
+    calls::run("leave_room", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        room.leave().await?;
+        eprintln!("[Bridge][members] Left {}", room_id);
+        Ok(())
+    })
+    .await
+}
+
+/// Forget a room we left, so the server drops it from our account and the local store drops it too.
+#[frb]
+pub async fn forget_room(client: ClientHandle, room_id: String, options: Option<CallOptions>) -> Result<(), BridgeError> {
+    calls::run("forget_room", options, async move {
+        let room = known_room(&client_state(client)?.client, &room_id)?;
+        room.forget().await?;
+        eprintln!("[Bridge][members] Forgot {}", room_id);
+        Ok(())
+    })
+    .await
+}
+
+/// A room the client knows about, whatever our membership.
+fn known_room(client: &Client, room_id: &str) -> Result<Room, BridgeError> {
+    let rid = RoomId::parse(room_id)?;
+    client
+        .get_room(&rid)
+        .ok_or_else(|| BridgeError::new(BridgeErrorKind::RoomNotFound, format!("Room {} not found", room_id)))
+}
+
+#[frb]
+pub async fn send_message(
+    client: ClientHandle,
+    room_id: String,
+    body: String,
+    options: Option<CallOptions>,
+) -> Result<String, BridgeError> {
+    calls::run("send_message", options, async move {
+        let client = client_state(client)?.client.clone();
+        let rid = RoomId::parse(&room_id)?;
+        let room = join_room_for_send(&client, &rid).await?;
         
         // Send the message
         eprintln!("[Bridge][send_message] Attempting to send message");
@@ -332,337 +1734,641 @@ pub fn send_message(room_id: String, body: String) -> Result<String, String> {
             .await
             .map_err(|e| {
                 eprintln!("[Bridge][send_message] Failed to send: {}", e);
-                e.to_string()
+                BridgeError::from(e)
             })?;
         
         eprintln!("[Bridge][send_message] Message sent successfully: {}", send_resp.event_id);
         Ok(send_resp.event_id.to_string())
     })
+    .await
+}
+
+/// Upload a file and post it to a room as m.image, m.video, m.audio or m.file depending on `mime_type`.
+/// Pass either `file_path` or the raw `data`; `filename` defaults to the path's file name.
+/// In encrypted rooms the file (and thumbnail) is encrypted before upload.
+/// Images get their dimensions and a JPEG thumbnail attached. Returns the event ID.
+#[frb]
+pub async fn send_attachment(
+    client: ClientHandle,
+    room_id: String,
+    file_path: Option<String>,
+    data: Option<Vec<u8>>,
+    filename: Option<String>,
+    mime_type: String,
+    options: Option<CallOptions>,
+) -> Result<String, BridgeError> {
+    use matrix_sdk::attachment::{
+        AttachmentConfig, AttachmentInfo, BaseAudioInfo, BaseFileInfo, BaseImageInfo, BaseVideoInfo,
+    };
+
+    let content_type: mime::Mime = mime_type
+        .parse()
+        .map_err(|e| BridgeError::new(BridgeErrorKind::InvalidId, format!("Invalid MIME type {}: {}", mime_type, e)))?;
+    let filename = filename
+        .filter(|f| !f.is_empty())
+        .or_else(|| {
+            file_path.as_ref().and_then(|p| {
+                std::path::Path::new(p).file_name().map(|n| n.to_string_lossy().into_owned())
+            })
+        })
+        .unwrap_or_else(|| "attachment".to_string());
+
+    calls::run("send_attachment", options, async move {
+        // Inside the call, so the timeout and cancel token cover reading a large file
+        let data = match (data, &file_path) {
+            (Some(bytes), _) => bytes,
+            (None, Some(path)) => tokio::fs::read(path).await.map_err(|e| format!("Failed to read {}: {}", path, e))?,
+            (None, None) => return Err("Either file_path or data is required".to_string().into()),
+        };
+        let client = client_state(client)?.client.clone();
+        let rid = RoomId::parse(&room_id)?;
+        let room = join_room_for_send(&client, &rid).await?;
+
+        let size = UInt::new(data.len() as u64);
+        let config = match content_type.type_() {
+            mime::IMAGE => {
+                // Only the header is read here; unknown formats are sent without dimensions
+                let (width, height) = image::io::Reader::new(std::io::Cursor::new(&data))
+                    .with_guessed_format()
+                    .ok()
+                    .and_then(|reader| reader.into_dimensions().ok())
+                    .map(|(w, h)| (Some(UInt::from(w)), Some(UInt::from(h))))
+                    .unwrap_or((None, None));
+                AttachmentConfig::new()
+                    .generate_thumbnail(None)
+                    .info(AttachmentInfo::Image(BaseImageInfo { height, width, size, blurhash: None }))
+            }
+            mime::VIDEO => AttachmentConfig::new().info(AttachmentInfo::Video(BaseVideoInfo {
+                duration: None,
+                height: None,
+                width: None,
+                size,
+                blurhash: None,
+            })),
+            mime::AUDIO => AttachmentConfig::new().info(AttachmentInfo::Audio(BaseAudioInfo { duration: None, size })),
+            _ => AttachmentConfig::new().info(AttachmentInfo::File(BaseFileInfo { size })),
+        };
+
+        eprintln!(
+            "[Bridge][send_attachment] Uploading {} ({}, {} bytes, encrypted room: {:?})",
+            filename,
+            content_type,
+            data.len(),
+            room.is_encrypted().await
+        );
+        let send_resp = room
+            .send_attachment(&filename, &content_type, data, config)
+            .await
+            .map_err(|e| {
+                eprintln!("[Bridge][send_attachment] Failed to send: {}", e);
+                BridgeError::from(e)
+            })?;
+
+        eprintln!("[Bridge][send_attachment] Attachment sent successfully: {}", send_resp.event_id);
+        Ok(send_resp.event_id.to_string())
+    })
+    .await
+}
+
+/// Get a room ready for sending, joining it first (which accepts a pending invite) unless it's joined already.
+async fn join_room_for_send(client: &Client, rid: &RoomId) -> Result<Room, BridgeError> {
+    if let Some(room) = client.get_room(rid).filter(|r| r.state() == RoomState::Joined) {
+        return Ok(room);
+    }
+    // The SDK marks the room as joined as soon as the server confirms, no need to wait for a sync
+    eprintln!("[Bridge][send] Not joined to {} yet, joining", rid);
+    client.join_room_by_id(rid).await.map_err(|e| {
+        eprintln!("[Bridge][send] Failed to join {}: {}", rid, e);
+        let mut err = BridgeError::from(e);
+        if err.kind == BridgeErrorKind::Other {
+            err.kind = BridgeErrorKind::RoomNotFound;
+        }
+        err
+    })
+}
+
+/// Change the media cache limits (defaults: 512 MiB total, 100 MiB per file).
+#[frb]
+pub fn configure_media_cache(client: ClientHandle, config: MediaCacheConfig) -> Result<(), BridgeError> {
+    let state = client_state(client)?;
+    state.media_cache.set_limits(config.max_total_bytes, config.max_file_bytes);
+    Ok(())
+}
+
+/// Resolve media to a local file path, downloading (and decrypting) it into the cache on first use.
+/// `source` is an `Attachment.source`/`thumbnail_source` JSON string or a bare mxc:// URI.
+/// `thumbnail` asks the server for a scaled copy; that only works for unencrypted media, for encrypted
+/// attachments pass the event's `thumbnail_source` instead. `filename` only picks the file extension.
+/// `size` is the attachment's `size`; media over the cache's per-file limit is then turned down
+/// without downloading it.
+/// Note that encrypted media is stored decrypted so it can be shown offline.
+#[frb]
+pub async fn fetch_media(
+    client: ClientHandle,
+    source: String,
+    filename: Option<String>,
+    size: Option<u64>,
+    thumbnail: Option<ThumbnailRequest>,
+    options: Option<CallOptions>,
+) -> Result<String, BridgeError> {
+    use matrix_sdk::media::{MediaFormat, MediaRequest, MediaThumbnailSize};
+    use matrix_sdk::ruma::api::client::media::get_content_thumbnail::v3::Method;
+    use matrix_sdk::ruma::OwnedMxcUri;
+
+    let source: MediaSource = if source.starts_with("mxc://") {
+        MediaSource::Plain(OwnedMxcUri::from(source))
+    } else {
+        serde_json::from_str(&source)
+            .map_err(|e| BridgeError::new(BridgeErrorKind::InvalidId, format!("Invalid media source: {}", e)))?
+    };
+    // The server can't scale ciphertext, so encrypted media always comes back as the full file
+    let thumbnail = match (&source, thumbnail) {
+        (MediaSource::Encrypted(_), Some(_)) => {
+            eprintln!("[Bridge][fetch_media] Thumbnail requested for encrypted media, fetching full file");
+            None
+        }
+        (_, thumbnail) => thumbnail,
+    };
+
+    let state = client_state(client)?;
+    let key = media_cache::cache_key(&source, thumbnail.map(|t| (t.width, t.height, t.crop)));
+    let extension = media_cache::extension_from_filename(filename.as_deref());
+    if let Some(path) = state.media_cache.get(&key, extension.as_deref()) {
+        return Ok(path.to_string_lossy().into_owned());
+    }
+    // The announced size is that of the full file
+    let size = if thumbnail.is_none() { size } else { None };
+    if let Some(size) = state.media_cache.oversized(&key, size) {
+        return Err(oversized_media(size, state.media_cache.max_file_bytes()));
+    }
+
+    calls::run("fetch_media", options, async move {
+        let cache = &state.media_cache;
+        let client = state.client.clone();
+        let format = match thumbnail {
+            Some(t) => MediaFormat::Thumbnail(MediaThumbnailSize {
+                method: if t.crop { Method::Crop } else { Method::Scale },
+                width: UInt::from(t.width),
+                height: UInt::from(t.height),
+            }),
+            None => MediaFormat::File,
+        };
+        let request = MediaRequest { source, format };
+
+        eprintln!("[Bridge][fetch_media] Downloading {}", key);
+        // Skip the SDK's own media cache, it lives in the state store and isn't bounded
+        let data = client
+            .media()
+            .get_media_content(&request, false)
+            .await?;
+
+        if data.len() as u64 > cache.max_file_bytes() {
+            cache.mark_oversized(&key, data.len() as u64);
+            return Err(oversized_media(data.len() as u64, cache.max_file_bytes()));
+        }
+
+        let path = cache
+            .put(&key, extension.as_deref(), &data)
+            .map_err(|e| format!("Failed to write media cache: {}", e))?;
+        eprintln!("[Bridge][fetch_media] Cached {} bytes at {}", data.len(), path.display());
+        Ok(path.to_string_lossy().into_owned())
+    })
+    .await
+}
+
+fn oversized_media(size: u64, max_file_bytes: u64) -> BridgeError {
+    format!("Media is {} bytes, over the cache's per-file limit of {} bytes", size, max_file_bytes).into()
+}
+
+/// Delete every cached media file.
+#[frb]
+pub fn clear_media_cache(client: ClientHandle) -> Result<(), BridgeError> {
+    client_state(client)?
+        .media_cache
+        .clear()
+        .map_err(|e| format!("Failed to clear media cache: {}", e).into())
 }
 
 /// Send a read receipt for a specific event in a room.
 #[frb]
-pub fn mark_read(room_id: String, event_id: String) -> Result<(), String> {
-    let rt = get_rt();
-    rt.block_on(async move {
-        let client = CLIENT.get().ok_or_else(|| "Client not initialized".to_string())?.clone();
-        let rid = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
-        let eid: OwnedEventId = event_id
-            .parse::<OwnedEventId>()
-            .map_err(|e| e.to_string())?;
+pub async fn mark_read(
+    client: ClientHandle,
+    room_id: String,
+    event_id: String,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    calls::run("mark_read", options, async move {
+        let client = client_state(client)?.client.clone();
+        let rid = RoomId::parse(&room_id)?;
+        let eid: OwnedEventId = event_id.parse::<OwnedEventId>()?;
         let room = client
             .get_room(&rid)
-            .ok_or_else(|| "Room not found".to_string())?;
+            .ok_or_else(|| BridgeError::new(BridgeErrorKind::RoomNotFound, "Room not found"))?;
         room
             .send_single_receipt(ReceiptType::Read, ReceiptThread::Unthreaded, eid)
-            .await
-            .map_err(|e| e.to_string())?;
+            .await?;
         Ok(())
     })
+    .await
+}
+
+/// Get the latest timeline messages from a room, in chronological order
+/// Encrypted events are decrypted with the client's Olm machine. If the room key is missing,
+/// the item is still returned as `MessageKind::UnableToDecrypt` with the reason in `decryption_error`.
+#[frb]
+pub async fn get_room_messages(
+    client: ClientHandle,
+    room_id: String,
+    limit: u32,
+    options: Option<CallOptions>,
+) -> Result<Vec<TimelineItem>, BridgeError> {
+    let page = get_room_messages_page(client, room_id, None, TimelineDirection::Backward, limit, options).await?;
+    Ok(page.messages)
 }
 
-/// Get timeline messages from a room
-/// Returns a JSON array of messages: [{"sender":"@user:server","body":"text","timestamp":1234567890,"eventId":"$xyz"}]
+/// Get one page of timeline messages from a room
+/// Pass `from = None` to start at the newest events (backward) or the oldest visible ones (forward),
+/// then pass the returned `next_token` to continue in the same direction.
+/// `messages` is in chronological order, same as `get_room_messages`.
 #[frb]
-pub fn get_room_messages(room_id: String, limit: u32) -> Result<String, String> {
-    use matrix_sdk::ruma::events::room::message::MessageType;
-    use matrix_sdk::ruma::events::AnyTimelineEvent;
-    use serde_json::json;
+pub async fn get_room_messages_page(
+    client: ClientHandle,
+    room_id: String,
+    from: Option<String>,
+    direction: TimelineDirection,
+    limit: u32,
+    options: Option<CallOptions>,
+) -> Result<TimelinePage, BridgeError> {
+    use matrix_sdk::room::MessagesOptions;
     
-    let rt = get_rt();
-    rt.block_on(async move {
-        let client = CLIENT.get().ok_or_else(|| "Client not initialized".to_string())?.clone();
-        let rid = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
+    calls::run("get_room_messages", options, async move {
+        let client = client_state(client)?.client.clone();
+        let rid = RoomId::parse(&room_id)?;
+        let room = get_or_join_room(&client, &rid).await?;
         
-        // Try to get the room, if not found try to join it first
-        let room = match client.get_room(&rid) {
-            Some(r) => {
-                eprintln!("[Bridge][get_room_messages] Room found: {} state: {:?}", room_id, r.state());
-                r
-            }
-            None => {
-                eprintln!("[Bridge][get_room_messages] Room not found locally, attempting to join: {}", room_id);
-                match client.join_room_by_id(&rid).await {
-                    Ok(joined_room) => {
-                        eprintln!("[Bridge][get_room_messages] Successfully joined room: {}", room_id);
-                        joined_room
-                    }
-                    Err(e) => {
-                        return Err(format!("Room not found and failed to join: {}", e));
-                    }
-                }
-            }
+        // Room::messages hits the /messages endpoint and runs every m.room.encrypted
+        // event through the Olm machine, so decryptable history comes back as plaintext
+        let mut options = match direction {
+            TimelineDirection::Backward => MessagesOptions::backward(),
+            TimelineDirection::Forward => MessagesOptions::forward(),
         };
+        options.from = from.filter(|t| !t.is_empty());
+        options.limit = limit.into();
         
-        // Get messages using /messages endpoint
-        use matrix_sdk::ruma::api::client::message::get_message_events;
-        let mut request = get_message_events::v3::Request::backward(rid.clone());
-        request.limit = limit.try_into().unwrap_or(50u32.try_into().unwrap());
+        let response = room.messages(options).await?;
         
-        let response = client.send(request, None).await.map_err(|e| format!("Failed to get messages: {}", e))?;
+        eprintln!(
+            "[Bridge][get_room_messages] Got {} events from /messages endpoint ({:?}, end: {:?})",
+            response.chunk.len(),
+            direction,
+            response.end
+        );
         
-        eprintln!("[Bridge][get_room_messages] Got {} events from /messages endpoint", response.chunk.len());
-        
-        let mut messages = Vec::new();
-        
-        // Parse and decrypt timeline events
-        for event in response.chunk.iter().rev() {
-            if let Ok(timeline_event) = event.deserialize() {
-                match timeline_event {
-                    AnyTimelineEvent::MessageLike(msg_like) => {
-                        match msg_like {
-                            matrix_sdk::ruma::events::AnyMessageLikeEvent::RoomMessage(room_msg) => {
-                                if let matrix_sdk::ruma::events::MessageLikeEvent::Original(msg) = room_msg {
-                                    let body = match &msg.content.msgtype {
-                                        MessageType::Text(text) => text.body.clone(),
-                                        _ => continue,
-                                    };
-                                    
-                                    let timestamp_secs = msg.origin_server_ts.as_secs();
-                                    
-                                    messages.push(json!({
-                                        "sender": msg.sender.to_string(),
-                                        "body": body,
-                                        "timestamp": timestamp_secs,
-                                        "eventId": msg.event_id.to_string(),
-                                    }));
-                                }
-                            }
-                            matrix_sdk::ruma::events::AnyMessageLikeEvent::RoomEncrypted(_encrypted_event) => {
-                                // Encrypted messages - these will be decrypted during sync
-                                // With persistent storage, they'll be available after restart
-                                eprintln!("[Bridge][get_room_messages] Encrypted message found - will be decrypted via sync");
-                            }
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
-            }
+        // Backward pagination returns newest first; always hand Dart chronological order
+        let mut chunk = response.chunk;
+        if matches!(direction, TimelineDirection::Backward) {
+            chunk.reverse();
         }
+        let messages = timeline_events_to_items(&room, chunk).await;
         
-        eprintln!("[Bridge][get_room_messages] Returning {} decrypted messages", messages.len());
+        // The server omits `end` once there is nothing further in this direction
+        let reached_start = response.end.is_none() && matches!(direction, TimelineDirection::Backward);
         
-        let result = json!(messages);
-        Ok(result.to_string())
+        Ok(TimelinePage {
+            messages,
+            next_token: response.end,
+            reached_start,
+        })
     })
+    .await
 }
 
-// Old implementation using /messages API (kept for reference, but doesn't decrypt)
-#[allow(dead_code)]
-fn get_room_messages_old(room_id: String, limit: u32) -> Result<String, String> {
-    use matrix_sdk::ruma::events::room::message::MessageType;
-    use matrix_sdk::ruma::events::AnyTimelineEvent;
-    use serde_json::json;
-    
-    let rt = get_rt();
-    rt.block_on(async move {
-        let client = CLIENT.get().ok_or_else(|| "Client not initialized".to_string())?.clone();
-        let rid = RoomId::parse(&room_id).map_err(|e| e.to_string())?;
-        
-        let room = client.get_room(&rid).ok_or_else(|| "Room not found".to_string())?;
-        
-        // Get messages using /messages endpoint
-        use matrix_sdk::ruma::api::client::message::get_message_events;
-        let mut request = get_message_events::v3::Request::backward(rid);
-        request.limit = limit.try_into().unwrap_or(50u32.try_into().unwrap());
-        
-        let response = client.send(request, None).await.map_err(|e| format!("Failed to get messages: {}", e))?;
-        
-        eprintln!("[Bridge][get_room_messages] Got {} events from /messages endpoint", response.chunk.len());
-        
-        let mut messages = Vec::new();
-        let mut event_type_counts = std::collections::HashMap::new();
-        
-        // Parse timeline events
-        for (i, event) in response.chunk.iter().rev().enumerate() {
-            match event.deserialize() {
-                Ok(timeline_event) => {
-                    match timeline_event {
-                        AnyTimelineEvent::MessageLike(msg_like) => {
-                            *event_type_counts.entry("MessageLike").or_insert(0) += 1;
-                            match msg_like {
-                                matrix_sdk::ruma::events::AnyMessageLikeEvent::RoomMessage(room_msg) => {
-                                    match room_msg {
-                                        matrix_sdk::ruma::events::MessageLikeEvent::Original(msg) => {
-                                            let body = match &msg.content.msgtype {
-                                                MessageType::Text(text) => text.body.clone(),
-                                                other => {
-                                                    eprintln!("[Bridge][get_room_messages] Event {}: Skipping non-text message type: {:?}", i, other);
-                                                    continue;
-                                                }
-                                            };
-                                            
-                                            // Convert timestamp: MilliSecondsSinceUnixEpoch to seconds
-                                            let timestamp_secs = msg.origin_server_ts.as_secs();
-                                            
-                                            messages.push(json!({
-                                                "sender": msg.sender.to_string(),
-                                                "body": body,
-                                                "timestamp": timestamp_secs,
-                                                "eventId": msg.event_id.to_string(),
-                                            }));
-                                        }
-                                        matrix_sdk::ruma::events::MessageLikeEvent::Redacted(_) => {
-                                            eprintln!("[Bridge][get_room_messages] Event {}: Redacted message", i);
-                                        }
-                                    }
-                                }
-                                matrix_sdk::ruma::events::AnyMessageLikeEvent::RoomEncrypted(encrypted) => {
-                                    *event_type_counts.entry("RoomEncrypted").or_insert(0) += 1;
-                                    eprintln!("[Bridge][get_room_messages] Event {}: Encrypted message - trying to decrypt via room timeline", i);
-                                    // Note: We can't decrypt here directly with the /messages API
-                                    // The SDK needs to decrypt these through the timeline
-                                    // For now, skip encrypted historical messages
-                                }
-                                other => {
-                                    let type_name = match &other {
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::CallAnswer(_) => "CallAnswer",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::CallInvite(_) => "CallInvite",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::CallHangup(_) => "CallHangup",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::CallCandidates(_) => "CallCandidates",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::KeyVerificationReady(_) => "KeyVerificationReady",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::KeyVerificationStart(_) => "KeyVerificationStart",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::KeyVerificationCancel(_) => "KeyVerificationCancel",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::KeyVerificationAccept(_) => "KeyVerificationAccept",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::KeyVerificationKey(_) => "KeyVerificationKey",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::KeyVerificationMac(_) => "KeyVerificationMac",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::KeyVerificationDone(_) => "KeyVerificationDone",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::Reaction(_) => "Reaction",
-                                        matrix_sdk::ruma::events::AnyMessageLikeEvent::RoomRedaction(_) => "RoomRedaction",
-                                        _ => "Unknown"
-                                    };
-                                    eprintln!("[Bridge][get_room_messages] Event {}: Other MessageLike type: {}", i, type_name);
-                                }
-                            }
-                        },
-                        AnyTimelineEvent::State(state) => {
-                            *event_type_counts.entry("State").or_insert(0) += 1;
-                        }
-                    }
+/// Look up a room locally, joining it by ID if the client doesn't know it yet.
+async fn get_or_join_room(client: &Client, rid: &RoomId) -> Result<Room, BridgeError> {
+    match client.get_room(rid) {
+        Some(r) => {
+            eprintln!("[Bridge][get_room_messages] Room found: {} state: {:?}", rid, r.state());
+            Ok(r)
+        }
+        None => {
+            eprintln!("[Bridge][get_room_messages] Room not found locally, attempting to join: {}", rid);
+            match client.join_room_by_id(rid).await {
+                Ok(joined_room) => {
+                    eprintln!("[Bridge][get_room_messages] Successfully joined room: {}", rid);
+                    Ok(joined_room)
                 }
                 Err(e) => {
-                    *event_type_counts.entry("ParseError").or_insert(0) += 1;
-                    eprintln!("[Bridge][get_room_messages] Event {}: Failed to deserialize: {}", i, e);
+                    eprintln!("[Bridge][get_room_messages] Room not found and failed to join: {}", e);
+                    let mut err = BridgeError::from(e);
+                    if err.kind == BridgeErrorKind::Other {
+                        err.kind = BridgeErrorKind::RoomNotFound;
+                    }
+                    Err(err)
                 }
             }
         }
-        
-        eprintln!("[Bridge][get_room_messages] Event type summary: {:?}", event_type_counts);
-        eprintln!("[Bridge][get_room_messages] Returning {} messages", messages.len());
-        
-        let result = json!(messages);
-        Ok(result.to_string())
-    })
+    }
+}
+
+/// Convert /messages events (already in display order) into timeline items.
+pub(crate) async fn timeline_events_to_items(room: &Room, events: Vec<TimelineEvent>) -> Vec<TimelineItem> {
+    let mut items = Vec::new();
+
+    for event in events {
+        let is_encrypted = event.encryption_info.is_some();
+        if let Some(item) = raw_to_timeline_item(room, event.event.cast(), is_encrypted).await {
+            items.push(item);
+        }
+    }
+
+    let undecryptable = items.iter().filter(|i| i.kind == MessageKind::UnableToDecrypt).count();
+    eprintln!(
+        "[Bridge][get_room_messages] Returning {} messages ({} unable to decrypt)",
+        items.len(),
+        undecryptable
+    );
+
+    items
+}
+
+/// Turn a raw timeline event into a `TimelineItem`, or None if it isn't a displayable message.
+pub(crate) async fn raw_to_timeline_item(
+    room: &Room,
+    mut raw: Raw<AnySyncTimelineEvent>,
+    mut is_encrypted: bool,
+) -> Option<TimelineItem> {
+    let mut timeline_event = match raw.deserialize() {
+        Ok(ev) => ev,
+        Err(e) => {
+            eprintln!("[Bridge][timeline] Failed to deserialize event: {}", e);
+            return None;
+        }
+    };
+
+    // Still encrypted means the SDK couldn't decrypt it. Try once more so we learn why
+    // (this also asks key backup for the session), and keys may have arrived meanwhile.
+    if let AnySyncTimelineEvent::MessageLike(AnySyncMessageLikeEvent::RoomEncrypted(
+        SyncMessageLikeEvent::Original(encrypted),
+    )) = &timeline_event
+    {
+        match room.decrypt_event(raw.cast_ref()).await {
+            Ok(decrypted) => {
+                is_encrypted = true;
+                raw = decrypted.event.cast();
+                timeline_event = match raw.deserialize() {
+                    Ok(ev) => ev,
+                    Err(e) => {
+                        eprintln!("[Bridge][timeline] Failed to deserialize decrypted event: {}", e);
+                        return None;
+                    }
+                };
+            }
+            Err(e) => {
+                eprintln!("[Bridge][timeline] Unable to decrypt {}: {}", encrypted.event_id, e);
+                return Some(TimelineItem {
+                    room_id: room.room_id().to_string(),
+                    event_id: encrypted.event_id.to_string(),
+                    sender: encrypted.sender.to_string(),
+                    timestamp_ms: encrypted.origin_server_ts.get().into(),
+                    kind: MessageKind::UnableToDecrypt,
+                    body: "Unable to decrypt message".to_string(),
+                    formatted_body: None,
+                    is_encrypted: true,
+                    decryption_error: Some(e.to_string()),
+                    replaces_event_id: None,
+                    in_reply_to: None,
+                    attachment: None,
+                    send_state: SendState::Sent,
+                });
+            }
+        }
+    }
+
+    match timeline_event {
+        AnySyncTimelineEvent::MessageLike(AnySyncMessageLikeEvent::RoomMessage(
+            SyncMessageLikeEvent::Original(msg),
+        )) => Some(message_to_timeline_item(room.room_id(), msg, is_encrypted)),
+        _ => None,
+    }
+}
+
+/// Map an `m.room.message` event onto the Dart-facing item.
+/// Edits are reported with the replacement content, replies with the plain-text fallback removed.
+pub(crate) fn message_to_timeline_item(
+    room_id: &RoomId,
+    ev: OriginalSyncRoomMessageEvent,
+    is_encrypted: bool,
+) -> TimelineItem {
+    use matrix_sdk::ruma::events::room::message::sanitize::remove_plain_reply_fallback;
+
+    let mut replaces_event_id = None;
+    let mut in_reply_to = None;
+    // The top-level content of an edit is a "* text" fallback; the real text is in m.new_content
+    let msgtype = match ev.content.relates_to {
+        Some(Relation::Replacement(replacement)) => {
+            replaces_event_id = Some(replacement.event_id.to_string());
+            replacement.new_content.msgtype
+        }
+        Some(Relation::Reply { in_reply_to: reply }) => {
+            in_reply_to = Some(reply.event_id.to_string());
+            ev.content.msgtype
+        }
+        _ => ev.content.msgtype,
+    };
+
+    let (kind, mut body, mut formatted_body, attachment) = match msgtype {
+        MessageType::Text(c) => (MessageKind::Text, c.body, c.formatted.map(|f| f.body), None),
+        MessageType::Notice(c) => (MessageKind::Notice, c.body, c.formatted.map(|f| f.body), None),
+        MessageType::Emote(c) => (MessageKind::Emote, c.body, c.formatted.map(|f| f.body), None),
+        MessageType::Image(c) => {
+            let mut attachment = media_attachment(&c.source, c.body.clone());
+            if let Some(info) = c.info {
+                attachment.mimetype = info.mimetype;
+                attachment.size = info.size.map(u64::from);
+                attachment.width = uint_to_u32(info.width);
+                attachment.height = uint_to_u32(info.height);
+                attachment.thumbnail_source = info.thumbnail_source.as_ref().and_then(media_source_json);
+            }
+            (MessageKind::Image, c.body, None, Some(attachment))
+        }
+        MessageType::File(c) => {
+            // `body` doubles as the filename unless a separate one is given
+            let filename = c.filename.unwrap_or_else(|| c.body.clone());
+            let mut attachment = media_attachment(&c.source, filename);
+            if let Some(info) = c.info {
+                attachment.mimetype = info.mimetype;
+                attachment.size = info.size.map(u64::from);
+                attachment.thumbnail_source = info.thumbnail_source.as_ref().and_then(media_source_json);
+            }
+            (MessageKind::File, c.body, None, Some(attachment))
+        }
+        MessageType::Video(c) => {
+            let mut attachment = media_attachment(&c.source, c.body.clone());
+            if let Some(info) = c.info {
+                attachment.mimetype = info.mimetype;
+                attachment.size = info.size.map(u64::from);
+                attachment.width = uint_to_u32(info.width);
+                attachment.height = uint_to_u32(info.height);
+                attachment.duration_ms = info.duration.and_then(|d| u64::try_from(d.as_millis()).ok());
+                attachment.thumbnail_source = info.thumbnail_source.as_ref().and_then(media_source_json);
+            }
+            (MessageKind::Video, c.body, None, Some(attachment))
+        }
+        MessageType::Audio(c) => {
+            let mut attachment = media_attachment(&c.source, c.body.clone());
+            if let Some(info) = c.info {
+                attachment.mimetype = info.mimetype;
+                attachment.size = info.size.map(u64::from);
+                attachment.duration_ms = info.duration.and_then(|d| u64::try_from(d.as_millis()).ok());
+            }
+            (MessageKind::Audio, c.body, None, Some(attachment))
+        }
+        MessageType::Location(c) => (MessageKind::Location, c.body, None, None),
+        other => (MessageKind::Other, other.body().to_string(), None, None),
+    };
+
+    if in_reply_to.is_some() {
+        body = remove_plain_reply_fallback(&body).to_string();
+        formatted_body = formatted_body.map(|html| match html.find("</mx-reply>") {
+            Some(end) => html[end + "</mx-reply>".len()..].to_string(),
+            None => html,
+        });
+    }
+
+    TimelineItem {
+        room_id: room_id.to_string(),
+        event_id: ev.event_id.to_string(),
+        sender: ev.sender.to_string(),
+        timestamp_ms: ev.origin_server_ts.get().into(),
+        kind,
+        body,
+        formatted_body,
+        is_encrypted,
+        decryption_error: None,
+        replaces_event_id,
+        in_reply_to,
+        attachment,
+        send_state: SendState::Sent,
+    }
+}
+
+fn media_attachment(source: &MediaSource, filename: String) -> Attachment {
+    let (url, is_encrypted) = match source {
+        MediaSource::Plain(uri) => (uri.to_string(), false),
+        MediaSource::Encrypted(file) => (file.url.to_string(), true),
+    };
+    Attachment {
+        url,
+        source: media_source_json(source).unwrap_or_default(),
+        is_encrypted,
+        filename,
+        mimetype: None,
+        size: None,
+        width: None,
+        height: None,
+        duration_ms: None,
+        thumbnail_source: None,
+    }
+}
+
+fn media_source_json(source: &MediaSource) -> Option<String> {
+    serde_json::to_string(source).ok()
+}
+
+fn uint_to_u32(value: Option<UInt>) -> Option<u32> {
+    value.and_then(|v| u32::try_from(u64::from(v)).ok())
 }
 
+/// Start the background sync loop of a client. Live timeline items go to its `subscribe_events` sink,
+/// its status to `subscribe_sync_status`. The loop retries failed syncs until `stop_sync`, except
+/// when the session has expired: then it stops by itself. Fails while a `stop_sync` is still
+/// waiting for the previous loop to finish.
+/// Uses sliding sync if `init` configured it and the server supports it, classic sync otherwise.
+/// Event handlers are registered once per client, so stopping and starting again doesn't deliver
+/// events twice.
 #[frb]
-pub fn start_sync() -> Result<(), String> {
+pub fn start_sync(client: ClientHandle) -> Result<(), BridgeError> {
     let rt = get_rt();
+    let state = client_state(client)?;
     // Spawn a background sync loop if not already running
-    let cell = SYNC_HANDLE.get_or_init(|| Mutex::new(None));
-    {
-        let guard = cell.lock().map_err(|_| "failed to lock sync handle".to_string())?;
-        if guard.is_some() {
+    let mut guard = state.sync_task.lock().map_err(|_| "failed to lock sync handle".to_string())?;
+    if let Some(task) = guard.as_ref() {
+        if !task.stopping() {
             return Ok(());
         }
+        // A second loop would write the sync token and forward events alongside the old one.
+        // One whose `stop_sync` gave up waiting may have ended by now, and is replaced
+        if !task.handle.is_finished() {
+            return Err("Sync is still stopping; start it again once stop_sync has returned".to_string().into());
+        }
     }
+    sync_loop::register_handlers(&state);
+    let handle_id = client.id;
+    let client = state.client.clone();
+    state.set_sync_status(SyncStatus::new(SyncState::InitialSync));
+    let (stop, stop_requested) = watch::channel(false);
+    let (finished, done) = watch::channel(());
+    let sync = sync_loop::run(handle_id, client, state.sliding_sync_config(), stop_requested);
     let handle = rt.spawn(async move {
-        if let Some(client) = CLIENT.get() {
-            let client = client.clone();
-            // Auto-accept room invitations
-            client.add_event_handler(|room_member: StrippedRoomMemberEvent, client: Client, room: Room| async move {
-                if room_member.state_key != client.user_id().unwrap() {
-                    return;
-                }
-                if room_member.content.membership != matrix_sdk::ruma::events::room::member::MembershipState::Invite {
-                    return;
-                }
-                eprintln!("[Bridge][sync] Auto-accepting invitation to room: {}", room.room_id());
-                if let Err(e) = room.join().await {
-                    eprintln!("[Bridge][sync] Failed to auto-accept invitation: {}", e);
-                } else {
-                    eprintln!("[Bridge][sync] Successfully auto-accepted invitation");
-                }
-            });
-
-            // Register event handler to forward message events to Dart via StreamSink
-            // Note: OriginalSyncRoomMessageEvent is already decrypted by the SDK
-            client.add_event_handler(|ev: OriginalSyncRoomMessageEvent, room: Room| async move {
-                let rid = room.room_id().to_string();
-                let eid = ev.event_id.to_string();
-                let sender = ev.sender.to_string();
-                // Convert timestamp to seconds (consistent with get_room_messages)
-                let ts: i64 = ev.origin_server_ts.as_secs().into();
-                // Extract plaintext (SDK has already decrypted if keys available)
-                let content = match &ev.content.msgtype {
-                    MessageType::Text(t) => Some(t.body.clone()),
-                    MessageType::Notice(n) => Some(n.body.clone()),
-                    MessageType::Emote(e) => Some(e.body.clone()),
-                    _ => None,
-                };
-                // If we have content, message was successfully decrypted (or was plaintext)
-                // Mark as encrypted=false since the content is available
-                let is_encrypted = false;
-                eprintln!("[Bridge][sync] Emitting event to Dart: room={} event={} content={:?}", &rid, &eid, &content);
-                let evt = MatrixEvent { room_id: rid, event_id: eid, sender, ts, content, is_encrypted };
-                if let Some(cell) = EVENT_SINK.get() {
-                    if let Ok(mut guard) = cell.lock() {
-                        if let Some(sink) = guard.as_mut() {
-                            match sink.add(evt) {
-                                Ok(_) => eprintln!("[Bridge][sync] Event emitted successfully"),
-                                Err(e) => eprintln!("[Bridge][sync] Failed to emit event: {:?}", e),
-                            }
-                        } else {
-                            eprintln!("[Bridge][sync] No sink available");
-                        }
-                    }
-                }
-            });
-
-            // Use long-polling sync for real-time updates
-            // Important: Keep the same settings object so sync token gets updated between calls
-            let mut settings = SyncSettings::default().timeout(std::time::Duration::from_secs(30));
-            eprintln!("[Bridge][sync] Sync loop starting with 30s long-polling...");
-            let mut sync_count = 0;
-            loop {
-                sync_count += 1;
-                if sync_count % 5 == 1 {
-                    eprintln!("[Bridge][sync] Sync iteration {} (long-polling for 30s or until events arrive)", sync_count);
-                }
-                match client.sync_once(settings.clone()).await {
-                    Ok(response) => {
-                        // Update settings with the new sync token for incremental sync
-                        settings = settings.token(response.next_batch);
-                        if sync_count <= 5 {
-                            eprintln!("[Bridge][sync] Sync successful, got token for next iteration");
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("[Bridge][sync] error: {}", e);
-                        // brief backoff on error
-                        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-                    }
-                }
-                // No delay needed with long-polling - server holds connection until events arrive
-            }
-        }
+        sync.await;
+        drop(finished);
     });
-    {
-        let mut guard = cell.lock().map_err(|_| "failed to lock sync handle".to_string())?;
-        *guard = Some(handle);
-    }
+    *guard = Some(SyncTask { handle, stop, done });
     Ok(())
 }
 
+/// Tell the bridge the device is online again (e.g. from a connectivity listener), so sync loops
+/// waiting to retry after a network error retry right away. Healthy loops aren't affected, and
+/// waits for a server rate limit still run their course.
 #[frb]
-pub fn stop_sync() -> Result<(), String> {
-    if let Some(cell) = SYNC_HANDLE.get() {
-        if let Ok(mut guard) = cell.lock() {
-            if let Some(handle) = guard.take() {
-                handle.abort();
-            }
+pub fn notify_network_available() -> Result<(), BridgeError> {
+    for state in clients::list() {
+        // Only loops that are backing off; a permit left on a healthy one would skip the backoff
+        // after its next failure. A loop that set its status but isn't waiting yet keeps the permit
+        if matches!(state.sync_status().state, SyncState::Backoff | SyncState::Offline) {
+            state.sync_wakeup.notify_one();
         }
     }
     Ok(())
 }
+
+/// Filter what classic sync fetches from the next sync request on, or stop filtering with None.
+/// The filter is uploaded once per set of options and reused from then on, across app starts too.
+/// Sliding sync has its own limits, see `SlidingSyncConfig`.
+#[frb]
+pub fn set_sync_filter(client: ClientHandle, filter: Option<SyncFilterConfig>) -> Result<(), BridgeError> {
+    client_state(client)?.set_sync_filter(filter);
+    Ok(())
+}
+
+/// Move the sliding sync window to the `count` rooms of the room list starting at `start`, e.g. as
+/// the user scrolls. Does nothing under classic sync, which syncs every room anyway.
+#[frb]
+pub async fn set_visible_rooms(
+    client: ClientHandle,
+    start: u32,
+    count: u32,
+    options: Option<CallOptions>,
+) -> Result<(), BridgeError> {
+    let Some(sliding) = client_state(client)?.sliding_sync() else {
+        return Ok(());
+    };
+    calls::run("set_visible_rooms", options, async move {
+        sync_loop::set_window(&sliding, start, count).await;
+        Ok(())
+    })
+    .await
+}
+
+/// Stop the sync loop of a client. The sync request in flight (a long poll of up to 30 s) is
+/// finished and its response stored first, so the returned future completes once sync has
+/// stopped. A timeout from `options` only ends the wait; the loop still stops.
+#[frb]
+pub async fn stop_sync(client: ClientHandle, options: Option<CallOptions>) -> Result<(), BridgeError> {
+    let state = client_state(client)?;
+    calls::run("stop_sync", options, async move {
+        state.stop_sync().await;
+        Ok(())
+    })
+    .await
+}
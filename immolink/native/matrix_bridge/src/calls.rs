@@ -0,0 +1,124 @@
+// Timeouts and cancellation for the async bridge functions.
+//
+// Exported functions are `async`, so FRB awaits them instead of parking a
+// worker thread in `block_on`. Their work is spawned onto the bridge runtime
+// (where the sync loops live) and the FRB side only awaits the join handle.
+// Dart can't drop a future it is waiting on, so cancellation goes through
+// `CancelToken`s: cancelling one aborts every call started with it, and every
+// call started with it later. A released token is inert.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use tokio::task::AbortHandle;
+
+use crate::bridge::{BridgeError, BridgeErrorKind, CallOptions, CancelToken};
+
+/// Cancel tokens and the calls running under live ones. Released tokens are removed.
+static TOKENS: Lazy<Mutex<HashMap<u64, TokenState>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_TOKEN: AtomicU64 = AtomicU64::new(1);
+/// Timeout for calls that don't set one, in ms; 0 means none
+static DEFAULT_TIMEOUT_MS: AtomicU64 = AtomicU64::new(0);
+
+enum TokenState {
+    Live(Vec<AbortHandle>),
+    Cancelled,
+}
+
+pub(crate) fn new_token() -> CancelToken {
+    let id = NEXT_TOKEN.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut tokens) = TOKENS.lock() {
+        tokens.insert(id, TokenState::Live(Vec::new()));
+    }
+    CancelToken { id }
+}
+
+/// Abort every call running under `token`. Calls started with it afterwards fail right away.
+/// A released token stays released.
+pub(crate) fn cancel(token: CancelToken) {
+    let calls = TOKENS
+        .lock()
+        .ok()
+        .and_then(|mut tokens| match std::mem::replace(tokens.get_mut(&token.id)?, TokenState::Cancelled) {
+            TokenState::Live(calls) => Some(calls),
+            TokenState::Cancelled => None,
+        })
+        .unwrap_or_default();
+    if !calls.is_empty() {
+        eprintln!("[Bridge][cancel] Cancelling {} call(s) of token {}", calls.len(), token.id);
+    }
+    for call in calls {
+        call.abort();
+    }
+}
+
+/// Forget `token` without cancelling the calls that are still running under it. Calls started with
+/// it afterwards aren't tied to it.
+pub(crate) fn release(token: CancelToken) {
+    if let Ok(mut tokens) = TOKENS.lock() {
+        tokens.remove(&token.id);
+    }
+}
+
+pub(crate) fn set_default_timeout(timeout_ms: Option<u64>) {
+    DEFAULT_TIMEOUT_MS.store(timeout_ms.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// Run `fut` on the bridge runtime, bounded by the timeout and cancel token in `options`.
+pub(crate) async fn run<T, F>(name: &'static str, options: Option<CallOptions>, fut: F) -> Result<T, BridgeError>
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T, BridgeError>> + Send + 'static,
+{
+    let options = options.unwrap_or_default();
+    let timeout = options
+        .timeout_ms
+        .or_else(|| Some(DEFAULT_TIMEOUT_MS.load(Ordering::Relaxed)).filter(|ms| *ms > 0))
+        .map(Duration::from_millis);
+
+    let task = crate::bridge::get_rt().spawn(async move {
+        match timeout {
+            Some(timeout) => tokio::time::timeout(timeout, fut).await.unwrap_or_else(|_| {
+                eprintln!("[Bridge][{}] Timed out after {} ms", name, timeout.as_millis());
+                Err(BridgeError::new(
+                    BridgeErrorKind::TimedOut,
+                    format!("{} timed out after {} ms", name, timeout.as_millis()),
+                ))
+            }),
+            None => fut.await,
+        }
+    });
+
+    if let Some(token) = options.cancel {
+        let mut tokens = TOKENS.lock().map_err(|_| "failed to lock cancel tokens".to_string())?;
+        match tokens.get_mut(&token.id) {
+            Some(TokenState::Live(calls)) => {
+                calls.retain(|call| !call.is_finished());
+                calls.push(task.abort_handle());
+            }
+            Some(TokenState::Cancelled) => {
+                task.abort();
+                return Err(cancelled(name));
+            }
+            // Released (or never handed out)
+            None => {}
+        }
+    }
+
+    match task.await {
+        Ok(result) => result,
+        Err(e) if e.is_cancelled() => {
+            eprintln!("[Bridge][{}] Cancelled", name);
+            Err(cancelled(name))
+        }
+        Err(e) => Err(format!("{} failed: {}", name, e).into()),
+    }
+}
+
+fn cancelled(name: &str) -> BridgeError {
+    BridgeError::new(BridgeErrorKind::Cancelled, format!("{} was cancelled", name))
+}
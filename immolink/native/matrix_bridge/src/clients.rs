@@ -0,0 +1,417 @@
+// Registry of live Matrix clients, addressed from Dart through `ClientHandle`.
+//
+// Every entry owns what belongs to one client: the store location, its media
+// cache, the background sync task and its status, the sync filter, invite
+// policy and sliding sync setup, the Dart event, room list, invite and sync
+// status sinks, and the latest
+// message of each room for the room list. Disposing an entry
+// aborts its sync task, so a new client can be built on the same store;
+// `stop_sync` instead lets the task finish the sync in flight.
+// Several clients (one per account) can be live at once; besides their own
+// sinks, all of them also feed the shared all-accounts sink.
+// A client whose store was rebuilt (see store_recovery) keeps its handle id.
+// A store being opened is reserved until its client is registered, so two
+// concurrent `init`s can't build clients on the same store.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+use matrix_sdk::event_handler::EventHandlerHandle;
+use matrix_sdk::{Client, SlidingSync};
+use once_cell::sync::Lazy;
+use tokio::sync::{watch, Notify};
+use tokio::task::JoinHandle;
+
+use crate::bridge::{
+    AccountTimelineItem, BridgeError, BridgeErrorKind, ClientHandle, InviteInfo, InvitePolicy, InvitePolicyKind,
+    RoomListUpdate, SlidingSyncConfig, StoreRecoveryEvent,
+    SyncFilterConfig, SyncState, SyncStatus, TimelineItem,
+};
+use crate::frb_generated::StreamSink;
+use crate::media_cache::MediaCache;
+use crate::store_recovery::Quarantine;
+
+static CLIENTS: Lazy<Mutex<HashMap<u32, Arc<ClientState>>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+/// Data dirs a client is being built on; only changed with `CLIENTS` locked
+static RESERVED: Lazy<Mutex<HashSet<PathBuf>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+static ALL_ACCOUNTS_SINK: Lazy<Mutex<Option<StreamSink<AccountTimelineItem>>>> = Lazy::new(|| Mutex::new(None));
+static STORE_RECOVERY_SINK: Lazy<Mutex<Option<StreamSink<StoreRecoveryEvent>>>> = Lazy::new(|| Mutex::new(None));
+
+pub(crate) struct ClientState {
+    pub(crate) id: u32,
+    pub(crate) client: Client,
+    pub(crate) data_dir: PathBuf,
+    /// Store passphrase, kept to rebuild the store after a mismatch and to open its key backup
+    pub(crate) passphrase: Option<String>,
+    pub(crate) media_cache: MediaCache,
+    pub(crate) sync_task: Mutex<Option<SyncTask>>,
+    /// Handlers the sync loop feeds, registered by the first `start_sync` and kept from then on
+    pub(crate) event_handlers: Mutex<Vec<EventHandlerHandle>>,
+    sync_status: Mutex<SyncStatus>,
+    /// Cuts short the sync loop's wait before retrying after a network error
+    pub(crate) sync_wakeup: Notify,
+    /// What classic sync leaves out, from `set_sync_filter`
+    sync_filter: Mutex<Option<SyncFilterConfig>>,
+    /// Which invites are joined without asking, from `init`
+    invite_policy: Mutex<InvitePolicy>,
+    /// Sync with sliding sync instead of classic sync, from `init`
+    sliding_sync_config: Mutex<Option<SlidingSyncConfig>>,
+    /// The running sliding sync, while the sync loop uses it
+    sliding_sync: Mutex<Option<SlidingSync>>,
+    pub(crate) sync_status_sink: Mutex<Option<StreamSink<SyncStatus>>>,
+    pub(crate) event_sink: Mutex<Option<StreamSink<TimelineItem>>>,
+    pub(crate) room_list_sink: Mutex<Option<StreamSink<RoomListUpdate>>>,
+    pub(crate) invite_sink: Mutex<Option<StreamSink<InviteInfo>>>,
+    /// Newest message seen per room id; None once a room was looked at and had none
+    pub(crate) latest_items: Mutex<HashMap<String, Option<TimelineItem>>>,
+    /// Keys of a quarantined store, imported once the client is logged in
+    pub(crate) pending_key_import: Mutex<Option<Quarantine>>,
+}
+
+impl ClientState {
+    fn new(id: u32, client: Client, data_dir: PathBuf, passphrase: Option<String>) -> Self {
+        Self {
+            id,
+            client,
+            media_cache: MediaCache::new(data_dir.join("media_cache")),
+            data_dir,
+            passphrase,
+            sync_task: Mutex::new(None),
+            event_handlers: Mutex::new(Vec::new()),
+            sync_status: Mutex::new(SyncStatus::new(SyncState::Idle)),
+            sync_wakeup: Notify::new(),
+            sync_filter: Mutex::new(None),
+            invite_policy: Mutex::new(InvitePolicy::accept_all()),
+            sliding_sync_config: Mutex::new(None),
+            sliding_sync: Mutex::new(None),
+            sync_status_sink: Mutex::new(None),
+            event_sink: Mutex::new(None),
+            room_list_sink: Mutex::new(None),
+            invite_sink: Mutex::new(None),
+            latest_items: Mutex::new(HashMap::new()),
+            pending_key_import: Mutex::new(None),
+        }
+    }
+
+    /// Push a live timeline item to this client's Dart subscriber and the all-accounts one, if any.
+    pub(crate) fn emit(&self, item: TimelineItem) {
+        self.remember_latest(&item);
+        if let Ok(mut guard) = ALL_ACCOUNTS_SINK.lock() {
+            if let Some(sink) = guard.as_mut() {
+                let tagged = AccountTimelineItem {
+                    client: ClientHandle { id: self.id },
+                    user_id: self.client.user_id().map(|u| u.to_string()).unwrap_or_default(),
+                    item: item.clone(),
+                };
+                if let Err(e) = sink.add(tagged) {
+                    eprintln!("[Bridge][sync] Failed to emit event to all-accounts sink: {:?}", e);
+                }
+            }
+        }
+        if let Ok(mut guard) = self.event_sink.lock() {
+            if let Some(sink) = guard.as_mut() {
+                match sink.add(item) {
+                    Ok(_) => eprintln!("[Bridge][sync] Event emitted successfully"),
+                    Err(e) => eprintln!("[Bridge][sync] Failed to emit event: {:?}", e),
+                }
+            } else {
+                eprintln!("[Bridge][sync] No sink available");
+            }
+        }
+    }
+
+    /// Keep `item` as its room's latest message unless a newer one is known.
+    pub(crate) fn remember_latest(&self, item: &TimelineItem) {
+        if let Ok(mut latest) = self.latest_items.lock() {
+            let entry = latest.entry(item.room_id.clone()).or_default();
+            if entry.as_ref().is_none_or(|known| known.timestamp_ms <= item.timestamp_ms) {
+                *entry = Some(item.clone());
+            }
+        }
+    }
+
+    pub(crate) fn emit_room_list(&self, update: RoomListUpdate) {
+        if let Ok(mut guard) = self.room_list_sink.lock() {
+            if let Some(sink) = guard.as_mut() {
+                if let Err(e) = sink.add(update) {
+                    eprintln!("[Bridge][rooms] Failed to emit room list update: {:?}", e);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn emit_invite(&self, invite: InviteInfo) {
+        if let Ok(mut guard) = self.invite_sink.lock() {
+            if let Some(sink) = guard.as_mut() {
+                if let Err(e) = sink.add(invite) {
+                    eprintln!("[Bridge][invites] Failed to emit invite: {:?}", e);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn sync_status(&self) -> SyncStatus {
+        self.sync_status.lock().map(|status| status.clone()).unwrap_or_else(|_| SyncStatus::new(SyncState::Idle))
+    }
+
+    /// Record the sync loop's status and push it to Dart if it changed.
+    pub(crate) fn set_sync_status(&self, status: SyncStatus) {
+        let Ok(mut current) = self.sync_status.lock() else {
+            return;
+        };
+        if current.state == status.state && current.attempt == status.attempt {
+            return;
+        }
+        eprintln!("[Bridge][sync] Status: {:?} (attempt {})", status.state, status.attempt);
+        *current = status.clone();
+        drop(current);
+        if let Ok(mut guard) = self.sync_status_sink.lock() {
+            if let Some(sink) = guard.as_mut() {
+                if let Err(e) = sink.add(status) {
+                    eprintln!("[Bridge][sync] Failed to emit sync status: {:?}", e);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn sync_filter(&self) -> Option<SyncFilterConfig> {
+        self.sync_filter.lock().ok().and_then(|filter| filter.clone())
+    }
+
+    /// Used from the next sync request on.
+    pub(crate) fn set_sync_filter(&self, filter: Option<SyncFilterConfig>) {
+        if let Ok(mut guard) = self.sync_filter.lock() {
+            *guard = filter;
+        }
+    }
+
+    pub(crate) fn invite_policy(&self) -> InvitePolicy {
+        self.invite_policy.lock().map(|policy| policy.clone()).unwrap_or_else(|_| InvitePolicy::accept_all())
+    }
+
+    pub(crate) fn set_invite_policy(&self, policy: InvitePolicy) {
+        if let Ok(mut guard) = self.invite_policy.lock() {
+            *guard = policy;
+        }
+    }
+
+    pub(crate) fn sliding_sync_config(&self) -> Option<SlidingSyncConfig> {
+        self.sliding_sync_config.lock().ok().and_then(|config| config.clone())
+    }
+
+    /// Takes effect the next time sync is started.
+    pub(crate) fn set_sliding_sync_config(&self, config: Option<SlidingSyncConfig>) {
+        if let Ok(mut guard) = self.sliding_sync_config.lock() {
+            *guard = config;
+        }
+    }
+
+    /// The sliding sync the sync loop runs; None under classic sync or when not syncing.
+    pub(crate) fn sliding_sync(&self) -> Option<SlidingSync> {
+        self.sliding_sync.lock().ok().and_then(|sliding| sliding.clone())
+    }
+
+    pub(crate) fn set_sliding_sync(&self, sliding: Option<SlidingSync>) {
+        if let Ok(mut guard) = self.sliding_sync.lock() {
+            *guard = sliding;
+        }
+    }
+
+    /// Whether a sync loop is running and hasn't been asked to stop.
+    pub(crate) fn is_syncing(&self) -> bool {
+        self.sync_task.lock().map(|guard| guard.as_ref().is_some_and(|task| !task.stopping())).unwrap_or(false)
+    }
+
+    /// Ask the sync loop to stop once the sync in flight is processed, and wait until it has.
+    /// The loop keeps its slot until then, so `start_sync` can't start a second one meanwhile.
+    pub(crate) async fn stop_sync(&self) {
+        let mut done = {
+            let Ok(guard) = self.sync_task.lock() else {
+                return;
+            };
+            let Some(task) = guard.as_ref() else {
+                return;
+            };
+            // Later callers only wait along
+            if !task.stop.send_replace(true) {
+                if let Some(sliding) = self.sliding_sync() {
+                    if let Err(e) = sliding.stop_sync() {
+                        eprintln!("[Bridge][sync] Failed to stop sliding sync: {}", e);
+                    }
+                }
+            }
+            task.done.clone()
+        };
+        // Fails once the loop has ended, panicked or been aborted
+        while done.changed().await.is_ok() {}
+        if let Ok(mut guard) = self.sync_task.lock() {
+            if guard.as_ref().is_some_and(|task| task.done.same_channel(&done)) {
+                guard.take();
+            }
+        }
+        self.set_sliding_sync(None);
+        self.set_sync_status(SyncStatus::new(SyncState::Idle));
+    }
+
+    /// Abort the background sync task right away, if one is running.
+    pub(crate) fn abort_sync(&self) {
+        if let Ok(mut guard) = self.sync_task.lock() {
+            if let Some(task) = guard.take() {
+                task.handle.abort();
+                self.set_sliding_sync(None);
+                self.set_sync_status(SyncStatus::new(SyncState::Idle));
+            }
+        }
+    }
+}
+
+/// A running sync loop and the signal asking it to stop.
+pub(crate) struct SyncTask {
+    pub(crate) handle: JoinHandle<()>,
+    pub(crate) stop: watch::Sender<bool>,
+    /// Closed when the loop has ended
+    pub(crate) done: watch::Receiver<()>,
+}
+
+impl SyncTask {
+    /// Whether `stop_sync` asked the loop to stop; it may still be finishing its last sync.
+    pub(crate) fn stopping(&self) -> bool {
+        *self.stop.borrow()
+    }
+}
+
+impl SyncStatus {
+    pub(crate) fn new(state: SyncState) -> Self {
+        Self { state, attempt: 0, next_retry_ms: None, error: None }
+    }
+}
+
+impl InvitePolicy {
+    /// What clients did before invite policies existed, and still do without one
+    pub(crate) fn accept_all() -> Self {
+        Self { kind: InvitePolicyKind::AcceptAll, allowed_users: Vec::new(), allowed_servers: Vec::new() }
+    }
+}
+
+/// Register a client built on `data_dir` and return its handle id.
+pub(crate) fn insert(client: Client, data_dir: PathBuf, passphrase: Option<String>) -> u32 {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let state = ClientState::new(id, client, data_dir, passphrase);
+    CLIENTS.lock().unwrap_or_else(|e| e.into_inner()).insert(id, Arc::new(state));
+    id
+}
+
+/// Register a client rebuilt on the store of a removed one under the old handle id.
+/// The Dart sinks taken from the old state (`take_sinks`) carry over.
+pub(crate) fn reinsert(
+    id: u32,
+    client: Client,
+    data_dir: PathBuf,
+    passphrase: Option<String>,
+    sinks: Sinks,
+) -> Arc<ClientState> {
+    let state = ClientState::new(id, client, data_dir, passphrase);
+    *state.event_sink.lock().unwrap_or_else(|e| e.into_inner()) = sinks.events;
+    *state.room_list_sink.lock().unwrap_or_else(|e| e.into_inner()) = sinks.room_list;
+    *state.invite_sink.lock().unwrap_or_else(|e| e.into_inner()) = sinks.invites;
+    *state.sync_status_sink.lock().unwrap_or_else(|e| e.into_inner()) = sinks.sync_status;
+    let state = Arc::new(state);
+    CLIENTS.lock().unwrap_or_else(|e| e.into_inner()).insert(id, state.clone());
+    state
+}
+
+/// The Dart subscriptions of a client, moved over when its store is rebuilt.
+pub(crate) struct Sinks {
+    events: Option<StreamSink<TimelineItem>>,
+    room_list: Option<StreamSink<RoomListUpdate>>,
+    invites: Option<StreamSink<InviteInfo>>,
+    sync_status: Option<StreamSink<SyncStatus>>,
+}
+
+pub(crate) fn take_sinks(state: &ClientState) -> Sinks {
+    Sinks {
+        events: state.event_sink.lock().ok().and_then(|mut sink| sink.take()),
+        room_list: state.room_list_sink.lock().ok().and_then(|mut sink| sink.take()),
+        invites: state.invite_sink.lock().ok().and_then(|mut sink| sink.take()),
+        sync_status: state.sync_status_sink.lock().ok().and_then(|mut sink| sink.take()),
+    }
+}
+
+pub(crate) fn get(id: u32) -> Result<Arc<ClientState>, BridgeError> {
+    CLIENTS.lock().unwrap_or_else(|e| e.into_inner()).get(&id).cloned().ok_or_else(|| {
+        BridgeError::new(
+            BridgeErrorKind::NotInitialized,
+            format!("Unknown client handle {} (not initialized or already disposed)", id),
+        )
+    })
+}
+
+/// All live clients, ordered by handle id.
+pub(crate) fn list() -> Vec<Arc<ClientState>> {
+    let mut states: Vec<_> = CLIENTS.lock().unwrap_or_else(|e| e.into_inner()).values().cloned().collect();
+    states.sort_by_key(|state| state.id);
+    states
+}
+
+pub(crate) fn set_all_accounts_sink(sink: StreamSink<AccountTimelineItem>) {
+    *ALL_ACCOUNTS_SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(sink);
+}
+
+pub(crate) fn set_store_recovery_sink(sink: StreamSink<StoreRecoveryEvent>) {
+    *STORE_RECOVERY_SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(sink);
+}
+
+pub(crate) fn emit_store_recovery(event: StoreRecoveryEvent) {
+    if let Ok(mut guard) = STORE_RECOVERY_SINK.lock() {
+        if let Some(sink) = guard.as_mut() {
+            if let Err(e) = sink.add(event) {
+                eprintln!("[Bridge][recovery] Failed to emit recovery event: {:?}", e);
+            }
+        }
+    }
+}
+
+/// Who has a data dir, from `claim_data_dir`.
+pub(crate) enum DataDirClaim {
+    /// A live client uses it
+    Live(u32, Arc<ClientState>),
+    /// Another call is building a client on it
+    Busy,
+    /// It's free and now reserved for the caller
+    Reserved(DataDirReservation),
+}
+
+/// Keeps a data dir reserved until dropped. Drop it after `insert`ing the client built on it, or
+/// on error.
+pub(crate) struct DataDirReservation {
+    data_dir: PathBuf,
+}
+
+impl Drop for DataDirReservation {
+    fn drop(&mut self) {
+        RESERVED.lock().unwrap_or_else(|e| e.into_inner()).remove(&self.data_dir);
+    }
+}
+
+/// Reserve `data_dir` unless a live client or another reservation has it.
+pub(crate) fn claim_data_dir(data_dir: &Path) -> DataDirClaim {
+    let clients = CLIENTS.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((id, state)) = clients.iter().find(|(_, state)| state.data_dir == data_dir) {
+        return DataDirClaim::Live(*id, state.clone());
+    }
+    if !RESERVED.lock().unwrap_or_else(|e| e.into_inner()).insert(data_dir.to_path_buf()) {
+        return DataDirClaim::Busy;
+    }
+    DataDirClaim::Reserved(DataDirReservation { data_dir: data_dir.to_path_buf() })
+}
+
+/// Unregister a client and abort its sync task. Other clones of the state die with their last user.
+pub(crate) fn remove(id: u32) -> Option<Arc<ClientState>> {
+    let state = CLIENTS.lock().unwrap_or_else(|e| e.into_inner()).remove(&id)?;
+    state.abort_sync();
+    take_sinks(&state);
+    Some(state)
+}
@@ -0,0 +1,127 @@
+// Finding the direct message room with a user.
+//
+// Other clients (Element, the web dashboard) only show a room as a DM if it
+// is listed under the other user in the `m.direct` account data, so that list
+// is checked first, read straight from the server since sync may lag behind.
+// DMs created by older builds, which never recorded them there, are found by
+// the `is_direct` flag of their invite and recorded on the way.
+
+use matrix_sdk::ruma::events::direct::DirectEventContent;
+use matrix_sdk::deserialized_responses::SyncOrStrippedState;
+use matrix_sdk::ruma::events::room::member::{MembershipState, RoomMemberEventContent};
+use matrix_sdk::ruma::events::{GlobalAccountDataEventType, SyncStateEvent};
+use matrix_sdk::ruma::{OwnedRoomId, OwnedUserId, UserId};
+use matrix_sdk::{Client, RoomState, room::Room};
+
+use crate::bridge::BridgeError;
+
+/// The room the client already shares with `user_id` as a DM, if any.
+/// A DM the user invited us to is joined. Rooms found other than through `m.direct` are added to it.
+pub(crate) async fn find_dm(client: &Client, user_id: &UserId) -> Result<Option<Room>, BridgeError> {
+    let direct = fetch_direct(client).await?;
+    let listed: Vec<OwnedRoomId> = direct.get(user_id).cloned().unwrap_or_default();
+
+    // Newest entries are appended, so prefer those
+    for room_id in listed.iter().rev() {
+        let Some(room) = client.get_room(room_id) else {
+            continue;
+        };
+        if room.state() == RoomState::Joined && is_active_member(&room, user_id).await? {
+            eprintln!("[Bridge][dm] Reusing {} from m.direct", room_id);
+            return Ok(Some(room));
+        }
+    }
+
+    // A room with just the two of us and no name of its own is a DM if it was opened as one, even
+    // if nobody recorded it. Without the flag it may just be a group room down to two members
+    for room in client.joined_rooms() {
+        if listed.contains(&room.room_id().to_owned()) || room.name().is_some() || room.active_members_count() != 2 {
+            continue;
+        }
+        if is_active_member(&room, user_id).await? && opened_as_direct(&room, user_id).await? {
+            eprintln!("[Bridge][dm] Reusing {} found by membership, adding it to m.direct", room.room_id());
+            record(client, direct, &room, user_id).await?;
+            return Ok(Some(room));
+        }
+    }
+
+    // The other side may have opened the DM first
+    for room in client.invited_rooms() {
+        if !room.is_direct().await.unwrap_or(false) {
+            continue;
+        }
+        let invite = room.invite_details().await?;
+        if invite.inviter.as_ref().map(|m| m.user_id()) != Some(user_id) {
+            continue;
+        }
+        eprintln!("[Bridge][dm] Accepting DM invite to {} from {}", room.room_id(), user_id);
+        room.join().await?;
+        record(client, direct, &room, user_id).await?;
+        return Ok(Some(room));
+    }
+
+    Ok(None)
+}
+
+/// Record `room` as a DM with `user_id` in `m.direct`, unless it is listed already.
+pub(crate) async fn mark_as_dm(client: &Client, room: &Room, user_id: &UserId) -> Result<(), BridgeError> {
+    record(client, fetch_direct(client).await?, room, user_id).await
+}
+
+/// `mark_as_dm` with the `m.direct` content already fetched.
+async fn record(client: &Client, mut direct: DirectEventContent, room: &Room, user_id: &UserId) -> Result<(), BridgeError> {
+    let rooms = direct.entry(user_id.to_owned()).or_default();
+    if rooms.iter().any(|r| r == room.room_id()) {
+        return Ok(());
+    }
+    rooms.push(room.room_id().to_owned());
+    client.account().set_account_data(direct).await?;
+    Ok(())
+}
+
+/// Invite whoever of `users` isn't in the room or invited to it yet. Failures are only logged;
+/// the room is usable without them.
+pub(crate) async fn invite_missing(room: &Room, users: &[OwnedUserId]) {
+    for user_id in users {
+        match is_active_member(room, user_id).await {
+            Ok(true) => continue,
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("[Bridge][dm] Failed to look up {} in {}: {}", user_id, room.room_id(), e);
+                continue;
+            }
+        }
+        match room.invite_user_by_id(user_id).await {
+            Ok(()) => eprintln!("[Bridge][dm] Invited {} to {}", user_id, room.room_id()),
+            Err(e) => eprintln!("[Bridge][dm] Failed to invite {} to {}: {}", user_id, room.room_id(), e),
+        }
+    }
+}
+
+async fn fetch_direct(client: &Client) -> Result<DirectEventContent, BridgeError> {
+    let raw = client.account().fetch_account_data(GlobalAccountDataEventType::Direct).await?;
+    Ok(raw.and_then(|raw| raw.deserialize_as::<DirectEventContent>().ok()).unwrap_or_default())
+}
+
+/// Whether either of us was invited to the room as a DM. The invite carries `is_direct`; once
+/// joined it is the previous content of the join event.
+async fn opened_as_direct(room: &Room, user_id: &UserId) -> Result<bool, BridgeError> {
+    let members = [room.get_member(user_id).await?, room.get_member(room.own_user_id()).await?];
+    Ok(members.iter().flatten().any(|member| match &**member.event() {
+        SyncOrStrippedState::Sync(SyncStateEvent::Original(event)) => {
+            is_direct(&event.content) || event.unsigned.prev_content.as_ref().is_some_and(is_direct)
+        }
+        SyncOrStrippedState::Sync(SyncStateEvent::Redacted(_)) => false,
+        SyncOrStrippedState::Stripped(event) => is_direct(&event.content),
+    }))
+}
+
+fn is_direct(content: &RoomMemberEventContent) -> bool {
+    content.is_direct == Some(true)
+}
+
+/// Whether `user_id` is in the room or invited to it (a DM they left isn't one anymore).
+async fn is_active_member(room: &Room, user_id: &UserId) -> Result<bool, BridgeError> {
+    let member = room.get_member(user_id).await?;
+    Ok(member.is_some_and(|m| matches!(m.membership(), MembershipState::Join | MembershipState::Invite)))
+}
@@ -0,0 +1,182 @@
+// Conversions into the `BridgeError` handed to Dart.
+//
+// SDK, ruma and parse errors are classified by their Matrix errcode, HTTP
+// status or failure mode, so the app can branch on `BridgeErrorKind` instead
+// of matching on message text. Plain `String` errors from internal helpers
+// become `Other`.
+
+use std::fmt;
+use std::time::Duration;
+
+use matrix_sdk::ruma::api::client::error::ErrorKind;
+use matrix_sdk::{ClientBuildError, HttpError};
+
+use crate::bridge::{BridgeError, BridgeErrorKind};
+use crate::store_recovery;
+
+/// Display text of the SQLite store error for a cipher that didn't decrypt with the given passphrase
+const CIPHER_INIT_ERROR: &str = "Failed to initialize the store cipher";
+
+impl BridgeError {
+    pub(crate) fn new(kind: BridgeErrorKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into(), retry_after_ms: None }
+    }
+
+    fn rate_limited(message: String, retry_after: Option<Duration>) -> Self {
+        Self {
+            kind: BridgeErrorKind::RateLimited,
+            message,
+            retry_after_ms: retry_after.and_then(|d| u64::try_from(d.as_millis()).ok()),
+        }
+    }
+}
+
+impl fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.kind, self.message)
+    }
+}
+
+impl std::error::Error for BridgeError {}
+
+impl From<String> for BridgeError {
+    fn from(message: String) -> Self {
+        Self::new(BridgeErrorKind::Other, message)
+    }
+}
+
+impl From<HttpError> for BridgeError {
+    fn from(e: HttpError) -> Self {
+        classify_http(&e, e.to_string())
+    }
+}
+
+impl From<matrix_sdk::Error> for BridgeError {
+    fn from(e: matrix_sdk::Error) -> Self {
+        let message = e.to_string();
+        match &e {
+            matrix_sdk::Error::Http(http) => classify_http(http, message),
+            matrix_sdk::Error::AuthenticationRequired => Self::new(BridgeErrorKind::NotLoggedIn, message),
+            matrix_sdk::Error::Identifier(_) | matrix_sdk::Error::Url(_) => Self::new(BridgeErrorKind::InvalidId, message),
+            _ if store_recovery::is_store_mismatch(&message) => Self::new(BridgeErrorKind::CryptoStoreMismatch, message),
+            _ => Self::new(BridgeErrorKind::Other, message),
+        }
+    }
+}
+
+impl From<ClientBuildError> for BridgeError {
+    fn from(e: ClientBuildError) -> Self {
+        let message = e.to_string();
+        match &e {
+            ClientBuildError::Http(http) => classify_http(http, message),
+            ClientBuildError::Url(_) => Self::new(BridgeErrorKind::InvalidId, message),
+            _ if store_recovery::is_store_mismatch(&message) => Self::new(BridgeErrorKind::CryptoStoreMismatch, message),
+            _ if message.contains(CIPHER_INIT_ERROR) => Self::new(BridgeErrorKind::WrongPassphrase, message),
+            _ => Self::new(BridgeErrorKind::Other, message),
+        }
+    }
+}
+
+impl From<matrix_sdk::IdParseError> for BridgeError {
+    fn from(e: matrix_sdk::IdParseError) -> Self {
+        Self::new(BridgeErrorKind::InvalidId, e.to_string())
+    }
+}
+
+impl From<url::ParseError> for BridgeError {
+    fn from(e: url::ParseError) -> Self {
+        Self::new(BridgeErrorKind::InvalidId, e.to_string())
+    }
+}
+
+fn classify_http(e: &HttpError, message: String) -> BridgeError {
+    if let Some(kind) = e.client_api_error_kind() {
+        return match kind {
+            ErrorKind::Forbidden => BridgeError::new(BridgeErrorKind::Forbidden, message),
+            // E.g. an unknown room ID or alias
+            ErrorKind::NotFound => BridgeError::new(BridgeErrorKind::RoomNotFound, message),
+            ErrorKind::LimitExceeded { retry_after_ms } => BridgeError::rate_limited(message, *retry_after_ms),
+            ErrorKind::UnknownToken { .. } | ErrorKind::MissingToken => {
+                BridgeError::new(BridgeErrorKind::NotLoggedIn, message)
+            }
+            _ => BridgeError::new(BridgeErrorKind::Other, message),
+        };
+    }
+    match e {
+        HttpError::Reqwest(err) if err.is_connect() || err.is_timeout() || err.is_request() => {
+            BridgeError::new(BridgeErrorKind::NetworkUnavailable, message)
+        }
+        HttpError::AuthenticationRequired => BridgeError::new(BridgeErrorKind::NotLoggedIn, message),
+        // Some proxies rate limit without a Matrix error body
+        _ if e.as_client_api_error().is_some_and(|api| api.status_code.as_u16() == 429) => {
+            BridgeError::rate_limited(message, None)
+        }
+        _ => BridgeError::new(BridgeErrorKind::Other, message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix_sdk::ruma::api::error::FromHttpResponseError;
+    use matrix_sdk::ruma::api::EndpointError;
+    use matrix_sdk::ruma::exports::http;
+    use matrix_sdk::ruma::UserId;
+
+    use super::*;
+
+    /// The error the SDK returns for a homeserver response with this status and body.
+    fn server_error(status: u16, body: &str) -> BridgeError {
+        let response = http::Response::builder().status(status).body(body.as_bytes()).unwrap();
+        let error = matrix_sdk::ruma::api::client::Error::from_http_response(response);
+        HttpError::from(FromHttpResponseError::Server(error)).into()
+    }
+
+    #[test]
+    fn matrix_errcodes_are_classified() {
+        let forbidden = server_error(403, r#"{"errcode":"M_FORBIDDEN","error":"Not in room"}"#);
+        assert_eq!(forbidden.kind, BridgeErrorKind::Forbidden);
+
+        let not_found = server_error(404, r#"{"errcode":"M_NOT_FOUND","error":"Unknown room"}"#);
+        assert_eq!(not_found.kind, BridgeErrorKind::RoomNotFound);
+
+        let expired = server_error(401, r#"{"errcode":"M_UNKNOWN_TOKEN","error":"Expired"}"#);
+        assert_eq!(expired.kind, BridgeErrorKind::NotLoggedIn);
+
+        let unknown = server_error(500, r#"{"errcode":"M_UNKNOWN","error":"Oops"}"#);
+        assert_eq!(unknown.kind, BridgeErrorKind::Other);
+        assert_eq!(unknown.retry_after_ms, None);
+    }
+
+    #[test]
+    fn rate_limits_carry_the_requested_pause() {
+        let limited = server_error(429, r#"{"errcode":"M_LIMIT_EXCEEDED","error":"Slow down","retry_after_ms":2000}"#);
+        assert_eq!(limited.kind, BridgeErrorKind::RateLimited);
+        assert_eq!(limited.retry_after_ms, Some(2000));
+
+        // From a proxy, without a Matrix body
+        let proxied = server_error(429, "Too Many Requests");
+        assert_eq!(proxied.kind, BridgeErrorKind::RateLimited);
+        assert_eq!(proxied.retry_after_ms, None);
+    }
+
+    #[test]
+    fn missing_session_is_not_logged_in() {
+        assert_eq!(BridgeError::from(matrix_sdk::Error::AuthenticationRequired).kind, BridgeErrorKind::NotLoggedIn);
+        assert_eq!(BridgeError::from(HttpError::AuthenticationRequired).kind, BridgeErrorKind::NotLoggedIn);
+    }
+
+    #[test]
+    fn bad_ids_and_urls_are_invalid_ids() {
+        let id = UserId::parse("landlord").unwrap_err();
+        assert_eq!(BridgeError::from(id).kind, BridgeErrorKind::InvalidId);
+        let url = url::Url::parse("not a homeserver").unwrap_err();
+        assert_eq!(BridgeError::from(url).kind, BridgeErrorKind::InvalidId);
+    }
+
+    #[test]
+    fn helper_errors_are_other() {
+        let error = BridgeError::from("Room has no name".to_string());
+        assert_eq!(error.kind, BridgeErrorKind::Other);
+        assert_eq!(error.to_string(), "Other: Room has no name");
+    }
+}
@@ -0,0 +1,105 @@
+// Shared rooms for a property, with everyone's role turned into a power level.
+//
+// Landlords and managers run the room: they can rename it and invite or remove
+// people. Only landlords can change who has which role. Caretakers and tenants
+// can post but not manage; the caretaker's level only sets them apart in the
+// member list.
+
+use std::collections::BTreeMap;
+
+use matrix_sdk::ruma::events::room::power_levels::RoomPowerLevelsEventContent;
+use matrix_sdk::ruma::events::TimelineEventType;
+use matrix_sdk::ruma::{Int, OwnedUserId, UserId};
+
+use crate::bridge::ParticipantRole;
+
+const LANDLORD_LEVEL: i64 = 100;
+const MANAGER_LEVEL: i64 = 50;
+const CARETAKER_LEVEL: i64 = 10;
+const TENANT_LEVEL: i64 = 0;
+/// Needed to rename the room or change its members
+const MANAGE_LEVEL: i64 = MANAGER_LEVEL;
+
+pub(crate) fn level(role: ParticipantRole) -> i64 {
+    match role {
+        ParticipantRole::Landlord => LANDLORD_LEVEL,
+        ParticipantRole::Manager => MANAGER_LEVEL,
+        ParticipantRole::Caretaker => CARETAKER_LEVEL,
+        ParticipantRole::Tenant => TENANT_LEVEL,
+    }
+}
+
+/// Power levels for a new group room. The creator keeps full power whatever role they're listed
+/// with: they have to send the initial state, and without a landlord nobody could change roles.
+pub(crate) fn power_levels(creator: &UserId, roles: &[(OwnedUserId, ParticipantRole)]) -> RoomPowerLevelsEventContent {
+    let mut users = BTreeMap::new();
+    for (user_id, role) in roles {
+        users.insert(user_id.clone(), Int::new_saturating(level(*role)));
+    }
+    users.insert(creator.to_owned(), Int::new_saturating(LANDLORD_LEVEL));
+
+    let manage = Int::new_saturating(MANAGE_LEVEL);
+    let mut content = RoomPowerLevelsEventContent::new();
+    content.users = users;
+    content.users_default = Int::new_saturating(TENANT_LEVEL);
+    // Everyone can post
+    content.events_default = Int::new_saturating(TENANT_LEVEL);
+    content.state_default = manage;
+    content.invite = manage;
+    content.kick = manage;
+    content.ban = manage;
+    content.redact = manage;
+    for event_type in [TimelineEventType::RoomName, TimelineEventType::RoomTopic, TimelineEventType::RoomAvatar] {
+        content.events.insert(event_type, manage);
+    }
+    content.events.insert(TimelineEventType::RoomPowerLevels, Int::new_saturating(LANDLORD_LEVEL));
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix_sdk::ruma::{owned_user_id, user_id};
+
+    use super::*;
+
+    fn user_level(content: &RoomPowerLevelsEventContent, user_id: &UserId) -> Option<i64> {
+        content.users.get(user_id).map(|level| i64::from(*level))
+    }
+
+    #[test]
+    fn roles_become_levels() {
+        let roles = [
+            (owned_user_id!("@manager:example.org"), ParticipantRole::Manager),
+            (owned_user_id!("@caretaker:example.org"), ParticipantRole::Caretaker),
+            (owned_user_id!("@tenant:example.org"), ParticipantRole::Tenant),
+        ];
+        let content = power_levels(user_id!("@landlord:example.org"), &roles);
+        assert_eq!(user_level(&content, user_id!("@landlord:example.org")), Some(LANDLORD_LEVEL));
+        assert_eq!(user_level(&content, user_id!("@manager:example.org")), Some(MANAGER_LEVEL));
+        assert_eq!(user_level(&content, user_id!("@caretaker:example.org")), Some(CARETAKER_LEVEL));
+        assert_eq!(user_level(&content, user_id!("@tenant:example.org")), Some(TENANT_LEVEL));
+    }
+
+    #[test]
+    fn creator_keeps_full_power_whatever_their_role() {
+        let creator = user_id!("@manager:example.org");
+        let roles = [(creator.to_owned(), ParticipantRole::Manager)];
+        let content = power_levels(creator, &roles);
+        assert_eq!(user_level(&content, creator), Some(LANDLORD_LEVEL));
+    }
+
+    #[test]
+    fn managers_manage_and_only_landlords_change_roles() {
+        let content = power_levels(user_id!("@landlord:example.org"), &[]);
+        let manage = Int::new_saturating(MANAGER_LEVEL);
+        assert_eq!(content.events_default, Int::new_saturating(TENANT_LEVEL));
+        assert_eq!(content.invite, manage);
+        assert_eq!(content.kick, manage);
+        assert_eq!(content.state_default, manage);
+        assert_eq!(content.events.get(&TimelineEventType::RoomName), Some(&manage));
+        assert_eq!(
+            content.events.get(&TimelineEventType::RoomPowerLevels),
+            Some(&Int::new_saturating(LANDLORD_LEVEL))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+// Room invites and the policy deciding which ones are joined by themselves.
+//
+// Any Matrix user on a federated server can invite a tenant, so invites are
+// only accepted without asking if the policy from `init` allows it. The rest
+// stay pending and go to the Dart invite sink with a preview of the room built
+// from the invite's stripped state, to be accepted or declined by the user.
+// Nothing is declined automatically.
+
+use matrix_sdk::room::Room;
+use matrix_sdk::ruma::events::StateEventType;
+use matrix_sdk::ruma::UserId;
+
+use crate::bridge::{InviteInfo, InvitePolicy, InvitePolicyKind};
+use crate::clients::ClientState;
+use crate::property_state::{self, PROPERTY_EVENT_TYPE};
+
+/// Handle a new invite to `room` from `inviter`: join it if the policy allows, otherwise pass it
+/// on to Dart.
+pub(crate) async fn on_invite(state: &ClientState, room: Room, inviter: &UserId) {
+    let policy = state.invite_policy();
+    if accepts(&policy, &room, inviter).await {
+        eprintln!("[Bridge][invites] Accepting invite to {} from {}", room.room_id(), inviter);
+        match room.join().await {
+            Ok(()) => return,
+            // Let the user retry
+            Err(e) => eprintln!("[Bridge][invites] Failed to accept invite to {}: {}", room.room_id(), e),
+        }
+    } else {
+        eprintln!("[Bridge][invites] Invite to {} from {} left to the user", room.room_id(), inviter);
+    }
+    state.emit_invite(preview(&room).await);
+}
+
+async fn accepts(policy: &InvitePolicy, room: &Room, inviter: &UserId) -> bool {
+    match policy.kind {
+        InvitePolicyKind::AcceptAll => true,
+        InvitePolicyKind::AcceptAllowlisted => {
+            policy.allowed_users.iter().any(|user| user == inviter.as_str())
+                || policy.allowed_servers.iter().any(|server| server == inviter.server_name().as_str())
+        }
+        InvitePolicyKind::AcceptPropertyRooms => is_property_room(room).await,
+        InvitePolicyKind::Manual => false,
+    }
+}
+
+/// What Dart shows for a pending invite.
+pub(crate) async fn preview(room: &Room) -> InviteInfo {
+    let details = room.invite_details().await.ok();
+    let inviter_member = details.as_ref().and_then(|details| details.inviter.as_ref());
+    // The inviter's own member event may be missing from the stripped state; our invite still
+    // says who sent it
+    let inviter = details.as_ref().map(|details| details.invitee.event().sender().to_string());
+    let display_name = match room.display_name().await {
+        Ok(name) => name.to_string(),
+        Err(_) => room.room_id().to_string(),
+    };
+    InviteInfo {
+        room_id: room.room_id().to_string(),
+        display_name,
+        avatar_url: room.avatar_url().map(|url| url.to_string()),
+        topic: room.topic(),
+        is_direct: room.is_direct().await.unwrap_or(false),
+        property: property_state::read(room).await,
+        inviter,
+        inviter_display_name: inviter_member.and_then(|member| member.display_name().map(str::to_owned)),
+        inviter_avatar_url: inviter_member.and_then(|member| member.avatar_url().map(|url| url.to_string())),
+    }
+}
+
+async fn is_property_room(room: &Room) -> bool {
+    room.get_state_events(StateEventType::from(PROPERTY_EVENT_TYPE))
+        .await
+        .map(|events| !events.is_empty())
+        .unwrap_or(false)
+}
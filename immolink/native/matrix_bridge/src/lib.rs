@@ -6,3 +6,19 @@ mod frb_generated; /* AUTO INJECTED BY flutter_rust_bridge. This line may not be
 // exercise — the bridge functions are the integration points.
 
 pub mod bridge;
+mod backoff;
+mod calls;
+mod clients;
+mod direct_rooms;
+mod errors;
+mod group_rooms;
+mod invites;
+mod media_cache;
+mod members;
+mod property_state;
+mod room_list;
+mod spaces;
+mod store_encryption;
+mod store_recovery;
+mod sync_filter;
+mod sync_loop;
@@ -0,0 +1,283 @@
+// On-disk cache for downloaded (and decrypted) media.
+//
+// Files live in a single flat directory under the client's data_dir and are
+// named after their mxc:// URI plus the requested format. Cache hits bump the
+// file's mtime, and after every insert the least recently used files are
+// removed until the directory is back under its byte budget. Media over the
+// per-file limit is remembered for the client's lifetime, so it isn't
+// downloaded again just to be turned down again.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use matrix_sdk::ruma::events::room::MediaSource;
+
+/// Default total budget for the cache directory (512 MiB)
+pub(crate) const DEFAULT_MAX_TOTAL_BYTES: u64 = 512 * 1024 * 1024;
+/// Default cap for a single cached file (100 MiB)
+pub(crate) const DEFAULT_MAX_FILE_BYTES: u64 = 100 * 1024 * 1024;
+/// Extension of files still being written
+const PARTIAL_EXTENSION: &str = "part";
+
+pub(crate) struct MediaCache {
+    dir: PathBuf,
+    max_total_bytes: AtomicU64,
+    max_file_bytes: AtomicU64,
+    /// Size of media found to be over the per-file limit, by key
+    oversized: Mutex<HashMap<String, u64>>,
+}
+
+impl MediaCache {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            max_total_bytes: AtomicU64::new(DEFAULT_MAX_TOTAL_BYTES),
+            max_file_bytes: AtomicU64::new(DEFAULT_MAX_FILE_BYTES),
+            oversized: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(crate) fn set_limits(&self, max_total_bytes: u64, max_file_bytes: u64) {
+        self.max_total_bytes.store(max_total_bytes, Ordering::Relaxed);
+        self.max_file_bytes.store(max_file_bytes, Ordering::Relaxed);
+    }
+
+    pub(crate) fn max_file_bytes(&self) -> u64 {
+        self.max_file_bytes.load(Ordering::Relaxed)
+    }
+
+    /// The size of `key`'s media if it is known to be over the per-file limit, either from an
+    /// earlier download or from `size` (the size the event announced).
+    pub(crate) fn oversized(&self, key: &str, size: Option<u64>) -> Option<u64> {
+        let max = self.max_file_bytes();
+        let known = self.oversized.lock().ok().and_then(|oversized| oversized.get(key).copied());
+        // The limit may have been raised since
+        known.or(size).filter(|size| *size > max)
+    }
+
+    /// Remember that `key`'s media is `size` bytes, over the per-file limit.
+    pub(crate) fn mark_oversized(&self, key: &str, size: u64) {
+        if let Ok(mut oversized) = self.oversized.lock() {
+            oversized.insert(key.to_owned(), size);
+        }
+    }
+
+    /// Return the cached file for `key`, marking it as recently used.
+    pub(crate) fn get(&self, key: &str, extension: Option<&str>) -> Option<PathBuf> {
+        let path = self.path_for(key, extension);
+        if !path.is_file() {
+            return None;
+        }
+        if let Ok(file) = fs::File::options().append(true).open(&path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(path)
+    }
+
+    /// Store `data` under `key` and evict old entries if the cache is over budget.
+    pub(crate) fn put(&self, key: &str, extension: Option<&str>, data: &[u8]) -> io::Result<PathBuf> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path_for(key, extension);
+        // Write next to the target and rename so readers never see a partial file. Each write
+        // gets its own temporary file, concurrent fetches of one key included
+        let mut tmp = tempfile::Builder::new()
+            .prefix(".")
+            .suffix(&format!(".{}", PARTIAL_EXTENSION))
+            .tempfile_in(&self.dir)?;
+        tmp.write_all(data)?;
+        tmp.persist(&path).map_err(|e| e.error)?;
+        self.evict(&path);
+        Ok(path)
+    }
+
+    pub(crate) fn clear(&self) -> io::Result<()> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)?;
+        }
+        Ok(())
+    }
+
+    /// Delete least recently used files until the directory fits the budget. `keep` is never removed.
+    fn evict(&self, keep: &Path) {
+        let max_total = self.max_total_bytes.load(Ordering::Relaxed);
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return;
+        };
+        let mut files: Vec<(PathBuf, u64, SystemTime)> = entries
+            .filter_map(|e| e.ok())
+            .filter_map(|e| {
+                let meta = e.metadata().ok()?;
+                // Files being written belong to another fetch
+                if !meta.is_file() || e.path().extension().is_some_and(|ext| ext == PARTIAL_EXTENSION) {
+                    return None;
+                }
+                Some((e.path(), meta.len(), meta.modified().unwrap_or(SystemTime::UNIX_EPOCH)))
+            })
+            .collect();
+        let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+        if total <= max_total {
+            return;
+        }
+        files.sort_by_key(|(_, _, modified)| *modified);
+        for (path, len, _) in files {
+            if total <= max_total {
+                break;
+            }
+            if path == keep {
+                continue;
+            }
+            match fs::remove_file(&path) {
+                Ok(()) => total = total.saturating_sub(len),
+                Err(e) => eprintln!("[Bridge][media] Failed to evict {}: {}", path.display(), e),
+            }
+        }
+    }
+
+    fn path_for(&self, key: &str, extension: Option<&str>) -> PathBuf {
+        match extension {
+            Some(ext) => self.dir.join(format!("{}.{}", key, ext)),
+            None => self.dir.join(key),
+        }
+    }
+}
+
+/// File name safe key for a media source and format (`None` = full file, `Some` = thumbnail).
+pub(crate) fn cache_key(source: &MediaSource, thumbnail: Option<(u32, u32, bool)>) -> String {
+    let uri = match source {
+        MediaSource::Plain(uri) => uri.as_str(),
+        MediaSource::Encrypted(file) => file.url.as_str(),
+    };
+    let mut key: String = uri
+        .trim_start_matches("mxc://")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect();
+    if let Some((width, height, crop)) = thumbnail {
+        key.push_str(&format!("_thumb{}x{}{}", width, height, if crop { "c" } else { "s" }));
+    }
+    key
+}
+
+/// Extension to give the cached file, taken from the original filename so the OS can open it.
+pub(crate) fn extension_from_filename(filename: Option<&str>) -> Option<String> {
+    let ext = Path::new(filename?).extension()?.to_str()?;
+    if !ext.is_empty() && ext.len() <= 8 && ext.chars().all(|c| c.is_ascii_alphanumeric()) {
+        Some(ext.to_ascii_lowercase())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use matrix_sdk::ruma::owned_mxc_uri;
+
+    use super::*;
+
+    fn plain(uri: &str) -> MediaSource {
+        MediaSource::Plain(uri.into())
+    }
+
+    /// Make `path` look last used `age_secs` ago.
+    fn age(path: &Path, age_secs: u64) {
+        let file = fs::File::options().append(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - Duration::from_secs(age_secs)).unwrap();
+    }
+
+    #[test]
+    fn keys_are_file_name_safe() {
+        let source = MediaSource::Plain(owned_mxc_uri!("mxc://example.org/AbC-123"));
+        assert_eq!(cache_key(&source, None), "example_org_AbC-123");
+        assert_eq!(cache_key(&source, Some((320, 240, true))), "example_org_AbC-123_thumb320x240c");
+        assert_eq!(cache_key(&source, Some((320, 240, false))), "example_org_AbC-123_thumb320x240s");
+        assert_eq!(cache_key(&plain("mxc://../../etc/passwd"), None), "______etc_passwd");
+    }
+
+    #[test]
+    fn extensions_come_from_sane_filenames() {
+        assert_eq!(extension_from_filename(Some("Lease.PDF")).as_deref(), Some("pdf"));
+        assert_eq!(extension_from_filename(Some("photo.jpeg")).as_deref(), Some("jpeg"));
+        assert_eq!(extension_from_filename(Some("README")), None);
+        assert_eq!(extension_from_filename(Some("archive.verylongext")), None);
+        assert_eq!(extension_from_filename(Some("note.t x")), None);
+        assert_eq!(extension_from_filename(None), None);
+    }
+
+    #[test]
+    fn put_then_get() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = MediaCache::new(dir.path().join("media"));
+        assert_eq!(cache.get("key", Some("pdf")), None);
+
+        let path = cache.put("key", Some("pdf"), b"lease").unwrap();
+        assert_eq!(path, dir.path().join("media").join("key.pdf"));
+        assert_eq!(cache.get("key", Some("pdf")), Some(path.clone()));
+        assert_eq!(fs::read(&path).unwrap(), b"lease");
+        // No temporary files left behind
+        assert_eq!(fs::read_dir(dir.path().join("media")).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn least_recently_used_files_are_evicted() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = MediaCache::new(dir.path().to_owned());
+        cache.set_limits(25, DEFAULT_MAX_FILE_BYTES);
+        let oldest = cache.put("oldest", None, &[0; 10]).unwrap();
+        let used = cache.put("used", None, &[0; 10]).unwrap();
+        age(&oldest, 200);
+        age(&used, 100);
+        // A hit makes it the most recently used
+        cache.get("used", None).unwrap();
+
+        let newest = cache.put("newest", None, &[0; 10]).unwrap();
+        assert!(!oldest.exists());
+        assert!(used.exists());
+        assert!(newest.exists());
+    }
+
+    #[test]
+    fn new_file_survives_even_over_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = MediaCache::new(dir.path().to_owned());
+        cache.set_limits(5, DEFAULT_MAX_FILE_BYTES);
+        let old = cache.put("old", None, &[0; 4]).unwrap();
+        age(&old, 100);
+        let big = cache.put("big", None, &[0; 10]).unwrap();
+        assert!(!old.exists());
+        assert!(big.exists());
+    }
+
+    #[test]
+    fn partial_files_are_not_evicted() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = MediaCache::new(dir.path().to_owned());
+        cache.set_limits(5, DEFAULT_MAX_FILE_BYTES);
+        let partial = dir.path().join(".other.part");
+        fs::write(&partial, [0; 10]).unwrap();
+        age(&partial, 100);
+        cache.put("key", None, &[0; 4]).unwrap();
+        assert!(partial.exists());
+    }
+
+    #[test]
+    fn oversized_media_is_remembered_against_the_current_limit() {
+        let cache = MediaCache::new(PathBuf::from("unused"));
+        cache.set_limits(DEFAULT_MAX_TOTAL_BYTES, 100);
+        assert_eq!(cache.oversized("key", None), None);
+        assert_eq!(cache.oversized("key", Some(100)), None);
+        assert_eq!(cache.oversized("key", Some(101)), Some(101));
+
+        cache.mark_oversized("key", 500);
+        assert_eq!(cache.oversized("key", None), Some(500));
+        // Raising the limit lets it through again
+        cache.set_limits(DEFAULT_MAX_TOTAL_BYTES, 1000);
+        assert_eq!(cache.oversized("key", None), None);
+    }
+}
@@ -0,0 +1,35 @@
+// Member lists of rooms.
+//
+// Unlike the room list's member ids, which only cover the members sync has
+// brought in (with lazy loading just the ones who spoke), this loads the full
+// list from the homeserver once per room; the SDK keeps it current after that.
+// Left and banned members are listed too, so they can be re-invited or unbanned.
+
+use matrix_sdk::room::{Room, RoomMember};
+use matrix_sdk::ruma::events::room::member::MembershipState;
+use matrix_sdk::RoomMemberships;
+
+use crate::bridge::{BridgeError, MemberState, RoomMemberInfo};
+
+/// Every member of `room` with any membership, highest power level first.
+pub(crate) async fn list(room: &Room) -> Result<Vec<RoomMemberInfo>, BridgeError> {
+    let mut members: Vec<RoomMemberInfo> = room.members(RoomMemberships::empty()).await?.iter().map(info).collect();
+    members.sort_by(|a, b| b.power_level.cmp(&a.power_level).then_with(|| a.user_id.cmp(&b.user_id)));
+    Ok(members)
+}
+
+fn info(member: &RoomMember) -> RoomMemberInfo {
+    RoomMemberInfo {
+        user_id: member.user_id().to_string(),
+        display_name: member.display_name().map(str::to_owned),
+        avatar_url: member.avatar_url().map(|url| url.to_string()),
+        membership: match member.membership() {
+            MembershipState::Join => MemberState::Joined,
+            MembershipState::Invite => MemberState::Invited,
+            MembershipState::Ban => MemberState::Banned,
+            MembershipState::Knock => MemberState::Knocked,
+            _ => MemberState::Left,
+        },
+        power_level: member.power_level(),
+    }
+}
@@ -0,0 +1,190 @@
+// Property and tenancy metadata kept in a room's state.
+//
+// The `ch.immosync.property` state event (empty state key) ties a room to the
+// property, tenancy and participant roles it is about, so a room found by
+// sync can be traced back to our domain without asking the backend. On the
+// wire roles are lowercase names keyed by user ID; roles this build doesn't
+// know are skipped when reading.
+
+use std::collections::BTreeMap;
+
+use matrix_sdk::deserialized_responses::RawAnySyncOrStrippedState;
+use matrix_sdk::room::Room;
+use matrix_sdk::ruma::events::{AnyInitialStateEvent, StateEventType};
+use matrix_sdk::ruma::serde::Raw;
+use serde::{Deserialize, Serialize};
+
+use crate::bridge::{BridgeError, Participant, ParticipantRole, PropertyMetadata};
+
+/// State event marking a room as belonging to a property. Servers only include it in the invite's
+/// stripped state if listed in their `room_prejoin_state` config.
+pub(crate) const PROPERTY_EVENT_TYPE: &str = "ch.immosync.property";
+
+#[derive(Serialize, Deserialize)]
+struct PropertyEventContent {
+    property_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tenancy_id: Option<String>,
+    /// Role name per user ID
+    #[serde(default)]
+    roles: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct PropertyEvent {
+    content: PropertyEventContent,
+}
+
+/// The property metadata of `room`, if it has any (a redacted event has none).
+pub(crate) async fn read(room: &Room) -> Option<PropertyMetadata> {
+    let raw = room.get_state_event(StateEventType::from(PROPERTY_EVENT_TYPE), "").await.ok()??;
+    let event = match raw {
+        RawAnySyncOrStrippedState::Sync(raw) => raw.deserialize_as::<PropertyEvent>(),
+        RawAnySyncOrStrippedState::Stripped(raw) => raw.deserialize_as::<PropertyEvent>(),
+    };
+    event.ok().map(|event| from_content(event.content))
+}
+
+/// Replace the property metadata of a room we're in.
+pub(crate) async fn write(room: &Room, metadata: &PropertyMetadata) -> Result<(), BridgeError> {
+    room.send_state_event_raw(PROPERTY_EVENT_TYPE, "", content_json(metadata)?).await?;
+    Ok(())
+}
+
+/// The metadata as initial state for a room about to be created.
+pub(crate) fn initial_state(metadata: &PropertyMetadata) -> Result<Raw<AnyInitialStateEvent>, BridgeError> {
+    let event = serde_json::json!({
+        "type": PROPERTY_EVENT_TYPE,
+        "state_key": "",
+        "content": content_json(metadata)?,
+    });
+    Ok(Raw::new(&event).map_err(|e| format!("Failed to serialize property metadata: {}", e))?.cast())
+}
+
+fn content_json(metadata: &PropertyMetadata) -> Result<serde_json::Value, BridgeError> {
+    let content = PropertyEventContent {
+        property_id: metadata.property_id.clone(),
+        address: metadata.address.clone(),
+        tenancy_id: metadata.tenancy_id.clone(),
+        roles: metadata
+            .participants
+            .iter()
+            .map(|participant| (participant.user_id.clone(), role_name(participant.role).to_owned()))
+            .collect(),
+    };
+    Ok(serde_json::to_value(content).map_err(|e| format!("Failed to serialize property metadata: {}", e))?)
+}
+
+fn from_content(content: PropertyEventContent) -> PropertyMetadata {
+    PropertyMetadata {
+        property_id: content.property_id,
+        address: content.address,
+        tenancy_id: content.tenancy_id,
+        participants: content
+            .roles
+            .into_iter()
+            .filter_map(|(user_id, role)| Some(Participant { user_id, role: role_from_name(&role)? }))
+            .collect(),
+    }
+}
+
+fn role_name(role: ParticipantRole) -> &'static str {
+    match role {
+        ParticipantRole::Landlord => "landlord",
+        ParticipantRole::Manager => "manager",
+        ParticipantRole::Caretaker => "caretaker",
+        ParticipantRole::Tenant => "tenant",
+    }
+}
+
+fn role_from_name(name: &str) -> Option<ParticipantRole> {
+    match name {
+        "landlord" => Some(ParticipantRole::Landlord),
+        "manager" => Some(ParticipantRole::Manager),
+        "caretaker" => Some(ParticipantRole::Caretaker),
+        "tenant" => Some(ParticipantRole::Tenant),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> PropertyMetadata {
+        PropertyMetadata {
+            property_id: "prop-1".to_owned(),
+            address: Some("Bahnhofstrasse 1, Zürich".to_owned()),
+            tenancy_id: None,
+            participants: vec![
+                Participant { user_id: "@landlord:example.org".to_owned(), role: ParticipantRole::Landlord },
+                Participant { user_id: "@tenant:example.org".to_owned(), role: ParticipantRole::Tenant },
+            ],
+        }
+    }
+
+    fn parse(content: serde_json::Value) -> PropertyMetadata {
+        from_content(serde_json::from_value(content).unwrap())
+    }
+
+    #[test]
+    fn content_uses_role_names_and_skips_missing_fields() {
+        let content = content_json(&metadata()).unwrap();
+        assert_eq!(
+            content,
+            serde_json::json!({
+                "property_id": "prop-1",
+                "address": "Bahnhofstrasse 1, Zürich",
+                "roles": {
+                    "@landlord:example.org": "landlord",
+                    "@tenant:example.org": "tenant",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn content_round_trips() {
+        let parsed = parse(content_json(&metadata()).unwrap());
+        assert_eq!(parsed.property_id, "prop-1");
+        assert_eq!(parsed.address.as_deref(), Some("Bahnhofstrasse 1, Zürich"));
+        assert_eq!(parsed.tenancy_id, None);
+        let roles: Vec<_> = parsed.participants.iter().map(|p| (p.user_id.as_str(), p.role)).collect();
+        assert_eq!(
+            roles,
+            [("@landlord:example.org", ParticipantRole::Landlord), ("@tenant:example.org", ParticipantRole::Tenant)]
+        );
+    }
+
+    #[test]
+    fn unknown_roles_are_skipped() {
+        let parsed = parse(serde_json::json!({
+            "property_id": "prop-1",
+            "roles": {
+                "@agent:example.org": "broker",
+                "@caretaker:example.org": "caretaker",
+            },
+        }));
+        assert_eq!(parsed.participants.len(), 1);
+        assert_eq!(parsed.participants[0].user_id, "@caretaker:example.org");
+        assert_eq!(parsed.participants[0].role, ParticipantRole::Caretaker);
+    }
+
+    #[test]
+    fn roles_are_optional() {
+        let parsed = parse(serde_json::json!({ "property_id": "prop-1", "tenancy_id": "ten-7" }));
+        assert_eq!(parsed.tenancy_id.as_deref(), Some("ten-7"));
+        assert!(parsed.participants.is_empty());
+    }
+
+    #[test]
+    fn initial_state_is_a_property_event() {
+        let event = initial_state(&metadata()).unwrap();
+        let event: serde_json::Value = event.deserialize_as().unwrap();
+        assert_eq!(event["type"], PROPERTY_EVENT_TYPE);
+        assert_eq!(event["state_key"], "");
+        assert_eq!(event["content"]["property_id"], "prop-1");
+    }
+}
@@ -0,0 +1,154 @@
+// Room list: summaries of the joined and invited rooms of a client, all of
+// them or those in one space. Spaces themselves aren't conversations and are
+// left out.
+//
+// Everything but the latest message comes from the SDK's local room state,
+// which sync keeps current. The latest message is the newest item the sync
+// handlers emitted for the room; rooms without one this session get it from
+// a short /messages request the first time the list is built, a few rooms at
+// a time so a long list doesn't flood the homeserver. After every
+// sync (classic or sliding), the rooms it touched are summarized again and
+// pushed to the room list sink.
+
+use std::collections::HashSet;
+
+use futures_util::{pin_mut, stream, StreamExt};
+use matrix_sdk::room::{MessagesOptions, Room};
+use matrix_sdk::ruma::OwnedRoomId;
+use matrix_sdk::{BaseRoom, RoomMemberships, RoomState};
+
+use crate::bridge::{RoomListChange, RoomListUpdate, RoomMembership, RoomSummary};
+use crate::clients::ClientState;
+use crate::property_state;
+
+/// Events looked at for a preview; rooms whose recent history is all state events get none
+const PREVIEW_SEARCH_LIMIT: u32 = 20;
+/// /messages requests for previews in flight at once
+const PREVIEW_FETCH_CONCURRENCY: usize = 4;
+
+/// Summaries of every joined and invited room, or only those `within` a space, most recently
+/// active first.
+pub(crate) async fn list(state: &ClientState, within: Option<&HashSet<OwnedRoomId>>) -> Vec<RoomSummary> {
+    let client = &state.client;
+    let rooms: Vec<Room> = client
+        .invited_rooms()
+        .into_iter()
+        .chain(client.joined_rooms())
+        .filter(|room| !room.is_space() && within.is_none_or(|within| within.contains(room.room_id())))
+        .collect();
+    fetch_missing_previews(state, &rooms).await;
+
+    let mut summaries = Vec::with_capacity(rooms.len());
+    for room in &rooms {
+        summaries.push(summarize(state, room).await);
+    }
+    summaries.sort_by_key(|summary| {
+        std::cmp::Reverse(summary.latest_message.as_ref().map(|item| item.timestamp_ms).unwrap_or_default())
+    });
+    summaries
+}
+
+/// Push an update for every room a sync touched. Rooms we left are removed from the list.
+pub(crate) async fn emit_changes(state: &ClientState, changed: &[OwnedRoomId]) {
+    if state.room_list_sink.lock().map(|sink| sink.is_none()).unwrap_or(true) {
+        return;
+    }
+    for room_id in changed {
+        let room = state.client.get_room(room_id).filter(|room| room.state() != RoomState::Left);
+        let Some(room) = room else {
+            state.emit_room_list(RoomListUpdate { room_id: room_id.to_string(), change: RoomListChange::Removed, room: None });
+            continue;
+        };
+        if room.is_space() {
+            continue;
+        }
+        let summary = summarize(state, &room).await;
+        state.emit_room_list(RoomListUpdate {
+            room_id: room_id.to_string(),
+            change: RoomListChange::Updated,
+            room: Some(summary),
+        });
+    }
+}
+
+pub(crate) async fn summarize(state: &ClientState, room: &Room) -> RoomSummary {
+    let base: &BaseRoom = room;
+    let display_name = match room.display_name().await {
+        Ok(name) => name.to_string(),
+        Err(e) => {
+            eprintln!("[Bridge][rooms] Failed to compute name of {}: {}", room.room_id(), e);
+            room.room_id().to_string()
+        }
+    };
+    // Only the locally known members, without a /members request per room
+    let members = room
+        .members_no_sync(RoomMemberships::ACTIVE)
+        .await
+        .map(|members| members.iter().map(|m| m.user_id().to_string()).collect())
+        .unwrap_or_default();
+    let counts = room.unread_notification_counts();
+    let latest_message = state
+        .latest_items
+        .lock()
+        .ok()
+        .and_then(|latest| latest.get(room.room_id().as_str()).cloned().flatten());
+
+    RoomSummary {
+        room_id: room.room_id().to_string(),
+        display_name,
+        avatar_url: room.avatar_url().map(|url| url.to_string()),
+        membership: match room.state() {
+            RoomState::Joined => RoomMembership::Joined,
+            RoomState::Invited => RoomMembership::Invited,
+            RoomState::Left => RoomMembership::Left,
+        },
+        is_encrypted: base.is_encrypted(),
+        is_direct: room.is_direct().await.unwrap_or(false),
+        members,
+        joined_members: room.joined_members_count(),
+        invited_members: room.invited_members_count(),
+        latest_message,
+        property: property_state::read(room).await,
+        unread_count: counts.notification_count,
+        highlight_count: counts.highlight_count,
+    }
+}
+
+/// Look up the latest message of joined rooms the sync handlers haven't seen one for yet.
+async fn fetch_missing_previews(state: &ClientState, rooms: &[Room]) {
+    let missing: Vec<Room> = {
+        let Ok(latest) = state.latest_items.lock() else {
+            return;
+        };
+        rooms
+            .iter()
+            .filter(|room| room.state() == RoomState::Joined && !latest.contains_key(room.room_id().as_str()))
+            .cloned()
+            .collect()
+    };
+
+    let fetches = stream::iter(missing)
+        .map(|room| async move {
+            let mut options = MessagesOptions::backward();
+            options.limit = PREVIEW_SEARCH_LIMIT.into();
+            let latest = match room.messages(options).await {
+                Ok(response) => crate::bridge::timeline_events_to_items(&room, response.chunk).await.into_iter().next(),
+                Err(e) => {
+                    eprintln!("[Bridge][rooms] Failed to load latest message of {}: {}", room.room_id(), e);
+                    return None;
+                }
+            };
+            Some((room.room_id().to_string(), latest))
+        })
+        .buffer_unordered(PREVIEW_FETCH_CONCURRENCY);
+    pin_mut!(fetches);
+    while let Some(result) = fetches.next().await {
+        let Some((room_id, latest)) = result else {
+            continue;
+        };
+        if let Ok(mut known) = state.latest_items.lock() {
+            // A live event may have come in meanwhile
+            known.entry(room_id).or_insert(latest);
+        }
+    }
+}
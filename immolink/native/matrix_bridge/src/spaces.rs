@@ -0,0 +1,142 @@
+// Spaces: rooms grouping other rooms, e.g. one per property and one per
+// portfolio of properties.
+//
+// A space lists its children in `m.space.child` state events and each child
+// points back with `m.space.parent`. Adding or removing a child updates both
+// sides; the back link is best effort, since we may lack the power level to
+// change the child's state. The hierarchy comes from the homeserver, which
+// also knows the rooms we aren't in; filtering the room list walks the local
+// state of the spaces we're in.
+
+use std::collections::{HashMap, HashSet};
+
+use matrix_sdk::deserialized_responses::SyncOrStrippedState;
+use matrix_sdk::room::Room;
+use matrix_sdk::ruma::api::client::space::get_hierarchy;
+use matrix_sdk::ruma::events::space::child::SpaceChildEventContent;
+use matrix_sdk::ruma::events::space::parent::SpaceParentEventContent;
+use matrix_sdk::ruma::events::SyncStateEvent;
+use matrix_sdk::ruma::room::RoomType;
+use matrix_sdk::ruma::{OwnedRoomId, OwnedServerName, RoomId};
+use matrix_sdk::{Client, RoomState};
+
+use crate::bridge::{BridgeError, BridgeErrorKind, SpaceHierarchyEntry};
+
+/// Hierarchy pages fetched at most, in case a server keeps handing out new `next_batch` tokens
+const MAX_HIERARCHY_PAGES: usize = 50;
+
+/// Make `child_id` a child of `space`.
+pub(crate) async fn add_child(client: &Client, space: &Room, child_id: &RoomId) -> Result<(), BridgeError> {
+    let via = via(client)?;
+    space.send_state_event_for_key(child_id, SpaceChildEventContent::new(via.clone())).await?;
+    if let Some(child) = client.get_room(child_id) {
+        let mut parent = SpaceParentEventContent::new(via);
+        parent.canonical = true;
+        if let Err(e) = child.send_state_event_for_key(space.room_id(), parent).await {
+            eprintln!("[Bridge][spaces] Failed to link {} back to {}: {}", child_id, space.room_id(), e);
+        }
+    }
+    Ok(())
+}
+
+/// Take `child_id` out of `space`. Empty `via` lists are how the spec removes both links.
+pub(crate) async fn remove_child(client: &Client, space: &Room, child_id: &RoomId) -> Result<(), BridgeError> {
+    space.send_state_event_for_key(child_id, SpaceChildEventContent::new(Vec::new())).await?;
+    if let Some(child) = client.get_room(child_id) {
+        if let Err(e) = child.send_state_event_for_key(space.room_id(), SpaceParentEventContent::new(Vec::new())).await {
+            eprintln!("[Bridge][spaces] Failed to unlink {} from {}: {}", child_id, space.room_id(), e);
+        }
+    }
+    Ok(())
+}
+
+/// Every room and subspace below `space_id`, as the homeserver sees it, the space itself first.
+/// Stops early if the server repeats a `next_batch` token or the hierarchy runs past
+/// `MAX_HIERARCHY_PAGES` pages.
+pub(crate) async fn hierarchy(client: &Client, space_id: &RoomId) -> Result<Vec<SpaceHierarchyEntry>, BridgeError> {
+    let mut chunks = Vec::new();
+    let mut from = None;
+    let mut seen_batches = HashSet::new();
+    for _ in 0..MAX_HIERARCHY_PAGES {
+        let mut request = get_hierarchy::v1::Request::new(space_id.to_owned());
+        request.from = from;
+        let response = client.send(request, None).await?;
+        chunks.extend(response.rooms);
+        from = match response.next_batch {
+            Some(batch) if seen_batches.insert(batch.clone()) => Some(batch),
+            Some(batch) => {
+                eprintln!("[Bridge][spaces] Server repeated hierarchy token {} for {}, stopping", batch, space_id);
+                None
+            }
+            None => None,
+        };
+        if from.is_none() {
+            break;
+        }
+    }
+    if from.is_some() {
+        eprintln!("[Bridge][spaces] Hierarchy of {} has over {} pages, truncating it", space_id, MAX_HIERARCHY_PAGES);
+    }
+    // A page served twice would list its rooms twice
+    let mut listed = HashSet::new();
+    chunks.retain(|chunk| listed.insert(chunk.room_id.clone()));
+
+    let mut parents: HashMap<OwnedRoomId, OwnedRoomId> = HashMap::new();
+    for chunk in &chunks {
+        for child in chunk.children_state.iter().filter_map(|raw| raw.deserialize().ok()) {
+            if let Ok(child_id) = OwnedRoomId::try_from(child.state_key) {
+                parents.entry(child_id).or_insert_with(|| chunk.room_id.clone());
+            }
+        }
+    }
+    Ok(chunks
+        .into_iter()
+        .map(|chunk| SpaceHierarchyEntry {
+            parent_id: parents.get(&chunk.room_id).map(|id| id.to_string()),
+            joined: client.get_room(&chunk.room_id).is_some_and(|room| room.state() == RoomState::Joined),
+            room_id: chunk.room_id.to_string(),
+            name: chunk.name,
+            topic: chunk.topic,
+            avatar_url: chunk.avatar_url.map(|url| url.to_string()),
+            is_space: chunk.room_type == Some(RoomType::Space),
+            joined_members: chunk.num_joined_members.into(),
+        })
+        .collect())
+}
+
+/// Rooms below `space_id` that we know of locally, through subspaces we're in as well.
+pub(crate) async fn descendants(client: &Client, space_id: &RoomId) -> Result<HashSet<OwnedRoomId>, BridgeError> {
+    let mut found = HashSet::new();
+    let mut pending = vec![space_id.to_owned()];
+    while let Some(space_id) = pending.pop() {
+        let Some(space) = client.get_room(&space_id) else {
+            continue;
+        };
+        for child_id in children(&space).await? {
+            if found.insert(child_id.clone()) {
+                pending.push(child_id);
+            }
+        }
+    }
+    Ok(found)
+}
+
+async fn children(space: &Room) -> Result<Vec<OwnedRoomId>, BridgeError> {
+    let events = space.get_state_events_static::<SpaceChildEventContent>().await?;
+    Ok(events
+        .into_iter()
+        .filter_map(|raw| match raw.deserialize().ok()? {
+            SyncOrStrippedState::Sync(SyncStateEvent::Original(ev)) if !ev.content.via.is_empty() => Some(ev.state_key),
+            SyncOrStrippedState::Stripped(ev) if ev.content.via.as_ref().is_some_and(|via| !via.is_empty()) => {
+                Some(ev.state_key)
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+/// Our own server, to list in `via`; it can reach every room we're in.
+fn via(client: &Client) -> Result<Vec<OwnedServerName>, BridgeError> {
+    let user_id = client.user_id().ok_or_else(|| BridgeError::new(BridgeErrorKind::NotLoggedIn, "Not logged in"))?;
+    Ok(vec![user_id.server_name().to_owned()])
+}
@@ -0,0 +1,306 @@
+// Passphrase handling for the SQLite stores in a client's data_dir.
+//
+// The SDK encrypts store values with a `StoreCipher` whose key sits, itself
+// encrypted with the passphrase, in the `cipher` row of each database's kv
+// table. Changing the passphrase therefore only rewrites that row. Encrypting
+// a store that was created without a passphrase means re-encoding every row
+// of the crypto store the way the SDK would have written it, so Olm/Megolm
+// keys survive. The state store only caches server state; it is dropped and
+// rebuilt by the next sync.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use matrix_sdk_store_encryption::StoreCipher;
+use rusqlite::{Connection, OptionalExtension};
+
+use crate::bridge::{BridgeError, BridgeErrorKind};
+
+const CRYPTO_DB: &str = "matrix-sdk-crypto.sqlite3";
+const STATE_DB: &str = "matrix-sdk-state.sqlite3";
+/// Files SQLite keeps next to a database
+const SQLITE_SUFFIXES: &[&str] = &["", "-wal", "-shm", "-journal"];
+/// Crypto store schema the row layout below matches (matrix-sdk-sqlite 0.7)
+const CRYPTO_DB_VERSION: u8 = 8;
+
+/// Crypto store tables: name, the table name their keys are hashed under, and their key columns.
+/// Every table keeps its value in a `data` column.
+const CRYPTO_TABLES: &[(&str, &str, &[&str])] = &[
+    ("session", "session", &["session_id", "sender_key"]),
+    ("inbound_group_session", "inbound_group_session", &["session_id", "room_id"]),
+    ("outbound_group_session", "outbound_group_session", &["room_id"]),
+    ("device", "device", &["user_id", "device_id"]),
+    ("identity", "identity", &["user_id"]),
+    ("tracked_user", "tracked_users", &["user_id"]),
+    ("key_requests", "key_requests", &["request_id"]),
+    ("room_settings", "room_settings", &["room_id"]),
+    ("direct_withheld_info", "direct_withheld_info", &["session_id", "room_id"]),
+    ("secrets", "secrets", &["secret_name"]),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum StoreEncryption {
+    /// No store has been created in the directory yet
+    Missing,
+    Plain,
+    Encrypted,
+}
+
+/// The SDK's database files in `dir` that exist. The directory may hold other files (other
+/// accounts' stores, the app's own data); those aren't part of the store.
+pub(crate) fn store_files(dir: &Path) -> Vec<PathBuf> {
+    [CRYPTO_DB, STATE_DB]
+        .iter()
+        .flat_map(|name| SQLITE_SUFFIXES.iter().map(move |suffix| dir.join(format!("{}{}", name, suffix))))
+        .filter(|path| path.exists())
+        .collect()
+}
+
+/// Delete the SDK's database files in `dir`, leaving the rest of the directory alone.
+pub(crate) fn remove_store(dir: &Path) -> Result<(), String> {
+    for path in store_files(dir) {
+        fs::remove_file(&path).map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Whether the store in `dir` is passphrase-encrypted.
+pub(crate) fn store_encryption(dir: &Path) -> Result<StoreEncryption, String> {
+    let mut found = StoreEncryption::Missing;
+    for name in [CRYPTO_DB, STATE_DB] {
+        let path = dir.join(name);
+        if !path.is_file() {
+            continue;
+        }
+        let conn = open(&path)?;
+        if read_kv(&conn, "cipher")?.is_some() {
+            return Ok(StoreEncryption::Encrypted);
+        }
+        found = StoreEncryption::Plain;
+    }
+    Ok(found)
+}
+
+/// Re-encrypt the store key of an encrypted store under a new passphrase.
+/// Both databases end up under the new passphrase or, if writing one fails, both stay under the
+/// old one.
+pub(crate) fn change_passphrase(dir: &Path, old_passphrase: &str, new_passphrase: &str) -> Result<(), BridgeError> {
+    // Check the old passphrase against every database and derive every new row before touching
+    // any of them
+    let mut updates = Vec::new();
+    for name in [CRYPTO_DB, STATE_DB] {
+        let path = dir.join(name);
+        if !path.is_file() {
+            continue;
+        }
+        let conn = open(&path)?;
+        let old = read_kv(&conn, "cipher")?.ok_or_else(|| format!("{} is not encrypted", name))?;
+        let cipher = StoreCipher::import(old_passphrase, &old)
+            .map_err(|_| BridgeError::new(BridgeErrorKind::WrongPassphrase, "Wrong store passphrase"))?;
+        let new = cipher.export(new_passphrase).map_err(|e| format!("Failed to export store key: {}", e))?;
+        updates.push((name, conn, old, new));
+    }
+    for (i, (name, conn, _, new)) in updates.iter().enumerate() {
+        if let Err(e) = write_kv(conn, "cipher", new) {
+            for (written, conn, old, _) in &updates[..i] {
+                if let Err(e) = write_kv(conn, "cipher", old) {
+                    eprintln!("[Bridge][store] Failed to restore the store key of {}: {}", written, e);
+                }
+            }
+            return Err(format!("Failed to write the store key of {}: {}", name, e).into());
+        }
+    }
+    Ok(())
+}
+
+/// Encrypt a store created without a passphrase, in place.
+pub(crate) fn encrypt_plain_store(dir: &Path, passphrase: &str) -> Result<(), String> {
+    let cipher = StoreCipher::new().map_err(|e| format!("Failed to create store key: {}", e))?;
+    let exported = cipher.export(passphrase).map_err(|e| format!("Failed to export store key: {}", e))?;
+
+    let crypto_path = dir.join(CRYPTO_DB);
+    if crypto_path.is_file() {
+        let mut conn = open(&crypto_path)?;
+        let version = read_kv(&conn, "version")?;
+        if version.as_deref() != Some(&[CRYPTO_DB_VERSION][..]) {
+            return Err(format!("Unsupported crypto store version {:?}", version));
+        }
+        // One transaction: either every row is re-encoded or the store stays as it was
+        let txn = conn.transaction().map_err(|e| e.to_string())?;
+        encrypt_crypto_rows(&txn, &cipher)?;
+        write_kv(&txn, "cipher", &exported)?;
+        txn.commit().map_err(|e| format!("Failed to commit re-encrypted crypto store: {}", e))?;
+    }
+
+    for suffix in SQLITE_SUFFIXES {
+        let path = dir.join(format!("{}{}", STATE_DB, suffix));
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+        }
+    }
+    Ok(())
+}
+
+fn encrypt_crypto_rows(conn: &Connection, cipher: &StoreCipher) -> Result<(), String> {
+    let encode_value = |value: Vec<u8>| -> Result<Vec<u8>, String> {
+        let encrypted = cipher.encrypt_value_data(value).map_err(|e| e.to_string())?;
+        rmp_serde::to_vec_named(&encrypted).map_err(|e| e.to_string())
+    };
+
+    // kv values are encoded too, except the schema version (and the cipher, which isn't there yet)
+    let rows: Vec<(String, Vec<u8>)> = select(conn, "SELECT key, value FROM kv WHERE key != 'version'", |row| {
+        Ok((row.get(0)?, row.get(1)?))
+    })?;
+    for (key, value) in rows {
+        write_kv(conn, &key, &encode_value(value)?)?;
+    }
+
+    for (table, hash_table, key_columns) in CRYPTO_TABLES {
+        let query = format!("SELECT rowid, {}, data FROM \"{}\"", key_columns.join(", "), table);
+        let rows: Vec<(i64, Vec<Vec<u8>>, Vec<u8>)> = select(conn, &query, |row| {
+            let keys = (1..=key_columns.len()).map(|i| row.get(i)).collect::<rusqlite::Result<_>>()?;
+            Ok((row.get(0)?, keys, row.get(key_columns.len() + 1)?))
+        })?;
+
+        let assignments: Vec<String> = key_columns.iter().map(|c| format!("{} = ?", c)).collect();
+        let update = format!("UPDATE \"{}\" SET {}, data = ? WHERE rowid = ?", table, assignments.join(", "));
+        for (rowid, keys, data) in rows {
+            let mut params: Vec<rusqlite::types::Value> = keys
+                .iter()
+                .map(|key| rusqlite::types::Value::Blob(cipher.hash_key(hash_table, key).to_vec()))
+                .collect();
+            params.push(rusqlite::types::Value::Blob(encode_value(data)?));
+            params.push(rusqlite::types::Value::Integer(rowid));
+            conn.execute(&update, rusqlite::params_from_iter(params))
+                .map_err(|e| format!("Failed to re-encrypt {}: {}", table, e))?;
+        }
+    }
+    Ok(())
+}
+
+fn open(path: &Path) -> Result<Connection, String> {
+    Connection::open(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))
+}
+
+fn select<T>(
+    conn: &Connection,
+    query: &str,
+    map: impl FnMut(&rusqlite::Row<'_>) -> rusqlite::Result<T>,
+) -> Result<Vec<T>, String> {
+    let mut stmt = conn.prepare(query).map_err(|e| e.to_string())?;
+    let rows = stmt.query_map([], map).map_err(|e| e.to_string())?;
+    rows.collect::<rusqlite::Result<_>>().map_err(|e| e.to_string())
+}
+
+fn read_kv(conn: &Connection, key: &str) -> Result<Option<Vec<u8>>, String> {
+    let has_kv: bool = conn
+        .query_row("SELECT count(*) FROM sqlite_master WHERE type = 'table' AND name = 'kv'", [], |row| {
+            row.get::<_, i64>(0)
+        })
+        .map(|count| count > 0)
+        .map_err(|e| e.to_string())?;
+    if !has_kv {
+        return Ok(None);
+    }
+    conn.query_row("SELECT value FROM kv WHERE key = ?", [key], |row| row.get(0))
+        .optional()
+        .map_err(|e| e.to_string())
+}
+
+fn write_kv(conn: &Connection, key: &str, value: &[u8]) -> Result<(), String> {
+    conn.execute("INSERT INTO kv VALUES (?1, ?2) ON CONFLICT (key) DO UPDATE SET value = ?2", (key, value))
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use matrix_sdk::crypto::olm::{Account, InboundGroupSession};
+    use matrix_sdk::crypto::store::{Changes, CryptoStore};
+    use matrix_sdk::crypto::types::EventEncryptionAlgorithm;
+    use matrix_sdk::crypto::vodozemac::megolm::{GroupSession, SessionConfig};
+    use matrix_sdk::ruma::{device_id, room_id, user_id, RoomId};
+    use matrix_sdk::SqliteCryptoStore;
+
+    use super::*;
+
+    fn room() -> &'static RoomId {
+        room_id!("!property:example.org")
+    }
+
+    fn inbound_session(account: &Account) -> InboundGroupSession {
+        let keys = account.identity_keys();
+        let outbound = GroupSession::new(SessionConfig::version_1());
+        InboundGroupSession::new(
+            keys.curve25519,
+            keys.ed25519,
+            room(),
+            &outbound.session_key(),
+            EventEncryptionAlgorithm::MegolmV1AesSha2,
+            None,
+        )
+        .unwrap()
+    }
+
+    /// A plaintext crypto store in a fresh directory holding two room keys, and their session IDs.
+    pub(crate) async fn plain_store() -> (tempfile::TempDir, Vec<String>) {
+        let dir = tempfile::tempdir().unwrap();
+        let account = Account::with_device_id(user_id!("@tenant:example.org"), device_id!("TENANTDEVICE"));
+        let sessions = vec![inbound_session(&account), inbound_session(&account)];
+        let mut ids: Vec<String> = sessions.iter().map(|session| session.session_id().to_owned()).collect();
+        ids.sort();
+        let store = SqliteCryptoStore::open(dir.path(), None).await.unwrap();
+        store.save_changes(Changes { inbound_group_sessions: sessions, ..Default::default() }).await.unwrap();
+        drop(store);
+        (dir, ids)
+    }
+
+    async fn session_ids(dir: &Path, passphrase: &str) -> Vec<String> {
+        let store = SqliteCryptoStore::open(dir, Some(passphrase)).await.unwrap();
+        let mut ids: Vec<String> = store
+            .get_inbound_group_sessions()
+            .await
+            .unwrap()
+            .iter()
+            .map(|session| session.session_id().to_owned())
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test]
+    async fn encrypted_plain_store_keeps_its_room_keys() {
+        let (dir, ids) = plain_store().await;
+        assert_eq!(store_encryption(dir.path()).unwrap(), StoreEncryption::Plain);
+
+        encrypt_plain_store(dir.path(), "secret").unwrap();
+
+        assert_eq!(store_encryption(dir.path()).unwrap(), StoreEncryption::Encrypted);
+        assert_eq!(session_ids(dir.path(), "secret").await, ids);
+        // Lookups go through the hashed key columns
+        let store = SqliteCryptoStore::open(dir.path(), Some("secret")).await.unwrap();
+        assert!(store.get_inbound_group_session(room(), &ids[0]).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn changed_passphrase_opens_the_store() {
+        let (dir, ids) = plain_store().await;
+        encrypt_plain_store(dir.path(), "secret").unwrap();
+
+        change_passphrase(dir.path(), "secret", "new secret").unwrap();
+
+        assert_eq!(session_ids(dir.path(), "new secret").await, ids);
+        assert!(SqliteCryptoStore::open(dir.path(), Some("secret")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn wrong_passphrase_leaves_the_store_alone() {
+        let (dir, ids) = plain_store().await;
+        encrypt_plain_store(dir.path(), "secret").unwrap();
+
+        let err = change_passphrase(dir.path(), "guess", "new secret").unwrap_err();
+
+        assert_eq!(err.kind, BridgeErrorKind::WrongPassphrase);
+        assert_eq!(session_ids(dir.path(), "secret").await, ids);
+    }
+}
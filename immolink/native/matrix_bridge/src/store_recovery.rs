@@ -0,0 +1,158 @@
+// Recovery from a store that belongs to another account or device.
+//
+// The SDK refuses to use a crypto store whose Olm account was created for a
+// different user/device ("account in the store doesn't match"). Instead of
+// wiping such a store, its room keys are exported into an encrypted backup
+// file next to it, its database files are moved aside, and a fresh store is
+// built in their place. Only the SDK's files are moved: the data_dir is often
+// shared with the app and with other accounts' stores. The keys are imported
+// into the new store once the client is logged in, so old history stays
+// readable, and the backup is deleted after that. The backup is encrypted with
+// the store passphrase, or with a random one kept in memory until the import
+// for stores without one.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use matrix_sdk::crypto::store::CryptoStore;
+use matrix_sdk::SqliteCryptoStore;
+use rand::distributions::{Alphanumeric, DistString};
+
+use crate::store_encryption;
+
+/// Substring of the SDK error for a store created by another account/device
+const MISMATCH_ERROR: &str = "account in the store doesn't match";
+/// PBKDF2 rounds for the key backup file, same as the SDK's `export_room_keys`
+const EXPORT_ROUNDS: u32 = 500_000;
+/// Length of the passphrase generated for the key backup of a store without one
+const ONE_TIME_PASSPHRASE_LEN: usize = 32;
+
+pub(crate) fn is_store_mismatch(error: &str) -> bool {
+    error.contains(MISMATCH_ERROR)
+}
+
+/// A store moved aside by `quarantine_store`.
+/// No `Debug`, so the key passphrase can't end up in a log.
+#[derive(Clone)]
+pub(crate) struct Quarantine {
+    pub(crate) reason: String,
+    pub(crate) quarantined_dir: PathBuf,
+    /// Room key export taken from the old store. None if the export failed; the keys are still
+    /// in the quarantined store then
+    pub(crate) key_file: Option<PathBuf>,
+    /// What `key_file` is encrypted with: the store passphrase, or a one-time one if the store
+    /// has none
+    pub(crate) key_passphrase: String,
+    pub(crate) exported_keys: usize,
+}
+
+/// Export the room keys of the store in `data_dir`, then move its database files to
+/// `<data_dir>.quarantine-<unix time>`. Everything else in `data_dir` stays where it is.
+/// The store must not be open anymore (Windows won't rename open files).
+pub(crate) async fn quarantine_store(data_dir: &Path, passphrase: Option<&str>, reason: String) -> Result<Quarantine, String> {
+    let stamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    let quarantined_dir = PathBuf::from(format!("{}.quarantine-{}", data_dir.display(), stamp));
+    let key_file = PathBuf::from(format!("{}.room-keys-{}.txt", data_dir.display(), stamp));
+
+    let passphrase = passphrase.filter(|p| !p.is_empty());
+    // The keys are never written out unprotected
+    let key_passphrase = match passphrase {
+        Some(passphrase) => passphrase.to_owned(),
+        None => Alphanumeric.sample_string(&mut rand::thread_rng(), ONE_TIME_PASSPHRASE_LEN),
+    };
+    // A failed export must not stop the recovery; the quarantined store still has the keys
+    let (key_file, exported_keys) = match export_room_keys(data_dir, passphrase, &key_passphrase, &key_file).await {
+        Ok(count) => {
+            eprintln!("[Bridge][recovery] Exported {} room keys to {}", count, key_file.display());
+            (Some(key_file), count)
+        }
+        Err(e) => {
+            eprintln!("[Bridge][recovery] Failed to export room keys: {}", e);
+            (None, 0)
+        }
+    };
+
+    fs::create_dir_all(&quarantined_dir)
+        .map_err(|e| format!("Failed to create {}: {}", quarantined_dir.display(), e))?;
+    for path in store_encryption::store_files(data_dir) {
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        fs::rename(&path, quarantined_dir.join(name))
+            .map_err(|e| format!("Failed to quarantine {}: {}", path.display(), e))?;
+    }
+    eprintln!("[Bridge][recovery] Old store moved to {}", quarantined_dir.display());
+
+    Ok(Quarantine { reason, quarantined_dir, key_file, key_passphrase, exported_keys })
+}
+
+/// Write the room keys of the store in `data_dir` (opened with `passphrase`) to `key_file`,
+/// encrypted with `key_passphrase`.
+async fn export_room_keys(
+    data_dir: &Path,
+    passphrase: Option<&str>,
+    key_passphrase: &str,
+    key_file: &Path,
+) -> Result<usize, String> {
+    let store = SqliteCryptoStore::open(data_dir, passphrase).await.map_err(|e| e.to_string())?;
+    let sessions = store.get_inbound_group_sessions().await.map_err(|e| e.to_string())?;
+    let mut keys = Vec::with_capacity(sessions.len());
+    for session in &sessions {
+        keys.push(session.export().await);
+    }
+    drop(store);
+
+    let export = matrix_sdk::crypto::encrypt_room_key_export(&keys, key_passphrase, EXPORT_ROUNDS)
+        .map_err(|e| e.to_string())?;
+    fs::write(key_file, export).map_err(|e| e.to_string())?;
+    Ok(keys.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use matrix_sdk::crypto::OlmMachine;
+    use matrix_sdk::ruma::{device_id, user_id};
+
+    use super::*;
+    use crate::store_encryption::tests::plain_store;
+
+    #[tokio::test]
+    async fn store_without_passphrase_keeps_its_room_keys() {
+        let (dir, ids) = plain_store().await;
+        let data_dir = dir.path().join("store");
+        fs::create_dir(&data_dir).unwrap();
+        for path in store_encryption::store_files(dir.path()) {
+            fs::rename(&path, data_dir.join(path.file_name().unwrap())).unwrap();
+        }
+
+        let quarantine = quarantine_store(&data_dir, None, "test".to_owned()).await.unwrap();
+
+        assert!(store_encryption::store_files(&data_dir).is_empty());
+        assert_eq!(quarantine.exported_keys, ids.len());
+        assert_eq!(quarantine.key_passphrase.len(), ONE_TIME_PASSPHRASE_LEN);
+        let key_file = quarantine.key_file.expect("room keys exported");
+        // Not readable without the passphrase
+        let export = fs::read_to_string(&key_file).unwrap();
+        assert!(matrix_sdk::crypto::decrypt_room_key_export(export.as_bytes(), "").is_err());
+
+        // What the next login does with it
+        let keys = matrix_sdk::crypto::decrypt_room_key_export(export.as_bytes(), &quarantine.key_passphrase).unwrap();
+        let store = SqliteCryptoStore::open(&data_dir, None).await.unwrap();
+        let machine = OlmMachine::with_store(user_id!("@tenant:example.org"), device_id!("NEWDEVICE"), store)
+            .await
+            .unwrap();
+        let result = machine.store().import_exported_room_keys(keys, |_, _| {}).await.unwrap();
+        assert_eq!(result.imported_count, ids.len());
+        let mut imported: Vec<String> = machine
+            .store()
+            .get_inbound_group_sessions()
+            .await
+            .unwrap()
+            .iter()
+            .map(|session| session.session_id().to_owned())
+            .collect();
+        imported.sort();
+        assert_eq!(imported, ids);
+    }
+}
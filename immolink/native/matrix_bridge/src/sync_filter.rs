@@ -0,0 +1,138 @@
+// Sync filters for classic sync.
+//
+// Without a filter every sync carries presence, the full member list of each
+// room and all of its state. The filter set with `set_sync_filter` trims that
+// down. It is uploaded to the homeserver once; the SDK keeps its id in the
+// store under a name derived from the options, so later syncs (and later app
+// starts) reuse it, and changed options get a filter of their own.
+
+use matrix_sdk::ruma::api::client::filter::{Filter, FilterDefinition, LazyLoadOptions, RoomEventFilter, RoomFilter};
+use matrix_sdk::ruma::UInt;
+use matrix_sdk::Client;
+
+use crate::bridge::{BridgeError, SyncFilterConfig};
+
+/// The filter id to sync with for `config`, uploading the filter if this store hasn't yet.
+pub(crate) async fn filter_id(client: &Client, config: &SyncFilterConfig) -> Result<String, BridgeError> {
+    Ok(client.get_or_upload_filter(&filter_name(config), definition(config)).await?)
+}
+
+fn filter_name(config: &SyncFilterConfig) -> String {
+    format!(
+        "immosync-lazy{}-limit{}-presence{}-account{}-ephemeral{}",
+        u8::from(config.lazy_load_members),
+        config.timeline_limit.map(|limit| limit.to_string()).unwrap_or_else(|| "none".to_owned()),
+        u8::from(config.include_presence),
+        u8::from(config.include_account_data),
+        u8::from(config.include_ephemeral),
+    )
+}
+
+fn definition(config: &SyncFilterConfig) -> FilterDefinition {
+    let lazy_load_options = if config.lazy_load_members {
+        // Members of senders we already sent once needn't come again
+        LazyLoadOptions::Enabled { include_redundant_members: false }
+    } else {
+        LazyLoadOptions::Disabled
+    };
+
+    let mut timeline = RoomEventFilter::empty();
+    timeline.limit = config.timeline_limit.map(UInt::from);
+    timeline.lazy_load_options = lazy_load_options;
+    let mut state = RoomEventFilter::empty();
+    state.lazy_load_options = lazy_load_options;
+
+    let mut room = RoomFilter::empty();
+    room.timeline = timeline;
+    room.state = state;
+    if !config.include_ephemeral {
+        room.ephemeral = RoomEventFilter::ignore_all();
+    }
+    if !config.include_account_data {
+        room.account_data = RoomEventFilter::ignore_all();
+    }
+
+    let mut definition = FilterDefinition::empty();
+    definition.room = room;
+    if !config.include_presence {
+        definition.presence = Filter::ignore_all();
+    }
+    if !config.include_account_data {
+        definition.account_data = Filter::ignore_all();
+    }
+    definition
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SyncFilterConfig {
+        SyncFilterConfig {
+            lazy_load_members: true,
+            timeline_limit: Some(20),
+            include_presence: false,
+            include_account_data: true,
+            include_ephemeral: false,
+        }
+    }
+
+    fn json(config: &SyncFilterConfig) -> serde_json::Value {
+        serde_json::to_value(definition(config)).unwrap()
+    }
+
+    #[test]
+    fn names_follow_the_options() {
+        assert_eq!(filter_name(&config()), "immosync-lazy1-limit20-presence0-account1-ephemeral0");
+        let unlimited = SyncFilterConfig { timeline_limit: None, ..config() };
+        assert_eq!(filter_name(&unlimited), "immosync-lazy1-limitnone-presence0-account1-ephemeral0");
+
+        let mut names: Vec<_> = [
+            config(),
+            unlimited,
+            SyncFilterConfig { lazy_load_members: false, ..config() },
+            SyncFilterConfig { include_presence: true, ..config() },
+            SyncFilterConfig { include_account_data: false, ..config() },
+            SyncFilterConfig { include_ephemeral: true, ..config() },
+        ]
+        .iter()
+        .map(filter_name)
+        .collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), 6);
+    }
+
+    #[test]
+    fn definition_lazy_loads_and_limits_the_timeline() {
+        let filter = json(&config());
+        let room = &filter["room"];
+        assert_eq!(room["timeline"]["limit"], 20);
+        assert_eq!(room["timeline"]["lazy_load_members"], true);
+        assert_eq!(room["state"]["lazy_load_members"], true);
+        assert_eq!(room["state"].get("include_redundant_members"), None);
+    }
+
+    #[test]
+    fn left_out_sections_are_ignored() {
+        let filter = json(&config());
+        assert_eq!(filter["presence"]["types"], serde_json::json!([]));
+        assert_eq!(filter["room"]["ephemeral"]["types"], serde_json::json!([]));
+        assert_eq!(filter.get("account_data"), None);
+        assert_eq!(filter["room"].get("account_data"), None);
+
+        let everything = SyncFilterConfig {
+            lazy_load_members: false,
+            timeline_limit: None,
+            include_presence: true,
+            include_account_data: true,
+            include_ephemeral: true,
+        };
+        assert_eq!(json(&everything), serde_json::json!({}));
+
+        let no_account_data = SyncFilterConfig { include_account_data: false, ..config() };
+        let filter = json(&no_account_data);
+        assert_eq!(filter["account_data"]["types"], serde_json::json!([]));
+        assert_eq!(filter["room"]["account_data"]["types"], serde_json::json!([]));
+    }
+}
@@ -0,0 +1,345 @@
+// The background sync loop of a client.
+//
+// Classic sync long-polls `/sync` from the last sync token, with the filter
+// from `set_sync_filter` if there is one (see sync_filter.rs). With sliding
+// sync configured in `init`, only the visible window of the room list is
+// synced, with a few timeline events per room, which makes cold starts with
+// hundreds of rooms fast. If neither the proxy nor the homeserver turn out to
+// speak sliding sync, the loop falls back to classic sync. Either way failed
+// syncs are retried with backoff (see backoff.rs) and every change of state is
+// reported through the client's sync status.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures_util::{pin_mut, FutureExt, StreamExt};
+use matrix_sdk::deserialized_responses::EncryptionInfo;
+use matrix_sdk::event_handler::RawEvent;
+use matrix_sdk::ruma::api::client::error::ErrorKind;
+use matrix_sdk::ruma::api::client::sync::sync_events::v3::Filter;
+use matrix_sdk::ruma::events::room::encrypted::OriginalSyncRoomEncryptedEvent;
+use matrix_sdk::ruma::events::room::member::{MembershipState, StrippedRoomMemberEvent};
+use matrix_sdk::ruma::events::room::message::OriginalSyncRoomMessageEvent;
+use matrix_sdk::ruma::events::StateEventType;
+use matrix_sdk::ruma::serde::Raw;
+use matrix_sdk::{config::SyncSettings, room::Room, Client, SlidingSync, SlidingSyncList, SlidingSyncMode};
+use matrix_sdk_base::store::{StateStoreDataKey, StateStoreDataValue};
+use tokio::sync::watch;
+use url::Url;
+
+use crate::backoff;
+use crate::bridge::{BridgeError, BridgeErrorKind, SlidingSyncConfig, SyncFilterConfig, SyncState, SyncStatus};
+use crate::clients::{self, ClientState};
+use crate::invites;
+use crate::property_state::PROPERTY_EVENT_TYPE;
+use crate::room_list;
+use crate::sync_filter;
+
+/// Long-polling timeout for both sync flavours
+const POLL_TIMEOUT: Duration = Duration::from_secs(30);
+/// Name of the single sliding sync list, the room list
+const ROOM_LIST: &str = "rooms";
+
+/// Sync until `stop` turns true (or the task is aborted) or the session expires.
+pub(crate) async fn run(
+    handle_id: u32,
+    client: Client,
+    sliding: Option<SlidingSyncConfig>,
+    mut stop: watch::Receiver<bool>,
+) {
+    if let Some(config) = sliding {
+        match sliding_sync(handle_id, &client, config, &mut stop).await {
+            Ok(()) => return,
+            Err(reason) => eprintln!("[Bridge][sync] Sliding sync unavailable ({}), falling back to classic sync", reason),
+        }
+    }
+    classic_sync(handle_id, &client, &mut stop).await;
+    eprintln!("[Bridge][sync] Sync loop stopped");
+}
+
+/// Register the handlers that forward synced events, unless the client has them already.
+pub(crate) fn register_handlers(state: &ClientState) {
+    let Ok(mut handlers) = state.event_handlers.lock() else {
+        return;
+    };
+    if !handlers.is_empty() {
+        return;
+    }
+    let handle_id = state.id;
+    let client = &state.client;
+
+    // Invites to us are joined or passed to Dart, depending on the invite policy
+    handlers.push(client.add_event_handler(move |ev: StrippedRoomMemberEvent, client: Client, room: Room| async move {
+        if client.user_id() != Some(ev.state_key.as_ref()) || ev.content.membership != MembershipState::Invite {
+            return;
+        }
+        if let Ok(state) = clients::get(handle_id) {
+            invites::on_invite(&state, room, &ev.sender).await;
+        }
+    }));
+
+    // Register event handler to forward message events to Dart via StreamSink
+    // Note: OriginalSyncRoomMessageEvent is already decrypted by the SDK; the
+    // encryption info tells us whether it arrived encrypted.
+    // Handlers look the client up by id rather than holding its state, so a disposed
+    // client's handlers just go quiet.
+    handlers.push(client.add_event_handler(move |ev: OriginalSyncRoomMessageEvent, room: Room, encryption_info: Option<EncryptionInfo>| async move {
+        let item = crate::bridge::message_to_timeline_item(room.room_id(), ev, encryption_info.is_some());
+        eprintln!("[Bridge][sync] Emitting event to Dart: room={} event={} kind={:?}", item.room_id, item.event_id, item.kind);
+        if let Ok(state) = clients::get(handle_id) {
+            state.emit(item);
+        }
+    }));
+
+    // Events that reach this handler are still encrypted, i.e. the SDK had no key for them.
+    // Retry once to get the reason (or the plaintext, if the key just arrived) and emit that.
+    handlers.push(client.add_event_handler(move |_ev: OriginalSyncRoomEncryptedEvent, room: Room, raw: RawEvent| async move {
+        if let Some(item) = crate::bridge::raw_to_timeline_item(&room, Raw::from_json((*raw).to_owned()), true).await {
+            eprintln!("[Bridge][sync] Emitting event to Dart: room={} event={} kind={:?}", item.room_id, item.event_id, item.kind);
+            if let Ok(state) = clients::get(handle_id) {
+                state.emit(item);
+            }
+        }
+    }));
+}
+
+async fn classic_sync(handle_id: u32, client: &Client, stop: &mut watch::Receiver<bool>) {
+    // Use long-polling sync for real-time updates
+    // Important: Keep the sync token of the last response so the next sync is incremental.
+    // `sync_once` doesn't pick up the stored token by itself, so start from it
+    let mut token = stored_token(client).await;
+    if token.is_some() {
+        eprintln!("[Bridge][sync] Resuming from the stored sync token");
+    }
+    // The uploaded filter for the options last set with `set_sync_filter`
+    let mut filter: Option<(SyncFilterConfig, String)> = None;
+    eprintln!("[Bridge][sync] Sync loop starting with 30s long-polling...");
+    let mut sync_count = 0;
+    let mut failures: u32 = 0;
+    // A stop request is looked at between syncs, so the one in flight is always processed
+    while !*stop.borrow() {
+        sync_count += 1;
+        if sync_count % 5 == 1 {
+            eprintln!("[Bridge][sync] Sync iteration {} (long-polling for 30s or until events arrive)", sync_count);
+        }
+        let mut settings = SyncSettings::default().timeout(POLL_TIMEOUT);
+        if let Some(token) = &token {
+            settings = settings.token(token.clone());
+        }
+        // Options can change between syncs; each set is uploaded once
+        if let Some(config) = clients::get(handle_id).ok().and_then(|state| state.sync_filter()) {
+            if filter.as_ref().map(|(known, _)| known) != Some(&config) {
+                match sync_filter::filter_id(client, &config).await {
+                    Ok(id) => {
+                        eprintln!("[Bridge][sync] Using sync filter {}", id);
+                        filter = Some((config, id));
+                    }
+                    Err(e) => {
+                        eprintln!("[Bridge][sync] Failed to upload sync filter: {}", e);
+                        if !wait_after_error(handle_id, &mut failures, e, stop).await {
+                            return;
+                        }
+                        continue;
+                    }
+                }
+            }
+            if let Some((_, id)) = &filter {
+                settings = settings.filter(Filter::FilterId(id.clone()));
+            }
+        }
+        match client.sync_once(settings).await {
+            Ok(response) => {
+                failures = 0;
+                if let Ok(state) = clients::get(handle_id) {
+                    state.set_sync_status(SyncStatus::new(SyncState::Running));
+                    let rooms = &response.rooms;
+                    let changed: Vec<_> =
+                        rooms.join.keys().chain(rooms.invite.keys()).chain(rooms.leave.keys()).cloned().collect();
+                    room_list::emit_changes(&state, &changed).await;
+                }
+                // Keep the new sync token for incremental sync
+                token = Some(response.next_batch);
+                if sync_count <= 5 {
+                    eprintln!("[Bridge][sync] Sync successful, got token for next iteration");
+                }
+            }
+            Err(e) => {
+                eprintln!("[Bridge][sync] error: {}", e);
+                if !wait_after_error(handle_id, &mut failures, BridgeError::from(e), stop).await {
+                    return;
+                }
+            }
+        }
+        // No delay needed with long-polling - server holds connection until events arrive
+    }
+}
+
+/// The token of the last sync saved in the store, if this store has synced before.
+async fn stored_token(client: &Client) -> Option<String> {
+    match client.store().get_kv_data(StateStoreDataKey::SyncToken).await {
+        Ok(Some(StateStoreDataValue::SyncToken(token))) => Some(token),
+        Ok(_) => None,
+        Err(e) => {
+            eprintln!("[Bridge][sync] Failed to read the stored sync token: {}", e);
+            None
+        }
+    }
+}
+
+/// Run sliding sync until stopped. Fails with the reason if sliding sync isn't available,
+/// which is only decided before the first successful sync.
+/// `ClientState::stop_sync` also stops the sliding sync itself, which ends its stream once the
+/// response in flight is processed.
+async fn sliding_sync(
+    handle_id: u32,
+    client: &Client,
+    config: SlidingSyncConfig,
+    stop: &mut watch::Receiver<bool>,
+) -> Result<(), String> {
+    let sliding = build_sliding_sync(client, &config).await.map_err(|e| e.to_string())?;
+    if let Ok(state) = clients::get(handle_id) {
+        state.set_sliding_sync(Some(sliding.clone()));
+    }
+    eprintln!(
+        "[Bridge][sync] Sliding sync starting (window of {} rooms, proxy: {:?})",
+        config.window_size,
+        config.proxy_url
+    );
+
+    let mut synced_once = false;
+    let mut failures: u32 = 0;
+    while !*stop.borrow() {
+        // The stream ends after yielding an error, so every retry starts a new one
+        let stream = sliding.sync();
+        pin_mut!(stream);
+        let mut failed = false;
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(summary) => {
+                    synced_once = true;
+                    failures = 0;
+                    if let Ok(state) = clients::get(handle_id) {
+                        state.set_sync_status(SyncStatus::new(SyncState::Running));
+                        room_list::emit_changes(&state, &summary.rooms).await;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("[Bridge][sync] Sliding sync error: {}", e);
+                    if !synced_once && is_unsupported(&e) {
+                        if let Ok(state) = clients::get(handle_id) {
+                            state.set_sliding_sync(None);
+                        }
+                        return Err(e.to_string());
+                    }
+                    if !wait_after_error(handle_id, &mut failures, BridgeError::from(e), stop).await {
+                        return Ok(());
+                    }
+                    failed = true;
+                }
+            }
+        }
+        if !failed {
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+async fn build_sliding_sync(client: &Client, config: &SlidingSyncConfig) -> Result<SlidingSync, BridgeError> {
+    let list = SlidingSyncList::builder(ROOM_LIST)
+        .sync_mode(SlidingSyncMode::new_selective().add_range(window(0, config.window_size)))
+        .timeline_limit(config.timeline_limit)
+        .required_state(vec![
+            (StateEventType::RoomEncryption, String::new()),
+            (StateEventType::RoomName, String::new()),
+            (StateEventType::RoomAvatar, String::new()),
+            (StateEventType::RoomPowerLevels, String::new()),
+            (StateEventType::RoomMember, "$LAZY".to_owned()),
+            (StateEventType::RoomMember, "$ME".to_owned()),
+            (StateEventType::from(PROPERTY_EVENT_TYPE), String::new()),
+        ]);
+    let mut builder = client
+        .sliding_sync("immosync")?
+        .add_cached_list(list)
+        .await?
+        .with_all_extensions()
+        .poll_timeout(POLL_TIMEOUT);
+    if let Some(proxy) = config.proxy_url.as_deref().filter(|p| !p.is_empty()) {
+        builder = builder.sliding_sync_proxy(Url::parse(proxy)?);
+    }
+    Ok(builder.build().await?)
+}
+
+/// Sync the `size` rooms of the room list starting at `start` (in recency order).
+pub(crate) async fn set_window(sliding: &SlidingSync, start: u32, size: u32) {
+    sliding
+        .on_list(ROOM_LIST, |list| {
+            list.set_sync_mode(SlidingSyncMode::new_selective().add_range(window(start, size)));
+            std::future::ready(())
+        })
+        .await;
+}
+
+fn window(start: u32, size: u32) -> std::ops::RangeInclusive<u32> {
+    start..=start.saturating_add(size.max(1) - 1)
+}
+
+/// Whether the server (or proxy) doesn't know the sliding sync endpoint at all.
+fn is_unsupported(e: &matrix_sdk::Error) -> bool {
+    matches!(e.client_api_error_kind(), Some(ErrorKind::Unrecognized))
+        || e.as_client_api_error().is_some_and(|api| matches!(api.status_code.as_u16(), 404 | 405))
+}
+
+/// Report a failed sync and wait until the next attempt. Returns false if sync has to stop
+/// because the session is gone, the client was disposed or a stop was requested meanwhile.
+async fn wait_after_error(
+    handle_id: u32,
+    failures: &mut u32,
+    error: BridgeError,
+    stop: &mut watch::Receiver<bool>,
+) -> bool {
+    let Ok(state) = clients::get(handle_id) else {
+        return false;
+    };
+    if error.kind == BridgeErrorKind::NotLoggedIn {
+        // Retrying can't help; a new login has to restart sync
+        eprintln!("[Bridge][sync] Session expired, stopping sync");
+        state.sync_task.lock().ok().and_then(|mut task| task.take());
+        state.set_sync_status(SyncStatus {
+            error: Some(error),
+            ..SyncStatus::new(SyncState::SessionExpired)
+        });
+        return false;
+    }
+    *failures += 1;
+    let delay = backoff::retry_delay(*failures, &error);
+    let rate_limited = error.kind == BridgeErrorKind::RateLimited;
+    let next_retry = SystemTime::now() + delay;
+    state.set_sync_status(SyncStatus {
+        state: if error.kind == BridgeErrorKind::NetworkUnavailable {
+            SyncState::Offline
+        } else {
+            SyncState::Backoff
+        },
+        attempt: *failures,
+        next_retry_ms: next_retry
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .and_then(|d| u64::try_from(d.as_millis()).ok()),
+        error: Some(error),
+    });
+    // A rate limit has to be waited out; other waits end early once the app reports
+    // the network is back
+    tokio::select! {
+        _ = tokio::time::sleep(delay) => {}
+        _ = state.sync_wakeup.notified(), if !rate_limited => {
+            eprintln!("[Bridge][sync] Network is back, retrying now");
+            *failures = 0;
+        }
+        // Also when the sender is gone
+        _ = stop.wait_for(|stop| *stop) => return false,
+    }
+    // Drop a wakeup that came in as the wait ended, or during a rate limit, so it can't cut the
+    // next wait short
+    let _ = state.sync_wakeup.notified().now_or_never();
+    true
+}